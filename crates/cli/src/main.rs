@@ -1,23 +1,40 @@
+mod serve;
+#[cfg(test)]
+mod tests;
 mod util;
 
+use crate::serve::serve;
 use crate::util::cli_progress::{CliProgressRef, CliProgressTrait, CliProgressType};
+use crate::util::documents::{document_path, is_output_pattern, ConcatFormat};
+use crate::util::lint::{count, LintFormat};
 use crate::util::logger::init_logger;
+use crate::util::overrides::OverrideArgs;
 #[cfg(feature = "embedded-plugins")]
 use crate::util::plugins::load_plugins;
+use crate::util::profile::ProfileFormat;
+use crate::util::sample::{schema_to_string, SampleFormat};
+use crate::util::watch::SchemaWatcher;
+use anyhow::Context;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
 use datagen_rs::generate::current_schema::CurrentSchema;
-use datagen_rs::generate::generated_schema::IntoRandom;
+use datagen_rs::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use datagen_rs::generate::profiler;
+use datagen_rs::infer::{infer_schema, InferOptions};
+use datagen_rs::lint::{lint_schema, Severity};
 use datagen_rs::plugins::plugin::Plugin;
 use datagen_rs::plugins::plugin_list::PluginList;
 use datagen_rs::schema::schema_definition::Schema;
-use datagen_rs::util::helpers::{read_schema, write_json_schema};
+use datagen_rs::util::compression::Compression;
+use datagen_rs::util::helpers::write_json_schema;
 use datagen_rs::validation::validate::Validate;
 #[cfg(feature = "node")]
 use datagen_rs_node_runner::runner::node_runner::NodeRunner;
 use datagen_rs_progress_plugin::{PluginWithSchemaResult, ProgressPlugin};
 use log::LevelFilter;
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::process::exit;
 use std::sync::Arc;
 
@@ -38,18 +55,113 @@ enum Commands {
         schema_file: String,
         /// An optional path to write the generated data to.
         /// If not specified, the data will be written to stdout.
+        /// If the path contains `{n}`, every document is written to its own file
+        /// with `{n}` replaced by the number of the document.
         out_file: Option<String>,
+        /// The number of independent documents to generate.
+        /// Requires an output path containing `{n}` or `--concat`
+        /// if more than one document is generated.
+        #[arg(long, default_value("1"), value_parser = clap::value_parser!(u32).range(1..))]
+        count: u32,
+        /// Write all documents to a single output,
+        /// either as JSON lines or as a YAML stream.
+        /// The serializer of the schema is ignored if this is set.
+        #[arg(long)]
+        concat: Option<ConcatFormat>,
         /// The log level to use
         #[arg(short, long)]
         log_level: Option<LevelFilter>,
         /// Whether to disable schema validation before generating the data
         #[arg(short, long, default_value("false"))]
         no_validate: bool,
+        /// Compress the generated data using gzip, zstd or bzip2.
+        /// If not specified, the compression is selected
+        /// by the extension of the output file, if any.
+        #[arg(short, long)]
+        compress: Option<Compression>,
+        /// Record the time spent generating every path of the schema
+        /// and in every plugin and transform.
+        /// Prints a report sorted by self time or writes the profile
+        /// as JSON or as folded stacks, which can be converted into a flamegraph.
+        #[arg(long)]
+        profile: Option<ProfileFormat>,
+        /// Write the profile to a file instead of stderr
+        #[arg(long, requires = "profile")]
+        profile_output: Option<String>,
+        #[command(flatten)]
+        overrides: OverrideArgs,
+    },
+    /// Regenerate data every time the schema or a file it uses changes
+    Watch {
+        /// The path to the schema file to use
+        schema_file: String,
+        /// An optional path to write the generated data to.
+        /// If not specified, the data will be written to stdout.
+        out_file: Option<String>,
+        /// The log level to use
+        #[arg(short, long)]
+        log_level: Option<LevelFilter>,
+        #[command(flatten)]
+        overrides: OverrideArgs,
+    },
+    /// Serve generated data over HTTP
+    Serve {
+        /// The path to the server configuration file
+        config_file: String,
+        /// The address to listen on
+        #[arg(long, default_value("127.0.0.1"))]
+        host: String,
+        /// The port to listen on
+        #[arg(short, long, default_value("8080"))]
+        port: u16,
+        /// The log level to use
+        #[arg(short, long)]
+        log_level: Option<LevelFilter>,
+        /// Whether to disable schema validation when loading the routes
+        #[arg(short, long, default_value("false"))]
+        no_validate: bool,
+    },
+    /// Infer a schema from sample data
+    Infer {
+        /// The path to the sample file.
+        /// Arrays and the rows of JSON lines and CSV files
+        /// are treated as a list of records.
+        sample: String,
+        /// An optional path to write the inferred schema to.
+        /// If not specified, the schema will be written to stdout.
+        out_file: Option<String>,
+        /// The format of the sample file.
+        /// If not specified, the format is selected by the extension of the file.
+        #[arg(short, long)]
+        format: Option<SampleFormat>,
+        /// The maximum number of distinct values of a string to be considered an enum
+        #[arg(long, default_value("10"))]
+        max_enum_values: usize,
+        /// Choose strings which are neither an enum nor of a known kind from the sample values
+        /// instead of generating random strings. This copies the sample values into the schema
+        #[arg(long, default_value("false"))]
+        include_samples: bool,
     },
     /// Validate a schema file
     Validate {
         /// The path to the schema file to use
         schema_file: String,
+        #[command(flatten)]
+        overrides: OverrideArgs,
+    },
+    /// Find likely mistakes in a schema file, like references to values
+    /// which don't exist or are generated after the reference
+    Lint {
+        /// The path to the schema file to use
+        schema_file: String,
+        /// The format of the findings
+        #[arg(short, long, default_value("text"))]
+        format: LintFormat,
+        /// Whether to fail if any warnings are found
+        #[arg(long, default_value("false"))]
+        deny_warnings: bool,
+        #[command(flatten)]
+        overrides: OverrideArgs,
     },
     /// Get the effective schema.
     EffectiveSchema {
@@ -59,14 +171,17 @@ enum Commands {
         /// If not specified, the data will be written to stdout.
         /// NOTE: Flatten values will not be included in the effective schema.
         out_file: Option<String>,
+        #[command(flatten)]
+        overrides: OverrideArgs,
     },
 }
 
-fn generate_random_data(
+fn generate_random_data<W: Write>(
     mut schema: Schema,
     additional_plugins: Option<HashMap<String, Arc<dyn Plugin>>>,
     progress_bar: &mut CliProgressRef,
-) -> anyhow::Result<(String, Arc<PluginList>)> {
+    writer: W,
+) -> anyhow::Result<()> {
     let plugins = PluginList::from_schema(&mut schema, additional_plugins)?;
     let options = Arc::new(schema.options.unwrap_or_default());
     let root = CurrentSchema::root(options.clone(), plugins.clone()).into();
@@ -76,42 +191,165 @@ fn generate_random_data(
     *progress_bar = CliProgressRef::with_type(CliProgressType::Serialize);
 
     let progress_bar_copy = progress_bar.clone();
-    Ok((
-        options
-            .serializer
-            .as_ref()
-            .unwrap_or_default()
-            .serialize_generated_with_progress(
-                generated,
-                Some(plugins.clone()),
-                Box::new(move |current, total| {
-                    progress_bar_copy.increase(current, total);
-                    Ok(())
-                }),
-            )?,
-        plugins,
-    ))
+    options
+        .serializer
+        .as_ref()
+        .unwrap_or_default()
+        .serialize_generated_to_with_progress(
+            generated,
+            Some(plugins),
+            Box::new(move |current, total| {
+                progress_bar_copy.increase(current, total);
+                Ok(())
+            }),
+            writer,
+        )
+}
+
+fn generate_documents(
+    mut schema: Schema,
+    additional_plugins: HashMap<String, Arc<dyn Plugin>>,
+    out_file: Option<String>,
+    compression: Option<Compression>,
+    count: u32,
+    concat: Option<ConcatFormat>,
+    progress_bar: &CliProgressRef,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    let plugins = PluginList::from_schema(&mut schema, Some(additional_plugins))?;
+    let options = Arc::new(schema.options.unwrap_or_default());
+    let serializer = match concat {
+        Some(concat) => concat.serializer(),
+        None => options.serializer.clone().unwrap_or_default(),
+    };
+
+    let generate = |write: &mut dyn FnMut(usize, Arc<GeneratedSchema>) -> anyhow::Result<()>| {
+        for n in 1..=count as usize {
+            let root = CurrentSchema::root(options.clone(), plugins.clone()).into();
+            let generated = schema
+                .value
+                .clone()
+                .into_random(root)
+                .with_context(|| format!("Failed to generate document #{n}"))?;
+            write(n, generated)?;
+
+            progress_bar.increase(n, count as _);
+        }
+
+        anyhow::Ok(())
+    };
+    let append = |concat: ConcatFormat, out: &mut dyn Write| {
+        generate(&mut |_, generated| {
+            let serialized =
+                serializer.serialize_generated_bytes(generated, Some(plugins.clone()))?;
+            concat.append(out, &serialized).map_err(Into::into)
+        })
+    };
+
+    match (concat, out_file) {
+        (Some(concat), Some(out_file)) => {
+            let compression = compression.or_else(|| Compression::from_path(&out_file));
+            write_output(create_file(&out_file)?, compression, |out| {
+                append(concat, out)
+            })?;
+
+            Ok(None)
+        }
+        (Some(concat), None) if compression.is_some() => {
+            write_output(std::io::stdout().lock(), compression, |out| {
+                append(concat, out)
+            })?;
+
+            Ok(None)
+        }
+        (Some(concat), None) => {
+            let mut concatenated = Vec::new();
+            append(concat, &mut concatenated)?;
+
+            Ok(Some(concatenated))
+        }
+        (None, Some(pattern)) => {
+            generate(&mut |n, generated| {
+                let path = document_path(&pattern, n);
+                let compression = compression.or_else(|| Compression::from_path(&path));
+                write_output(create_file(&path)?, compression, |out| {
+                    serializer.serialize_generated_to(generated, Some(plugins.clone()), out)
+                })
+            })?;
+
+            Ok(None)
+        }
+        (None, None) => anyhow::bail!("No output for the generated documents was specified"),
+    }
+}
+
+fn create_file(path: &str) -> anyhow::Result<BufWriter<File>> {
+    Ok(BufWriter::new(File::create(path).with_context(|| {
+        format!("Failed to create the file '{path}'")
+    })?))
+}
+
+/// Write the output using `write`, compressing it while it is written.
+fn write_output<W: Write>(
+    mut writer: W,
+    compression: Option<Compression>,
+    write: impl FnOnce(&mut dyn Write) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    match compression {
+        Some(compression) => {
+            let mut writer = compression.writer(writer)?;
+            write(&mut writer)?;
+            writer.finish()?;
+        }
+        None => {
+            write(&mut writer)?;
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
 }
 
 fn generate_data(
-    schema_file: String,
+    schema: Schema,
     out_file: Option<String>,
     disable_validation: bool,
+    compression: Option<Compression>,
+    count: u32,
+    concat: Option<ConcatFormat>,
     progress_bar: &mut CliProgressRef,
-) -> anyhow::Result<Option<String>> {
+) -> anyhow::Result<Option<Vec<u8>>> {
+    let pattern = out_file.as_deref().is_some_and(is_output_pattern);
+    if pattern && concat.is_some() {
+        anyhow::bail!("--concat can't be used with an output path containing '{{n}}'");
+    } else if count > 1 && !pattern && concat.is_none() {
+        anyhow::bail!(
+            "Generating multiple documents requires an output path containing '{{n}}' or --concat"
+        );
+    }
+
     let progress_bar_copy = progress_bar.clone();
-    let schema = read_schema(schema_file)?;
     if !disable_validation {
         schema.validate_root()?;
     }
 
+    // The progress of multiple documents is tracked per document.
+    // The progress plugin generates arrays and objects itself, which
+    // would hide their paths from the profile, so it's skipped while profiling.
+    let multiple = pattern || concat.is_some();
     #[cfg_attr(not(feature = "node"), allow(unused_mut))]
     let PluginWithSchemaResult {
         mut schema,
         mut plugins,
-    } = ProgressPlugin::with_schema(schema, move |current, total| {
-        progress_bar_copy.increase(current, total);
-    })?;
+    } = if multiple || profiler::is_enabled() {
+        PluginWithSchemaResult {
+            schema,
+            plugins: HashMap::new(),
+        }
+    } else {
+        ProgressPlugin::with_schema(schema, move |current, total| {
+            progress_bar_copy.increase(current, total);
+        })?
+    };
 
     #[cfg(feature = "node")]
     let (_runner, node_plugins) = NodeRunner::init(&mut schema)?;
@@ -120,21 +358,44 @@ fn generate_data(
     #[cfg(feature = "embedded-plugins")]
     plugins.extend(load_plugins(&schema)?);
 
-    let (generated, plugins) = generate_random_data(schema, Some(plugins), progress_bar)?;
-    drop(plugins);
+    if multiple {
+        return generate_documents(
+            schema,
+            plugins,
+            out_file,
+            compression,
+            count,
+            concat,
+            progress_bar,
+        );
+    }
 
-    if let Some(out_file) = out_file {
-        progress_bar.set_message("Writing results to file");
-        std::fs::write(out_file, generated)?;
+    match out_file {
+        Some(out_file) => {
+            let compression = compression.or_else(|| Compression::from_path(&out_file));
+            write_output(create_file(&out_file)?, compression, |out| {
+                generate_random_data(schema, Some(plugins), progress_bar, out)
+            })?;
 
-        Ok(None)
-    } else {
-        Ok(Some(generated))
+            Ok(None)
+        }
+        None if compression.is_some() => {
+            write_output(std::io::stdout().lock(), compression, |out| {
+                generate_random_data(schema, Some(plugins), progress_bar, out)
+            })?;
+
+            Ok(None)
+        }
+        None => {
+            let mut generated = Vec::new();
+            generate_random_data(schema, Some(plugins), progress_bar, &mut generated)?;
+
+            Ok(Some(generated))
+        }
     }
 }
 
-fn validate_schema(schema_file: String) -> anyhow::Result<()> {
-    let schema = read_schema(schema_file)?;
+fn validate_schema(schema: &Schema) -> anyhow::Result<()> {
     let Err(error) = schema.validate_root() else {
         return Ok(());
     };
@@ -159,6 +420,82 @@ fn validate_schema(schema_file: String) -> anyhow::Result<()> {
     anyhow::bail!("Found {} schema violations:\n{}", error.len(), cause);
 }
 
+fn regenerate(schema: anyhow::Result<Schema>, out_file: Option<String>) -> anyhow::Result<()> {
+    let schema = schema?;
+    validate_schema(&schema)?;
+
+    let mut progress_bar = CliProgressRef::with_type(CliProgressType::Generate);
+    let res = generate_data(schema, out_file, true, None, 1, None, &mut progress_bar);
+    progress_bar.finish(res.is_ok());
+
+    if let Some(generated) = res? {
+        std::io::stdout().write_all(&generated)?;
+        println!();
+    }
+
+    Ok(())
+}
+
+fn watch_schema(
+    schema_file: String,
+    out_file: Option<String>,
+    overrides: OverrideArgs,
+) -> anyhow::Result<()> {
+    let mut watcher = SchemaWatcher::new()?;
+
+    loop {
+        let schema = overrides.read_schema(&schema_file);
+        watcher.watch(&schema_file, schema.as_ref().ok())?;
+
+        if let Err(err) = regenerate(schema, out_file.clone()) {
+            eprintln!("{}: {err:?}", "Failed to generate data".bright_red());
+        }
+
+        eprintln!(
+            "{} for changes of {} file(s)...",
+            "Watching".bright_cyan(),
+            watcher.files().len()
+        );
+        watcher.wait_for_change()?;
+    }
+}
+
+fn infer(
+    sample: String,
+    out_file: Option<String>,
+    format: Option<SampleFormat>,
+    options: InferOptions,
+) -> anyhow::Result<()> {
+    let format = format.map_or_else(|| SampleFormat::from_path(&sample), Ok)?;
+    let schema = infer_schema(&format.read(&sample)?, &options);
+    let serialized = schema_to_string(&schema)?;
+
+    if let Some(out_file) = out_file {
+        std::fs::write(&out_file, serialized)
+            .with_context(|| format!("Failed to write the schema to '{out_file}'"))?;
+    } else {
+        println!("{serialized}");
+    }
+
+    Ok(())
+}
+
+/// Lint the schema and print the findings.
+/// Returns whether the schema passed.
+fn lint(
+    schema_file: String,
+    format: LintFormat,
+    deny_warnings: bool,
+    overrides: OverrideArgs,
+) -> anyhow::Result<bool> {
+    let schema = overrides.read_schema(&schema_file)?;
+    let findings = lint_schema(&schema);
+    println!("{}", format.format(&findings)?);
+
+    Ok(count(&findings, Severity::Error) == 0
+        && (!deny_warnings || count(&findings, Severity::Warning) == 0))
+}
+
 fn main() {
     let args = CommendLineArgs::parse();
 
@@ -166,14 +503,33 @@ fn main() {
         Commands::Generate {
             schema_file,
             out_file,
+            count,
+            concat,
             log_level,
             no_validate,
+            compress,
+            profile,
+            profile_output,
+            overrides,
         } => {
             init_logger(log_level);
 
             let mut progress_bar = CliProgressRef::with_type(CliProgressType::Generate);
+            if profile.is_some() {
+                profiler::start();
+            }
 
-            let res = generate_data(schema_file, out_file, no_validate, &mut progress_bar);
+            let res = overrides.read_schema(&schema_file).and_then(|schema| {
+                generate_data(
+                    schema,
+                    out_file,
+                    no_validate,
+                    compress,
+                    count,
+                    concat,
+                    &mut progress_bar,
+                )
+            });
             progress_bar.finish(res.is_ok());
 
             match res {
@@ -181,11 +537,67 @@ fn main() {
                     eprintln!("{:?}", err.context("Failed to generate data"));
                     exit(1);
                 }
-                Ok(Some(generated)) => {
-                    println!("{generated}");
-                }
+                Ok(Some(generated)) => match String::from_utf8(generated) {
+                    Ok(generated) => println!("{generated}"),
+                    Err(err) => {
+                        if let Err(err) = std::io::stdout().write_all(err.as_bytes()) {
+                            eprintln!("Failed to write generated data: {err}");
+                            exit(1);
+                        }
+                    }
+                },
                 Ok(None) => {}
             }
+
+            if let Some(profile) = profile {
+                if let Err(err) = profile.write(&profiler::finish(), profile_output) {
+                    eprintln!("{:?}", err.context("Failed to write the profile"));
+                    exit(1);
+                }
+            }
+        }
+        Commands::Watch {
+            schema_file,
+            out_file,
+            log_level,
+            overrides,
+        } => {
+            init_logger(log_level);
+
+            if let Err(err) = watch_schema(schema_file, out_file, overrides) {
+                eprintln!("{:?}", err.context("Failed to watch the schema"));
+                exit(1);
+            }
+        }
+        Commands::Serve {
+            config_file,
+            host,
+            port,
+            log_level,
+            no_validate,
+        } => {
+            init_logger(log_level);
+
+            if let Err(err) = serve(config_file, host, port, !no_validate) {
+                eprintln!("{:?}", err.context("Failed to start the server"));
+                exit(1);
+            }
+        }
+        Commands::Infer {
+            sample,
+            out_file,
+            format,
+            max_enum_values,
+            include_samples,
+        } => {
+            let options = InferOptions {
+                max_enum_values,
+                include_samples,
+            };
+            if let Err(err) = infer(sample, out_file, format, options) {
+                eprintln!("{:?}", err.context("Failed to infer the schema"));
+                exit(1);
+            }
         }
         Commands::WriteJsonSchema { path } => {
             if let Err(e) = write_json_schema(path) {
@@ -193,7 +605,13 @@ fn main() {
                 exit(1);
             }
         }
-        Commands::Validate { schema_file } => match validate_schema(schema_file) {
+        Commands::Validate {
+            schema_file,
+            overrides,
+        } => match overrides
+            .read_schema(&schema_file)
+            .and_then(|schema| validate_schema(&schema))
+        {
             Err(e) => {
                 eprintln!("{}: {e}", "Failed to validate the schema".bright_red());
                 exit(1);
@@ -202,11 +620,27 @@ fn main() {
                 println!("{} The schema is valid.", "Success!".bright_green());
             }
         },
+        Commands::Lint {
+            schema_file,
+            format,
+            deny_warnings,
+            overrides,
+        } => match lint(schema_file, format, deny_warnings, overrides) {
+            Ok(true) => {}
+            Ok(false) => exit(1),
+            Err(err) => {
+                eprintln!("{:?}", err.context("Failed to lint the schema"));
+                exit(1);
+            }
+        },
         Commands::EffectiveSchema {
             schema_file,
             out_file,
+            overrides,
         } => {
-            let mut schema = read_schema(schema_file).expect("Failed to read schema");
+            let mut schema = overrides
+                .read_schema(&schema_file)
+                .expect("Failed to read schema");
 
             #[cfg(feature = "embedded-plugins")]
             let plugins = load_plugins(&schema).expect("Failed to load embedded plugins");
@@ -0,0 +1,60 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// The default number of responses kept in the cache.
+pub const DEFAULT_CACHE_SIZE: usize = 100;
+
+#[derive(Default)]
+struct CacheEntries {
+    responses: HashMap<String, Arc<Vec<u8>>>,
+    /// The keys of the responses, oldest first.
+    order: VecDeque<String>,
+}
+
+/// A cache of the responses of requests with a seed.
+/// Once the cache is full, the oldest response is evicted.
+pub struct ResponseCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+impl ResponseCache {
+    /// Create a cache holding up to `capacity` responses.
+    /// A capacity of `0` disables the cache.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::default(),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<Arc<Vec<u8>>> {
+        self.entries.lock().ok()?.responses.get(key).cloned()
+    }
+
+    /// Store a response and return the cached response for the key.
+    /// Concurrent requests with the same key get the response which was stored first.
+    pub fn insert(&self, key: String, response: Vec<u8>) -> Arc<Vec<u8>> {
+        let response = Arc::new(response);
+        if self.capacity == 0 {
+            return response;
+        }
+
+        let Ok(mut entries) = self.entries.lock() else {
+            return response;
+        };
+        if let Some(cached) = entries.responses.get(&key) {
+            return cached.clone();
+        }
+
+        while entries.order.len() >= self.capacity {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.responses.remove(&oldest);
+            }
+        }
+
+        entries.order.push_back(key.clone());
+        entries.responses.insert(key, response.clone());
+        response
+    }
+}
@@ -0,0 +1,66 @@
+use rand::Rng;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// The configuration of the mock server.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ServeConfig {
+    /// The routes to serve. The first matching route is used.
+    pub routes: Vec<RouteConfig>,
+    /// The default latency of all routes.
+    pub latency: Option<Latency>,
+    /// The default probability of all routes to respond with an error.
+    pub error_rate: Option<f64>,
+    /// The default status of injected errors.
+    pub error_status: Option<u16>,
+    /// The maximum number of responses of requests
+    /// with a seed to keep. Defaults to `100`.
+    pub seed_cache_size: Option<usize>,
+}
+
+/// A route serving generated data.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RouteConfig {
+    /// The path of the route, for example `/users/{id}`.
+    /// Segments in braces match any value, which is
+    /// available as variable in the schema.
+    pub path: String,
+    /// The HTTP method of the route. Matches all methods if not set.
+    pub method: Option<String>,
+    /// The path of the schema file.
+    pub schema: String,
+    /// A JSON pointer to the part of the schema to generate.
+    /// The options of the schema are always used.
+    pub pointer: Option<String>,
+    /// The status of successful responses. Defaults to `200`.
+    pub status: Option<u16>,
+    /// The content type of the responses.
+    /// Defaults to the content type of the serializer.
+    pub content_type: Option<String>,
+    /// The latency of the route.
+    pub latency: Option<Latency>,
+    /// The probability of the route to respond with an error.
+    pub error_rate: Option<f64>,
+    /// The status of injected errors. Defaults to `500`.
+    pub error_status: Option<u16>,
+}
+
+/// The time to wait before responding, in milliseconds.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum Latency {
+    Constant(u64),
+    Random { min: u64, max: u64 },
+}
+
+impl Latency {
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(match *self {
+            Latency::Constant(value) => value,
+            Latency::Random { min, max } if min < max => rand::thread_rng().gen_range(min..=max),
+            Latency::Random { min, .. } => min,
+        })
+    }
+}
@@ -0,0 +1,170 @@
+pub mod cache;
+pub mod config;
+pub mod route;
+
+use crate::serve::cache::{ResponseCache, DEFAULT_CACHE_SIZE};
+use crate::serve::config::ServeConfig;
+use crate::serve::route::{query_parameters, Route};
+use anyhow::{anyhow, Context};
+use colored::Colorize;
+use datagen_rs::util::json_deserialize::from_reader;
+#[cfg(feature = "node")]
+use datagen_rs_node_runner::runner::node_runner::NodeRunner;
+use log::{error, info};
+use rand::Rng;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::sync::Arc;
+use tiny_http::{Header, Request, Response, Server};
+
+/// The query parameter used to seed the generated response.
+const SEED_PARAMETER: &str = "seed";
+
+/// Serve data generated from the routes in the config file
+/// until the process is terminated.
+pub fn serve(config_file: String, host: String, port: u16, validate: bool) -> anyhow::Result<()> {
+    let mut config: ServeConfig = from_reader(
+        File::open(&config_file)
+            .with_context(|| format!("Failed to open the config file '{config_file}'"))?,
+    )
+    .context("Failed to parse the config file")?;
+
+    #[cfg(feature = "node")]
+    let mut runners = Vec::<NodeRunner>::new();
+    let routes = std::mem::take(&mut config.routes)
+        .into_iter()
+        .map(|route| {
+            let path = route.path.clone();
+            Route::load(
+                route,
+                &config,
+                validate,
+                #[cfg(feature = "node")]
+                &mut runners,
+            )
+            .with_context(|| format!("Failed to load the route '{path}'"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let server = Server::http((host.as_str(), port))
+        .map_err(|e| anyhow!("Failed to listen on {host}:{port}: {e}"))?;
+    eprintln!(
+        "{} {} route(s) on {}",
+        "Serving".bright_green(),
+        routes.len(),
+        format!("http://{host}:{port}").bright_cyan()
+    );
+
+    run(
+        server,
+        routes,
+        ResponseCache::new(config.seed_cache_size.unwrap_or(DEFAULT_CACHE_SIZE)),
+    );
+
+    Ok(())
+}
+
+/// Handle the requests to the server until it is shut down.
+pub fn run(server: Server, routes: Vec<Route>, cache: ResponseCache) {
+    let routes = Arc::new(routes);
+    let cache = Arc::new(cache);
+    for request in server.incoming_requests() {
+        let routes = routes.clone();
+        let cache = cache.clone();
+        std::thread::spawn(move || handle(request, &routes, &cache));
+    }
+}
+
+fn handle(request: Request, routes: &[Route], cache: &ResponseCache) {
+    let method = request.method().as_str().to_uppercase();
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+
+    let Some((index, route, mut variables)) = routes
+        .iter()
+        .enumerate()
+        .find_map(|(i, route)| route.matches(&method, path).map(|v| (i, route, v)))
+    else {
+        return respond(
+            request,
+            404,
+            "application/json",
+            json!({ "error": format!("No route matches '{method} {path}'") })
+                .to_string()
+                .into_bytes(),
+        );
+    };
+
+    // Path variables take precedence over query parameters
+    for (key, value) in query_parameters(query) {
+        variables.entry(key).or_insert(value);
+    }
+
+    if let Some(latency) = route.latency {
+        std::thread::sleep(latency.duration());
+    }
+
+    if route.error_rate > 0.0 && rand::thread_rng().gen_bool(route.error_rate.min(1.0)) {
+        return respond(
+            request,
+            route.error_status,
+            "application/json",
+            json!({ "error": "Injected error" })
+                .to_string()
+                .into_bytes(),
+        );
+    }
+
+    let generated = match variables.get(SEED_PARAMETER) {
+        Some(seed) => {
+            // Seeded responses only depend on the route and the variables,
+            // so they are cached to avoid generating them again
+            let key = format!(
+                "{index}:{}",
+                serde_json::to_string(&variables.iter().collect::<BTreeMap<_, _>>())
+                    .unwrap_or_default()
+            );
+            match cache.get(&key) {
+                Some(cached) => Ok(cached),
+                None => route
+                    .generate(&variables, Some(seed))
+                    .map(|generated| cache.insert(key, generated)),
+            }
+        }
+        None => route.generate(&variables, None).map(Arc::new),
+    };
+
+    match generated {
+        Ok(generated) => respond(
+            request,
+            route.status,
+            &route.content_type,
+            generated.to_vec(),
+        ),
+        Err(err) => {
+            error!("Failed to generate the response of '{method} {url}': {err:?}");
+            respond(
+                request,
+                500,
+                "application/json",
+                json!({ "error": format!("{err:#}") })
+                    .to_string()
+                    .into_bytes(),
+            )
+        }
+    }
+}
+
+fn respond(request: Request, status: u16, content_type: &str, body: Vec<u8>) {
+    info!("{} {} -> {status}", request.method(), request.url());
+
+    let mut response = Response::from_data(body).with_status_code(status);
+    if let Ok(header) = Header::from_bytes("Content-Type", content_type) {
+        response.add_header(header);
+    }
+
+    if let Err(err) = request.respond(response) {
+        error!("Failed to send the response: {err}");
+    }
+}
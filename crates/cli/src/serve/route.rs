@@ -0,0 +1,202 @@
+use crate::serve::config::{Latency, RouteConfig, ServeConfig};
+#[cfg(feature = "embedded-plugins")]
+use crate::util::plugins::load_plugins;
+use anyhow::{anyhow, Context};
+use datagen_rs::generate::current_schema::CurrentSchema;
+use datagen_rs::generate::generated_schema::IntoRandom;
+use datagen_rs::plugins::plugin_list::{PluginList, PluginMap};
+use datagen_rs::schema::any_value::AnyValue;
+use datagen_rs::schema::schema_definition::{Schema, SchemaOptions};
+use datagen_rs::schema::serializer::Serializer;
+use datagen_rs::util::json_deserialize::{from_value, from_value_with_variables};
+use datagen_rs::validation::validate::Validate;
+#[cfg(feature = "node")]
+use datagen_rs_node_runner::runner::node_runner::NodeRunner;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+enum Segment {
+    Literal(String),
+    Variable(String),
+}
+
+/// A route serving data generated from a schema.
+pub struct Route {
+    method: Option<String>,
+    path: String,
+    segments: Vec<Segment>,
+    /// The schema value, with variables not yet replaced.
+    template: Value,
+    options: Arc<SchemaOptions>,
+    plugins: Arc<PluginList>,
+    serializer: Serializer,
+    pub content_type: String,
+    pub status: u16,
+    pub latency: Option<Latency>,
+    pub error_rate: f64,
+    pub error_status: u16,
+}
+
+impl Route {
+    pub fn load(
+        config: RouteConfig,
+        defaults: &ServeConfig,
+        validate: bool,
+        #[cfg(feature = "node")] runners: &mut Vec<NodeRunner>,
+    ) -> anyhow::Result<Self> {
+        let raw: Value = serde_json::from_reader(
+            File::open(&config.schema)
+                .with_context(|| format!("Failed to open the schema '{}'", config.schema))?,
+        )
+        .with_context(|| format!("Failed to parse the schema '{}'", config.schema))?;
+
+        let value = match &config.pointer {
+            Some(pointer) => raw.pointer(pointer).cloned().ok_or_else(|| {
+                anyhow!(
+                    "The pointer '{pointer}' does not exist in the schema '{}'",
+                    config.schema
+                )
+            })?,
+            None => raw.clone(),
+        };
+        // Variables are replaced on every request
+        let mut schema = Schema {
+            value: serde_json::from_value(value)?,
+            options: raw.get("options").cloned().map(from_value).transpose()?,
+        };
+        if validate {
+            schema.validate_root()?;
+        }
+
+        #[cfg_attr(not(feature = "node"), allow(unused_mut))]
+        let mut plugins = PluginMap::new();
+        #[cfg(feature = "node")]
+        {
+            let (runner, node_plugins) = NodeRunner::init(&mut schema)?;
+            plugins.extend(node_plugins);
+            runners.extend(runner);
+        }
+        #[cfg(feature = "embedded-plugins")]
+        plugins.extend(load_plugins(&schema)?);
+        let plugins = PluginList::from_schema(&mut schema, Some(plugins))?;
+
+        let options = schema.options.unwrap_or_default();
+        let serializer = options.serializer.clone().unwrap_or_default();
+
+        Ok(Self {
+            method: config.method.map(|method| method.to_uppercase()),
+            segments: split_path(&config.path)
+                .map(|segment| match segment.strip_prefix('{') {
+                    Some(name) => Segment::Variable(name.trim_end_matches('}').to_string()),
+                    None => Segment::Literal(segment.to_string()),
+                })
+                .collect(),
+            path: config.path,
+            template: serde_json::to_value(schema.value)?,
+            options: Arc::new(options),
+            plugins,
+            content_type: config
+                .content_type
+                .unwrap_or_else(|| serializer.content_type().to_string()),
+            serializer,
+            status: config.status.unwrap_or(200),
+            latency: config.latency.or(defaults.latency),
+            error_rate: config.error_rate.or(defaults.error_rate).unwrap_or(0.0),
+            error_status: config.error_status.or(defaults.error_status).unwrap_or(500),
+        })
+    }
+
+    /// Match the request against this route.
+    /// Returns the values of the path variables if the route matches.
+    pub fn matches(&self, method: &str, path: &str) -> Option<HashMap<String, String>> {
+        if self.method.as_ref().is_some_and(|m| m != method) {
+            return None;
+        }
+
+        let segments = split_path(path).collect::<Vec<_>>();
+        if segments.len() != self.segments.len() {
+            return None;
+        }
+
+        let mut variables = HashMap::new();
+        for (segment, expected) in segments.into_iter().zip(&self.segments) {
+            match expected {
+                Segment::Literal(literal) if literal == segment => {}
+                Segment::Literal(_) => return None,
+                Segment::Variable(name) => {
+                    variables.insert(name.clone(), decode(segment));
+                }
+            }
+        }
+
+        Some(variables)
+    }
+
+    /// Generate and serialize a new response.
+    /// Responses generated with a seed are the same for the same seed and variables.
+    pub fn generate(
+        &self,
+        variables: &HashMap<String, String>,
+        seed: Option<&str>,
+    ) -> anyhow::Result<Vec<u8>> {
+        let value: AnyValue = from_value_with_variables(self.template.clone(), variables)?;
+        let root = match seed {
+            Some(seed) => CurrentSchema::seeded_root(
+                self.options.clone(),
+                self.plugins.clone(),
+                self.seed(seed, variables),
+            ),
+            None => CurrentSchema::root(self.options.clone(), self.plugins.clone()),
+        };
+        let generated = value.into_random(root.into())?;
+
+        self.serializer
+            .serialize_generated_bytes(generated, Some(self.plugins.clone()))
+    }
+
+    /// Derive the seed of the random number generator from the seed,
+    /// the route and the variables, so the same seed generates
+    /// different data for different routes and variables.
+    fn seed(&self, seed: &str, variables: &HashMap<String, String>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        self.method.hash(&mut hasher);
+        self.path.hash(&mut hasher);
+        variables
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
+
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Parse the parameters of a query string.
+/// Parameters without a value are set to an empty string.
+pub fn query_parameters(query: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+        .map(|(key, value)| {
+            (
+                decode(&key.replace('+', " ")),
+                decode(&value.replace('+', " ")),
+            )
+        })
+}
+
+/// Decode a percent-encoded URL component.
+pub fn decode(value: &str) -> String {
+    urlencoding::decode(value)
+        .map(|decoded| decoded.into_owned())
+        .unwrap_or_else(|_| value.to_string())
+}
@@ -0,0 +1,137 @@
+use crate::generate_data;
+use crate::util::cli_progress::{CliProgressRef, CliProgressTrait, CliProgressType};
+use crate::util::documents::ConcatFormat;
+use datagen_rs::schema::schema_definition::Schema;
+use datagen_rs::util::compression::Compression;
+use serde_json::{json, Value};
+
+const EXPECTED: &str = r#"[{"id":1,"name":"test"},{"id":1,"name":"test"}]"#;
+
+fn schema() -> Schema {
+    serde_json::from_value(json!({
+        "type": "array",
+        "length": 2,
+        "items": {
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "value": 1},
+                "name": "test"
+            }
+        }
+    }))
+    .unwrap()
+}
+
+fn out_file(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("datagen-generate-{}-{name}", std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn generate(
+    schema: Schema,
+    out_file: Option<String>,
+    compression: Option<Compression>,
+    count: u32,
+    concat: Option<ConcatFormat>,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    generate_data(
+        schema,
+        out_file,
+        false,
+        compression,
+        count,
+        concat,
+        &mut CliProgressRef::with_type(CliProgressType::Generate),
+    )
+}
+
+#[test]
+fn test_generate_to_stdout() {
+    let generated = generate(schema(), None, None, 1, None).unwrap().unwrap();
+
+    assert_eq!(String::from_utf8(generated).unwrap(), EXPECTED);
+}
+
+#[test]
+fn test_generate_compressed_file() {
+    for (name, compression) in [
+        ("out.json.gz", None),
+        ("out.json", Some(Compression::Gzip)),
+        ("out.json.zst", None),
+    ] {
+        let path = out_file(name);
+        assert!(generate(schema(), Some(path.clone()), compression, 1, None)
+            .unwrap()
+            .is_none());
+
+        let expected = compression
+            .or_else(|| Compression::from_path(&path))
+            .unwrap()
+            .compress(EXPECTED.as_bytes())
+            .unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), expected, "{name}");
+    }
+}
+
+#[test]
+fn test_generate_concatenated_file() {
+    let path = out_file("out.jsonl.gz");
+    generate(
+        schema(),
+        Some(path.clone()),
+        None,
+        2,
+        Some(ConcatFormat::Jsonl),
+    )
+    .unwrap();
+
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        Compression::Gzip
+            .compress(format!("{EXPECTED}\n{EXPECTED}\n").as_bytes())
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_generate_documents() {
+    let pattern = out_file("doc-{n}.json");
+    generate(schema(), Some(pattern.clone()), None, 2, None).unwrap();
+
+    for n in 1..=2 {
+        let path = pattern.replace("{n}", &n.to_string());
+        let generated: Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(generated, serde_json::from_str::<Value>(EXPECTED).unwrap());
+    }
+}
+
+#[test]
+fn test_generate_unique_refill_with_progress() {
+    // The progress plugin is used when a single document is generated
+    let schema = serde_json::from_value(json!({
+        "type": "array",
+        "length": 10,
+        "items": {
+            "type": "array",
+            "length": 3,
+            "items": {
+                "type": "array",
+                "length": {"min": 1, "max": 1},
+                "items": {"type": "integer", "min": 1, "max": 3}
+            },
+            "transform": [{"type": "unique", "refill": true}]
+        }
+    }))
+    .unwrap();
+
+    let generated = generate(schema, None, None, 1, None).unwrap().unwrap();
+    let generated: Vec<Vec<Vec<i64>>> = serde_json::from_slice(&generated).unwrap();
+
+    assert_eq!(generated.len(), 10);
+    for mut items in generated {
+        items.sort();
+        assert_eq!(items, vec![vec![1], vec![2], vec![3]]);
+    }
+}
@@ -0,0 +1,3 @@
+mod generate;
+mod serve;
+mod util;
@@ -0,0 +1,39 @@
+use crate::serve::cache::ResponseCache;
+
+#[test]
+fn test_cache_get() {
+    let cache = ResponseCache::new(2);
+    assert!(cache.get("a").is_none());
+
+    assert_eq!(*cache.insert("a".into(), b"1".to_vec()), b"1");
+    assert_eq!(*cache.get("a").unwrap(), b"1");
+}
+
+#[test]
+fn test_cache_keeps_first_response() {
+    let cache = ResponseCache::new(2);
+    cache.insert("a".into(), b"1".to_vec());
+
+    assert_eq!(*cache.insert("a".into(), b"2".to_vec()), b"1");
+    assert_eq!(*cache.get("a").unwrap(), b"1");
+}
+
+#[test]
+fn test_cache_evicts_oldest() {
+    let cache = ResponseCache::new(2);
+    cache.insert("a".into(), b"1".to_vec());
+    cache.insert("b".into(), b"2".to_vec());
+    cache.insert("c".into(), b"3".to_vec());
+
+    assert!(cache.get("a").is_none());
+    assert_eq!(*cache.get("b").unwrap(), b"2");
+    assert_eq!(*cache.get("c").unwrap(), b"3");
+}
+
+#[test]
+fn test_cache_disabled() {
+    let cache = ResponseCache::new(0);
+
+    assert_eq!(*cache.insert("a".into(), b"1".to_vec()), b"1");
+    assert!(cache.get("a").is_none());
+}
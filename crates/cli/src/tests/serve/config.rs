@@ -0,0 +1,89 @@
+use crate::serve::config::{Latency, ServeConfig};
+use serde_json::{from_value, json};
+use std::time::Duration;
+
+#[test]
+fn test_parse_config() {
+    let config: ServeConfig = from_value(json!({
+        "latency": {"min": 50, "max": 200},
+        "errorRate": 0.1,
+        "errorStatus": 503,
+        "seedCacheSize": 10,
+        "routes": [
+            {
+                "path": "/users/{id}",
+                "method": "get",
+                "schema": "users.json",
+                "pointer": "/items",
+                "status": 201,
+                "contentType": "text/plain",
+                "latency": 10,
+                "errorRate": 0.5,
+                "errorStatus": 502
+            },
+            {
+                "path": "/orders",
+                "schema": "orders.json"
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert!(matches!(
+        config.latency,
+        Some(Latency::Random { min: 50, max: 200 })
+    ));
+    assert_eq!(config.error_rate, Some(0.1));
+    assert_eq!(config.error_status, Some(503));
+    assert_eq!(config.seed_cache_size, Some(10));
+    assert_eq!(config.routes.len(), 2);
+
+    let route = &config.routes[0];
+    assert_eq!(route.path, "/users/{id}");
+    assert_eq!(route.method.as_deref(), Some("get"));
+    assert_eq!(route.schema, "users.json");
+    assert_eq!(route.pointer.as_deref(), Some("/items"));
+    assert_eq!(route.status, Some(201));
+    assert_eq!(route.content_type.as_deref(), Some("text/plain"));
+    assert!(matches!(route.latency, Some(Latency::Constant(10))));
+    assert_eq!(route.error_rate, Some(0.5));
+    assert_eq!(route.error_status, Some(502));
+
+    let route = &config.routes[1];
+    assert!(route.method.is_none());
+    assert!(route.latency.is_none());
+    assert!(route.error_rate.is_none());
+}
+
+#[test]
+fn test_parse_invalid_config() {
+    assert!(from_value::<ServeConfig>(json!({})).is_err());
+    assert!(from_value::<ServeConfig>(json!({
+        "routes": [{"path": "/users"}]
+    }))
+    .is_err());
+    assert!(from_value::<ServeConfig>(json!({
+        "routes": [{"path": "/users", "schema": "users.json", "errorrate": 1}]
+    }))
+    .is_err());
+    assert!(from_value::<ServeConfig>(json!({
+        "routes": [],
+        "latency": {"min": 1}
+    }))
+    .is_err());
+}
+
+#[test]
+fn test_latency_duration() {
+    assert_eq!(Latency::Constant(20).duration(), Duration::from_millis(20));
+    assert_eq!(
+        Latency::Random { min: 30, max: 10 }.duration(),
+        Duration::from_millis(30)
+    );
+
+    for _ in 0..100 {
+        let duration = Latency::Random { min: 10, max: 20 }.duration();
+        assert!(duration >= Duration::from_millis(10));
+        assert!(duration <= Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::serve::config::{RouteConfig, ServeConfig};
+use crate::serve::route::Route;
+use serde_json::Value;
+use std::path::PathBuf;
+
+mod cache;
+mod config;
+mod route;
+mod server;
+
+/// Write the schema to a temporary file and return its path.
+fn write_schema(name: &str, schema: Value) -> String {
+    let path: PathBuf =
+        std::env::temp_dir().join(format!("datagen-serve-{}-{name}.json", std::process::id()));
+    std::fs::write(&path, schema.to_string()).unwrap();
+
+    path.to_string_lossy().into_owned()
+}
+
+fn load_route(config: Value, defaults: &ServeConfig) -> Route {
+    Route::load(
+        serde_json::from_value::<RouteConfig>(config).unwrap(),
+        defaults,
+        true,
+        #[cfg(feature = "node")]
+        &mut Vec::new(),
+    )
+    .unwrap()
+}
+
+fn no_defaults() -> ServeConfig {
+    serde_json::from_value(serde_json::json!({"routes": []})).unwrap()
+}
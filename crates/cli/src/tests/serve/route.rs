@@ -0,0 +1,130 @@
+use crate::serve::route::query_parameters;
+use crate::tests::serve::{load_route, no_defaults, write_schema};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_route_matches() {
+    let schema = write_schema("matches", json!("value"));
+    let route = load_route(
+        json!({"path": "/users/{id}/posts", "method": "get", "schema": schema}),
+        &no_defaults(),
+    );
+
+    assert_eq!(
+        route.matches("GET", "/users/42/posts"),
+        Some(HashMap::from([("id".to_string(), "42".to_string())]))
+    );
+    assert_eq!(
+        route.matches("GET", "users/a%20b/posts/"),
+        Some(HashMap::from([("id".to_string(), "a b".to_string())]))
+    );
+    assert_eq!(route.matches("POST", "/users/42/posts"), None);
+    assert_eq!(route.matches("GET", "/users/42"), None);
+    assert_eq!(route.matches("GET", "/users/42/posts/1"), None);
+    assert_eq!(route.matches("GET", "/accounts/42/posts"), None);
+}
+
+#[test]
+fn test_route_matches_any_method() {
+    let schema = write_schema("any-method", json!("value"));
+    let route = load_route(json!({"path": "/", "schema": schema}), &no_defaults());
+
+    assert_eq!(route.matches("GET", "/"), Some(HashMap::new()));
+    assert_eq!(route.matches("DELETE", ""), Some(HashMap::new()));
+    assert_eq!(route.matches("GET", "/users"), None);
+}
+
+#[test]
+fn test_route_defaults() {
+    let schema = write_schema("defaults", json!("value"));
+    let defaults = serde_json::from_value(json!({
+        "routes": [],
+        "latency": 10,
+        "errorRate": 0.5,
+        "errorStatus": 503
+    }))
+    .unwrap();
+
+    let route = load_route(json!({"path": "/", "schema": schema}), &defaults);
+    assert_eq!(route.status, 200);
+    assert_eq!(route.content_type, "application/json");
+    assert!(route.latency.is_some());
+    assert_eq!(route.error_rate, 0.5);
+    assert_eq!(route.error_status, 503);
+
+    let route = load_route(
+        json!({"path": "/", "schema": schema, "errorRate": 0, "errorStatus": 502}),
+        &defaults,
+    );
+    assert_eq!(route.error_rate, 0.0);
+    assert_eq!(route.error_status, 502);
+}
+
+#[test]
+fn test_route_generate() {
+    let schema = write_schema(
+        "generate",
+        json!({
+            "type": "object",
+            "properties": {
+                "id": "${id}",
+                "status": "${status:-active}"
+            }
+        }),
+    );
+    let route = load_route(json!({"path": "/{id}", "schema": schema}), &no_defaults());
+
+    let generated = route
+        .generate(&HashMap::from([("id".to_string(), "42".to_string())]), None)
+        .unwrap();
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&generated).unwrap(),
+        json!({"id": "42", "status": "active"})
+    );
+}
+
+#[test]
+fn test_route_generate_seeded() {
+    let schema = write_schema(
+        "seeded",
+        json!({
+            "type": "array",
+            "length": {"min": 1, "max": 10},
+            "items": {
+                "type": "object",
+                "properties": {
+                    "id": "${id}",
+                    "name": {"type": "string", "generator": {"type": "fullName"}},
+                    "age": {"type": "integer", "min": 0, "max": 100}
+                }
+            }
+        }),
+    );
+    let variables = |id: &str| HashMap::from([("id".to_string(), id.to_string())]);
+    // Each response is generated by a freshly loaded route
+    let generate = |id: &str, seed: Option<&str>| {
+        load_route(json!({"path": "/{id}", "schema": schema}), &no_defaults())
+            .generate(&variables(id), seed)
+            .unwrap()
+    };
+
+    assert_eq!(generate("1", Some("a")), generate("1", Some("a")));
+    assert_ne!(generate("1", Some("a")), generate("1", Some("b")));
+}
+
+#[test]
+fn test_query_parameters() {
+    assert_eq!(
+        query_parameters("a=1&b=&c&&d=x+y&e=%20z%26&f%3D=1").collect::<Vec<_>>(),
+        vec![
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "".to_string()),
+            ("c".to_string(), "".to_string()),
+            ("d".to_string(), "x y".to_string()),
+            ("e".to_string(), " z&".to_string()),
+            ("f=".to_string(), "1".to_string()),
+        ]
+    );
+    assert_eq!(query_parameters("").count(), 0);
+}
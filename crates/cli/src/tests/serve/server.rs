@@ -0,0 +1,104 @@
+use crate::serve::cache::ResponseCache;
+use crate::serve::run;
+use crate::tests::serve::{load_route, no_defaults, write_schema};
+use serde_json::{json, Value};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+use tiny_http::Server;
+
+/// Start a server on a random port and return the port.
+fn start(routes: Vec<Value>) -> u16 {
+    let routes = routes
+        .into_iter()
+        .map(|route| load_route(route, &no_defaults()))
+        .collect();
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+    std::thread::spawn(move || run(server, routes, ResponseCache::new(10)));
+
+    port
+}
+
+/// Send a GET request and return the status and the body of the response.
+fn get(port: u16, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    write!(
+        stream,
+        "GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+
+    (status, body.to_string())
+}
+
+#[test]
+fn test_serve_variables() {
+    let schema = write_schema(
+        "server-variables",
+        json!({
+            "type": "object",
+            "properties": {
+                "id": "${id}",
+                "status": "${status:-active}"
+            }
+        }),
+    );
+    let port = start(vec![json!({"path": "/users/{id}", "schema": schema})]);
+
+    let (status, body) = get(port, "/users/42?status=blocked&id=1");
+    assert_eq!(status, 200);
+    assert_eq!(
+        serde_json::from_str::<Value>(&body).unwrap(),
+        json!({"id": "42", "status": "blocked"})
+    );
+
+    let (status, body) = get(port, "/orders");
+    assert_eq!(status, 404);
+    assert!(body.contains("No route matches 'GET /orders'"));
+}
+
+#[test]
+fn test_serve_seed() {
+    let schema = write_schema(
+        "server-seed",
+        json!({"type": "integer", "min": 0, "max": i64::MAX}),
+    );
+    let port = start(vec![json!({"path": "/", "schema": schema})]);
+
+    let (_, first) = get(port, "/?seed=1");
+    assert_eq!(get(port, "/?seed=1").1, first);
+    assert_ne!(get(port, "/?seed=2").1, first);
+}
+
+#[test]
+fn test_serve_error_injection() {
+    let schema = write_schema("server-errors", json!("value"));
+    let port = start(vec![
+        json!({"path": "/errors", "schema": schema, "errorRate": 1, "errorStatus": 503}),
+        json!({"path": "/", "schema": schema, "errorRate": 0}),
+    ]);
+
+    for _ in 0..10 {
+        let (status, body) = get(port, "/errors");
+        assert_eq!(status, 503);
+        assert!(body.contains("Injected error"));
+
+        assert_eq!(get(port, "/"), (200, "\"value\"".to_string()));
+    }
+}
+
+#[test]
+fn test_serve_latency() {
+    let schema = write_schema("server-latency", json!("value"));
+    let port = start(vec![json!({"path": "/", "schema": schema, "latency": 200})]);
+
+    let started = Instant::now();
+    assert_eq!(get(port, "/").0, 200);
+    assert!(started.elapsed() >= Duration::from_millis(200));
+}
@@ -0,0 +1,50 @@
+use crate::util::documents::{document_path, is_output_pattern, ConcatFormat};
+use datagen_rs::schema::serializer::Serializer;
+
+#[test]
+fn test_output_pattern() {
+    assert!(is_output_pattern("out/{n}.json"));
+    assert!(is_output_pattern("{n}"));
+    assert!(!is_output_pattern("out/n.json"));
+    assert!(!is_output_pattern("out/{}.json"));
+}
+
+#[test]
+fn test_document_path() {
+    assert_eq!(document_path("out/{n}.json", 0), "out/0.json");
+    assert_eq!(document_path("out/{n}.json", 42), "out/42.json");
+    assert_eq!(document_path("{n}/data-{n}.json", 3), "3/data-3.json");
+}
+
+#[test]
+fn test_concat_serializer() {
+    assert!(matches!(
+        ConcatFormat::Jsonl.serializer(),
+        Serializer::Json {
+            pretty: Some(false)
+        }
+    ));
+    assert!(matches!(ConcatFormat::Yaml.serializer(), Serializer::Yaml));
+}
+
+#[test]
+fn test_concat_jsonl() {
+    let mut out = Vec::new();
+    ConcatFormat::Jsonl
+        .append(&mut out, br#"{"id":1}"#)
+        .unwrap();
+    ConcatFormat::Jsonl
+        .append(&mut out, br#"{"id":2}"#)
+        .unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), "{\"id\":1}\n{\"id\":2}\n");
+}
+
+#[test]
+fn test_concat_yaml() {
+    let mut out = Vec::new();
+    ConcatFormat::Yaml.append(&mut out, b"id: 1\n").unwrap();
+    ConcatFormat::Yaml.append(&mut out, b"id: 2").unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), "---\nid: 1\n---\nid: 2\n");
+}
@@ -0,0 +1,3 @@
+mod documents;
+mod overrides;
+mod sample;
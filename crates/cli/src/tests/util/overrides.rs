@@ -0,0 +1,157 @@
+use crate::util::overrides::{apply_overrides, SchemaOverride};
+use serde_json::{json, Value};
+
+fn apply(arg: &str, mut target: Value) -> anyhow::Result<Value> {
+    arg.parse::<SchemaOverride>()?.apply(&mut target)?;
+    Ok(target)
+}
+
+#[test]
+fn test_override_nested() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "name": "Jane",
+            "a/b": 1,
+            "c~d": 2
+        }
+    });
+
+    assert_eq!(
+        apply("/properties/name=John", schema.clone()).unwrap(),
+        json!({"type": "object", "properties": {"name": "John", "a/b": 1, "c~d": 2}})
+    );
+    assert_eq!(
+        apply("/properties/a~1b=3", schema.clone()).unwrap(),
+        json!({"type": "object", "properties": {"name": "Jane", "a/b": 3, "c~d": 2}})
+    );
+    assert_eq!(
+        apply("/properties/c~0d={\"value\": 4}", schema).unwrap(),
+        json!({"type": "object", "properties": {"name": "Jane", "a/b": 1, "c~d": {"value": 4}}})
+    );
+}
+
+#[test]
+fn test_override_root() {
+    assert_eq!(
+        apply("=\"value\"", json!({"a": 1})).unwrap(),
+        json!("value")
+    );
+}
+
+#[test]
+fn test_override_unset_option() {
+    assert_eq!(
+        apply(
+            "/options/serializer={\"type\": \"yaml\"}",
+            json!({"type": "string", "options": null})
+        )
+        .unwrap(),
+        json!({"type": "string", "options": {"serializer": {"type": "yaml"}}})
+    );
+}
+
+#[test]
+fn test_override_array_index() {
+    let schema = json!({"type": "array", "values": ["a", "b"]});
+
+    assert_eq!(
+        apply("/values/1=c", schema.clone()).unwrap(),
+        json!({"type": "array", "values": ["a", "c"]})
+    );
+    assert!(
+        format!("{:?}", apply("/values/2=c", schema.clone()).unwrap_err())
+            .contains("The path '/values/2' does not exist: '2' was not found at '/values'")
+    );
+    assert!(apply("/values/first=c", schema).is_err());
+}
+
+#[test]
+fn test_override_array_append() {
+    assert_eq!(
+        apply(
+            "/values/-=c",
+            json!({"type": "array", "values": ["a", "b"]})
+        )
+        .unwrap(),
+        json!({"type": "array", "values": ["a", "b", "c"]})
+    );
+}
+
+#[test]
+fn test_override_missing_path() {
+    let schema = json!({"type": "array", "length": {"value": 1}, "items": "a"});
+
+    assert!(
+        format!("{:?}", apply("/lenght=5", schema.clone()).unwrap_err())
+            .contains("The path '/lenght' does not exist: 'lenght' was not found at '/'")
+    );
+    assert!(format!(
+        "{:?}",
+        apply("/length/vlaue=5", schema.clone()).unwrap_err()
+    )
+    .contains("'vlaue' was not found at '/length'"));
+    assert!(format!(
+        "{:?}",
+        apply("/missing/value=5", schema.clone()).unwrap_err()
+    )
+    .contains("'missing' was not found at '/'"));
+    assert!(
+        format!("{:?}", apply("/items/value=5", schema).unwrap_err())
+            .contains("The value at '/items' is neither an object nor an array")
+    );
+}
+
+#[test]
+fn test_override_invalid_argument() {
+    assert!(
+        format!("{:?}", "/length".parse::<SchemaOverride>().unwrap_err())
+            .contains("Expected '<path>=<value>', but got '/length'")
+    );
+    assert!(
+        format!("{:?}", "length=5".parse::<SchemaOverride>().unwrap_err())
+            .contains("must be a JSON pointer starting with '/'")
+    );
+}
+
+#[test]
+fn test_apply_overrides_to_shorthand() {
+    let schema = apply_overrides(
+        json!({"type": "array", "length": 10, "items": "a"}),
+        vec!["/length=5".parse().unwrap(), "/items=b".parse().unwrap()],
+    )
+    .unwrap();
+
+    let value = serde_json::to_value(schema.value).unwrap();
+    assert_eq!(value["length"], json!(5));
+    assert_eq!(value["items"], json!("b"));
+}
+
+#[test]
+fn test_apply_overrides_to_unset_options() {
+    let schema = apply_overrides(
+        json!({"type": "string", "value": "a"}),
+        vec!["/options/serializer={\"type\": \"yaml\"}".parse().unwrap()],
+    )
+    .unwrap();
+    assert!(schema.options.unwrap().serializer.is_some());
+
+    let schema = apply_overrides(
+        json!({"type": "string", "value": "a", "options": {"maxRefCacheSize": 1}}),
+        vec!["/options/serializeNonStrings=true".parse().unwrap()],
+    )
+    .unwrap();
+    let options = schema.options.unwrap();
+    assert_eq!(options.max_ref_cache_size, Some(1));
+    assert_eq!(options.serialize_non_strings, Some(true));
+
+    assert!(format!(
+        "{:?}",
+        apply_overrides(
+            json!({"type": "string", "value": "a"}),
+            vec!["/options/serialzer=yaml".parse().unwrap()],
+        )
+        .unwrap_err()
+    )
+    .contains("'serialzer' was not found at '/options'"));
+}
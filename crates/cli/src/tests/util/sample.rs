@@ -0,0 +1,84 @@
+use crate::util::sample::{parse_cell, SampleFormat};
+use serde_json::{json, Value};
+
+fn write_sample(name: &str, contents: &str) -> String {
+    let path = std::env::temp_dir().join(format!("datagen-sample-{}-{name}", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_parse_cell() {
+    assert_eq!(parse_cell(""), Value::Null);
+    assert_eq!(parse_cell("true"), json!(true));
+    assert_eq!(parse_cell("false"), json!(false));
+    assert_eq!(parse_cell("1"), json!(1));
+    assert_eq!(parse_cell("-42"), json!(-42));
+    assert_eq!(parse_cell("1.5"), json!(1.5));
+    assert_eq!(parse_cell("1e3"), json!(1000.0));
+}
+
+#[test]
+fn test_parse_cell_strings() {
+    assert_eq!(parse_cell("True"), json!("True"));
+    assert_eq!(parse_cell(" 1"), json!(" 1"));
+    assert_eq!(parse_cell("abc"), json!("abc"));
+    assert_eq!(parse_cell("NaN"), json!("NaN"));
+    assert_eq!(parse_cell("inf"), json!("inf"));
+    assert_eq!(parse_cell("null"), json!("null"));
+}
+
+#[test]
+fn test_format_from_path() {
+    assert!(matches!(
+        SampleFormat::from_path("data.json").unwrap(),
+        SampleFormat::Json
+    ));
+    assert!(matches!(
+        SampleFormat::from_path("data.JSONL").unwrap(),
+        SampleFormat::Jsonl
+    ));
+    assert!(matches!(
+        SampleFormat::from_path("data.ndjson").unwrap(),
+        SampleFormat::Jsonl
+    ));
+    assert!(matches!(
+        SampleFormat::from_path("data.csv").unwrap(),
+        SampleFormat::Csv
+    ));
+    assert!(SampleFormat::from_path("data.txt").is_err());
+    assert!(SampleFormat::from_path("data").is_err());
+}
+
+#[test]
+fn test_read_csv() {
+    let path = write_sample(
+        "read.csv",
+        "name,age,active,score\nJane,42,true,1.5\nJohn,,false,\n",
+    );
+
+    assert_eq!(
+        SampleFormat::Csv.read(&path).unwrap(),
+        json!([
+            {"name": "Jane", "age": 42, "active": true, "score": 1.5},
+            {"name": "John", "age": null, "active": false, "score": null}
+        ])
+    );
+}
+
+#[test]
+fn test_read_jsonl() {
+    let path = write_sample("read.jsonl", "{\"id\": 1}\n\n{\"id\": 2}\n");
+
+    assert_eq!(
+        SampleFormat::Jsonl.read(&path).unwrap(),
+        json!([{"id": 1}, {"id": 2}])
+    );
+
+    let path = write_sample("invalid.jsonl", "{\"id\": 1}\n{\"id\":\n");
+    assert!(
+        format!("{:?}", SampleFormat::Jsonl.read(&path).unwrap_err())
+            .contains("Failed to parse line 2")
+    );
+}
@@ -10,7 +10,6 @@ use std::time::Duration;
 pub trait CliProgressTrait {
     fn with_type(ty: CliProgressType) -> Self;
     fn increase(&self, current: usize, total: usize);
-    fn set_message(&self, msg: &'static str);
     fn finish(&self, ok: bool);
 }
 
@@ -41,7 +40,7 @@ impl CliProgress {
                     format!("~{}", total.to_formatted_string(&Locale::en))
                 );
             } else {
-                println!(
+                eprintln!(
                     "{}ing {} records",
                     self.ty,
                     format!("~{}", total.to_formatted_string(&Locale::en)).bright_cyan()
@@ -82,12 +81,6 @@ impl CliProgress {
         self.pb.as_ref().unwrap().set_position(current as _);
     }
 
-    pub fn set_message(&self, msg: &'static str) {
-        if let Some(pb) = self.pb.as_ref() {
-            pb.set_message(msg);
-        }
-    }
-
     pub fn finish(&self, ok: bool) {
         if let Some(pb) = self.pb.as_ref() {
             if ok {
@@ -100,7 +93,7 @@ impl CliProgress {
                         pb.elapsed()
                     );
                 } else {
-                    println!(
+                    eprintln!(
                         "Success - {}ed {} records in {}",
                         self.ty,
                         pb.position().to_formatted_string(&Locale::en).bright_blue(),
@@ -129,10 +122,6 @@ impl CliProgressTrait for CliProgressRef {
         self.lock().unwrap().increase(current, total);
     }
 
-    fn set_message(&self, msg: &'static str) {
-        self.lock().unwrap().set_message(msg);
-    }
-
     fn finish(&self, ok: bool) {
         self.lock().unwrap().finish(ok);
     }
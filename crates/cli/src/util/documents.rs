@@ -0,0 +1,58 @@
+use clap::ValueEnum;
+use datagen_rs::schema::serializer::Serializer;
+use std::io::Write;
+
+/// The placeholder in output file names which is
+/// replaced with the number of the generated document.
+const DOCUMENT_NUMBER_PLACEHOLDER: &str = "{n}";
+
+/// The format used to write multiple generated documents to a single output.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ConcatFormat {
+    /// One JSON document per line.
+    Jsonl,
+    /// A YAML stream with one document per `---` section.
+    Yaml,
+}
+
+impl ConcatFormat {
+    /// The serializer used to serialize a single document.
+    pub fn serializer(&self) -> Serializer {
+        match self {
+            ConcatFormat::Jsonl => Serializer::Json {
+                pretty: Some(false),
+            },
+            ConcatFormat::Yaml => Serializer::Yaml,
+        }
+    }
+
+    /// Append a serialized document to the output.
+    pub fn append<W: Write + ?Sized>(&self, out: &mut W, document: &[u8]) -> std::io::Result<()> {
+        match self {
+            ConcatFormat::Jsonl => {
+                out.write_all(document)?;
+                out.write_all(b"\n")
+            }
+            ConcatFormat::Yaml => {
+                out.write_all(b"---\n")?;
+                out.write_all(document)?;
+                if !document.ends_with(b"\n") {
+                    out.write_all(b"\n")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Check whether the output file is a pattern
+/// which creates one file per generated document.
+pub fn is_output_pattern(out_file: &str) -> bool {
+    out_file.contains(DOCUMENT_NUMBER_PLACEHOLDER)
+}
+
+/// Get the path of the `n`-th document for an output pattern.
+pub fn document_path(pattern: &str, n: usize) -> String {
+    pattern.replace(DOCUMENT_NUMBER_PLACEHOLDER, &n.to_string())
+}
@@ -0,0 +1,71 @@
+use clap::ValueEnum;
+use colored::Colorize;
+use datagen_rs::lint::{Finding, Severity};
+
+/// The format of the lint findings.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LintFormat {
+    /// One finding per line, with the suggested fix below it.
+    Text,
+    /// The findings as a JSON array.
+    Json,
+}
+
+impl LintFormat {
+    pub fn format(&self, findings: &[Finding]) -> anyhow::Result<String> {
+        match self {
+            LintFormat::Text => Ok(text(findings)),
+            LintFormat::Json => Ok(serde_json::to_string_pretty(findings)?),
+        }
+    }
+}
+
+fn text(findings: &[Finding]) -> String {
+    let mut lines = findings
+        .iter()
+        .map(|finding| {
+            let severity = match finding.severity {
+                Severity::Error => finding.severity.to_string().bright_red(),
+                Severity::Warning => finding.severity.to_string().bright_yellow(),
+            };
+            let path = if finding.path.is_empty() {
+                "the root"
+            } else {
+                &finding.path
+            };
+
+            let mut line = format!(
+                "{severity}[{}] at {}: {}",
+                finding.kind,
+                path.bright_cyan(),
+                finding.message
+            );
+            if let Some(fix) = &finding.fix {
+                line.push_str(&format!("\n  {} {fix}", "fix:".bright_green()));
+            }
+
+            line
+        })
+        .collect::<Vec<_>>();
+
+    let errors = count(findings, Severity::Error);
+    let warnings = count(findings, Severity::Warning);
+    lines.push(if findings.is_empty() {
+        format!("{} No problems found.", "Success!".bright_green())
+    } else {
+        format!(
+            "Found {errors} error{} and {warnings} warning{}.",
+            if errors == 1 { "" } else { "s" },
+            if warnings == 1 { "" } else { "s" }
+        )
+    });
+
+    lines.join("\n")
+}
+
+pub fn count(findings: &[Finding], severity: Severity) -> usize {
+    findings
+        .iter()
+        .filter(|finding| finding.severity == severity)
+        .count()
+}
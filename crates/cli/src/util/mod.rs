@@ -1,4 +1,10 @@
 pub mod cli_progress;
+pub mod documents;
+pub mod lint;
 pub mod logger;
+pub mod overrides;
 #[cfg(feature = "embedded-plugins")]
 pub mod plugins;
+pub mod profile;
+pub mod sample;
+pub mod watch;
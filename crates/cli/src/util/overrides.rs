@@ -0,0 +1,214 @@
+use anyhow::{anyhow, Context};
+use clap::Args;
+use datagen_rs::schema::schema_definition::{Schema, SchemaOptions};
+use datagen_rs::util::helpers::read_schema;
+use datagen_rs::util::json_deserialize::from_value;
+use serde_json::{Map, Value};
+use std::fs::File;
+use std::io::BufReader;
+use std::str::FromStr;
+
+/// A value which overrides a part of the schema.
+/// Parsed from `<JSON pointer>=<value>`.
+#[derive(Debug, Clone)]
+pub struct SchemaOverride {
+    pointer: String,
+    value: Value,
+}
+
+impl SchemaOverride {
+    fn split(arg: &str) -> anyhow::Result<(String, &str)> {
+        let (pointer, value) = arg
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected '<path>=<value>', but got '{arg}'"))?;
+        if !pointer.is_empty() && !pointer.starts_with('/') {
+            anyhow::bail!("The path '{pointer}' must be a JSON pointer starting with '/'");
+        }
+
+        Ok((pointer.to_string(), value))
+    }
+
+    /// Parse the value as JSON.
+    /// Values which aren't valid JSON are used as strings.
+    fn parse_value(value: &str) -> Value {
+        serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()))
+    }
+
+    /// Parse an override from `<path>=<file>`, using the contents of the file as value.
+    pub fn from_file_arg(arg: &str) -> anyhow::Result<Self> {
+        let (pointer, file) = Self::split(arg)?;
+        let contents = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read the file '{file}'"))?;
+
+        Ok(Self {
+            pointer,
+            value: Self::parse_value(contents.trim_end()),
+        })
+    }
+
+    /// Apply the override to the serialized schema.
+    /// Only existing values may be replaced, except for values of
+    /// optional objects which are not set, and `-`, which appends to an array.
+    pub fn apply(self, target: &mut Value) -> anyhow::Result<()> {
+        let Some(path) = self.pointer.strip_prefix('/') else {
+            *target = self.value;
+            return Ok(());
+        };
+
+        let tokens = path
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect::<Vec<_>>();
+        let (last, parents) = tokens
+            .split_last()
+            .ok_or_else(|| anyhow!("The path '{}' is empty", self.pointer))?;
+
+        let mut current = target;
+        let mut current_path = String::new();
+        for token in parents {
+            // Optional values which are not set are serialized as null
+            if current.is_null() {
+                *current = Value::Object(Map::new());
+            }
+
+            current = match current {
+                Value::Object(object) => object.get_mut(token),
+                Value::Array(array) => token
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| array.get_mut(index)),
+                _ => None,
+            }
+            .ok_or_else(|| self.missing(token, &current_path))?;
+            current_path = format!("{current_path}/{token}");
+        }
+
+        let unset = current.is_null();
+        if unset {
+            *current = Value::Object(Map::new());
+        }
+
+        match current {
+            Value::Object(object) => {
+                if !unset && !object.contains_key(last) {
+                    return Err(self.missing(last, &current_path));
+                }
+
+                object.insert(last.clone(), self.value);
+            }
+            Value::Array(array) if last == "-" => array.push(self.value),
+            Value::Array(array) => {
+                let element = last
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| array.get_mut(index))
+                    .ok_or_else(|| self.missing(last, &current_path))?;
+                *element = self.value;
+            }
+            _ => {
+                return Err(anyhow!(
+                    "The path '{}' does not exist: The value at '{current_path}' is neither an object nor an array",
+                    self.pointer
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn missing(&self, token: &str, parent: &str) -> anyhow::Error {
+        let parent = if parent.is_empty() { "/" } else { parent };
+        anyhow!(
+            "The path '{}' does not exist: '{token}' was not found at '{parent}'",
+            self.pointer
+        )
+    }
+}
+
+impl FromStr for SchemaOverride {
+    type Err = anyhow::Error;
+
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        let (pointer, value) = Self::split(arg)?;
+
+        Ok(Self {
+            pointer,
+            value: Self::parse_value(value),
+        })
+    }
+}
+
+/// Command line arguments overriding values of the schema.
+#[derive(Args, Debug, Clone)]
+pub struct OverrideArgs {
+    /// Override a value of the schema.
+    /// The path is a JSON pointer into the schema, the value is parsed as JSON.
+    /// Values which aren't valid JSON are used as strings.
+    /// May be specified multiple times.
+    #[arg(long = "set", value_name = "PATH=VALUE")]
+    set: Vec<SchemaOverride>,
+    /// Override a value of the schema with the contents of a file.
+    /// The contents are parsed like the values of `--set`.
+    /// Applied before the values of `--set`.
+    #[arg(long = "set-file", value_name = "PATH=FILE", value_parser = SchemaOverride::from_file_arg)]
+    set_file: Vec<SchemaOverride>,
+}
+
+impl OverrideArgs {
+    /// Read the schema file and apply the overrides.
+    /// The overrides are applied to the contents of the file as written,
+    /// so shorthand values like `"length": 10` can be overridden directly.
+    pub fn read_schema(&self, schema_file: &str) -> anyhow::Result<Schema> {
+        let overrides = self
+            .set_file
+            .iter()
+            .chain(&self.set)
+            .cloned()
+            .collect::<Vec<_>>();
+        if overrides.is_empty() {
+            return read_schema(schema_file);
+        }
+
+        let file = File::open(schema_file).context("Failed to read schema file")?;
+        let value = serde_json::from_reader(BufReader::new(file))
+            .context("Failed to parse the schema file")?;
+        apply_overrides(value, overrides)
+    }
+}
+
+/// Apply the overrides to the JSON value of a schema, in the given order,
+/// and deserialize the result.
+pub fn apply_overrides(mut value: Value, overrides: Vec<SchemaOverride>) -> anyhow::Result<Schema> {
+    add_unset_options(&mut value)?;
+    for schema_override in overrides {
+        let pointer = schema_override.pointer.clone();
+        schema_override
+            .apply(&mut value)
+            .with_context(|| format!("Failed to override '{pointer}'"))?;
+    }
+
+    from_value(value).context("The schema is invalid after applying the overrides")
+}
+
+/// Set all options which aren't set in the schema to null,
+/// so they can be overridden like options which are set.
+fn add_unset_options(value: &mut Value) -> anyhow::Result<()> {
+    let Value::Object(schema) = value else {
+        return Ok(());
+    };
+
+    let options = schema.entry("options").or_insert(Value::Null);
+    if options.is_null() {
+        *options = Value::Object(Map::new());
+    }
+
+    if let (Value::Object(options), Value::Object(defaults)) =
+        (options, serde_json::to_value(SchemaOptions::default())?)
+    {
+        for (key, default) in defaults {
+            options.entry(key).or_insert(default);
+        }
+    }
+
+    Ok(())
+}
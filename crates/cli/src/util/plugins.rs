@@ -4,6 +4,7 @@ use datagen_rs::plugins::plugin_list::PluginMap;
 use datagen_rs::schema::schema_definition::{PluginInitArgs, Schema};
 use log::{debug, warn};
 use openaddresses_plugin::OpenAddressesPlugin;
+use rhai_plugin::RhaiPlugin;
 use serde_json::Value;
 use sql_plugin::SQLPlugin;
 use std::sync::Arc;
@@ -34,28 +35,40 @@ impl PluginLoader {
         name: &str,
         schema: &Schema,
     ) -> anyhow::Result<Self> {
-        if let Some(options) = schema.options.as_ref() {
-            if let Some(plugins) = options.plugins.as_ref() {
-                if let Some(plugin) = plugins.get(name) {
-                    let args = match plugin {
-                        PluginInitArgs::Args { args, .. } => {
-                            warn!("Using args with plugin path for the '{name}' plugin when the plugin is bundled in the binary. The plugin path will be ignored.");
-                            args.clone().unwrap_or_default()
-                        }
-                        PluginInitArgs::Value(args) => args.clone(),
-                    };
-
-                    debug!("Loading plugin '{name}' with args: {}", args);
-                    let plugin = P::new(args, PluginOptions::default())
-                        .context("Failed to load bundled plugin with arguments")?;
-                    self.0.insert(name.into(), Arc::new(plugin) as Arc<_>);
-                }
-            }
+        if let Some(args) = Self::get_args(name, schema) {
+            debug!("Loading plugin '{name}' with args: {}", args);
+            let plugin = P::new(args, PluginOptions::default())
+                .context("Failed to load bundled plugin with arguments")?;
+            self.0.insert(name.into(), Arc::new(plugin) as Arc<_>);
         }
 
         Ok(self)
     }
 
+    fn add_with_optional_args<P: PluginConstructor + 'static>(
+        self,
+        name: &str,
+        schema: &Schema,
+    ) -> anyhow::Result<Self> {
+        if Self::get_args(name, schema).is_some() {
+            self.add_with_args::<P>(name, schema)
+        } else {
+            self.add_without_args::<P>(name)
+        }
+    }
+
+    fn get_args(name: &str, schema: &Schema) -> Option<Value> {
+        let plugin = schema.options.as_ref()?.plugins.as_ref()?.get(name)?;
+
+        Some(match plugin {
+            PluginInitArgs::Args { args, .. } => {
+                warn!("Using args with plugin path for the '{name}' plugin when the plugin is bundled in the binary. The plugin path will be ignored.");
+                args.clone().unwrap_or_default()
+            }
+            PluginInitArgs::Value(args) => args.clone(),
+        })
+    }
+
     fn build(self) -> anyhow::Result<PluginMap> {
         Ok(self.0)
     }
@@ -66,5 +79,6 @@ pub fn load_plugins(schema: &Schema) -> anyhow::Result<PluginMap> {
         .add_without_args::<UploadPlugin>("upload-plugin")?
         .add_without_args::<SQLPlugin>("sql-plugin")?
         .add_with_args::<OpenAddressesPlugin>("openaddresses-plugin", schema)?
+        .add_with_optional_args::<RhaiPlugin>("rhai-plugin", schema)?
         .build()
 }
@@ -0,0 +1,116 @@
+use anyhow::Context;
+use clap::ValueEnum;
+use datagen_rs::generate::profiler::Profile;
+use serde_json::json;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// The format of the profile written after generating data.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ProfileFormat {
+    /// A table of all paths, transforms and plugins, sorted by their self time.
+    Report,
+    /// The entries and stacks of the profile as JSON.
+    Json,
+    /// Folded stacks with the self time in microseconds,
+    /// which can be converted into a flamegraph.
+    Folded,
+}
+
+impl ProfileFormat {
+    pub fn format(&self, profile: &Profile) -> anyhow::Result<String> {
+        match self {
+            ProfileFormat::Report => Ok(report(profile)),
+            ProfileFormat::Json => json(profile),
+            ProfileFormat::Folded => Ok(folded(profile)),
+        }
+    }
+
+    /// Write the profile to the file or to stderr if no file is given.
+    pub fn write(&self, profile: &Profile, out_file: Option<String>) -> anyhow::Result<()> {
+        let formatted = self.format(profile)?;
+
+        match out_file {
+            Some(out_file) => std::fs::write(&out_file, formatted)
+                .with_context(|| format!("Failed to write the profile to '{out_file}'")),
+            None => {
+                eprintln!("{formatted}");
+                Ok(())
+            }
+        }
+    }
+}
+
+fn report(profile: &Profile) -> String {
+    let mut report = format!(
+        "{:<10} {:>10} {:>12} {:>12}  Name\n",
+        "Category", "Calls", "Total", "Self"
+    );
+
+    for entry in &profile.entries {
+        writeln!(
+            report,
+            "{:<10} {:>10} {:>12} {:>12}  {}",
+            entry.category.to_string(),
+            entry.calls,
+            format_duration(entry.total),
+            format_duration(entry.self_time),
+            entry.name
+        )
+        .unwrap();
+    }
+
+    report.trim_end().to_string()
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn json(profile: &Profile) -> anyhow::Result<String> {
+    let entries = profile
+        .entries
+        .iter()
+        .map(|entry| {
+            json!({
+                "category": entry.category.to_string(),
+                "name": entry.name,
+                "calls": entry.calls,
+                "totalMicros": entry.total.as_micros() as u64,
+                "selfMicros": entry.self_time.as_micros() as u64,
+            })
+        })
+        .collect::<Vec<_>>();
+    let stacks = profile
+        .stacks
+        .iter()
+        .map(|stack| {
+            json!({
+                "frames": stack.frames,
+                "selfMicros": stack.self_time.as_micros() as u64,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(serde_json::to_string_pretty(
+        &json!({ "entries": entries, "stacks": stacks }),
+    )?)
+}
+
+fn folded(profile: &Profile) -> String {
+    profile
+        .stacks
+        .iter()
+        .map(|stack| {
+            // Semicolons separate the frames
+            let frames = stack
+                .frames
+                .iter()
+                .map(|frame| frame.replace(';', "_"))
+                .collect::<Vec<_>>();
+
+            format!("{} {}", frames.join(";"), stack.self_time.as_micros())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
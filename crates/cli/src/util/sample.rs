@@ -0,0 +1,121 @@
+use anyhow::{anyhow, Context};
+use clap::ValueEnum;
+use datagen_rs::schema::schema_definition::Schema;
+use serde_json::{Map, Value};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// The format of a sample file.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SampleFormat {
+    /// A single JSON document. Arrays are treated as a list of records.
+    Json,
+    /// One JSON record per line.
+    Jsonl,
+    /// A CSV file with a header row. Every row is a record.
+    Csv,
+}
+
+impl SampleFormat {
+    /// Select the format by the extension of the file.
+    pub fn from_path(path: &str) -> anyhow::Result<Self> {
+        match Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("json") => Ok(SampleFormat::Json),
+            Some("jsonl" | "ndjson") => Ok(SampleFormat::Jsonl),
+            Some("csv") => Ok(SampleFormat::Csv),
+            _ => Err(anyhow!(
+                "Unable to detect the format of '{path}', please specify it using --format"
+            )),
+        }
+    }
+
+    /// Read the sample file.
+    /// Records are returned as an array.
+    pub fn read(&self, path: &str) -> anyhow::Result<Value> {
+        let file = File::open(path).with_context(|| format!("Failed to open '{path}'"))?;
+
+        match self {
+            SampleFormat::Json => serde_json::from_reader(BufReader::new(file))
+                .with_context(|| format!("Failed to parse '{path}'")),
+            SampleFormat::Jsonl => BufReader::new(file)
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+                .map(|(i, line)| {
+                    serde_json::from_str(&line?)
+                        .with_context(|| format!("Failed to parse line {} of '{path}'", i + 1))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map(Value::Array),
+            SampleFormat::Csv => {
+                let mut reader = csv::Reader::from_reader(file);
+                let headers = reader.headers()?.clone();
+
+                reader
+                    .records()
+                    .map(|record| {
+                        let record = record.with_context(|| format!("Failed to parse '{path}'"))?;
+                        Ok(Value::Object(
+                            headers
+                                .iter()
+                                .zip(record.iter())
+                                .map(|(header, cell)| (header.to_string(), parse_cell(cell)))
+                                .collect::<Map<_, _>>(),
+                        ))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+                    .map(Value::Array)
+            }
+        }
+    }
+}
+
+/// Parse a CSV cell into the most specific JSON value.
+/// Empty cells are `null`.
+pub fn parse_cell(cell: &str) -> Value {
+    if cell.is_empty() {
+        Value::Null
+    } else if let Ok(bool) = cell.parse::<bool>() {
+        Value::Bool(bool)
+    } else if let Ok(int) = cell.parse::<i64>() {
+        int.into()
+    } else if let Some(number) = cell
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+    {
+        Value::Number(number)
+    } else {
+        Value::String(cell.to_string())
+    }
+}
+
+/// Serialize an inferred schema, omitting unset options.
+pub fn schema_to_string(schema: &Schema) -> anyhow::Result<String> {
+    let mut value = serde_json::to_value(schema)?;
+    remove_unset_options(&mut value);
+
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+fn remove_unset_options(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            // Only schema nodes have a type, other objects
+            // like properties may contain null values
+            if object.get("type").is_some_and(Value::is_string) {
+                object.retain(|_, value| !value.is_null());
+            }
+
+            object.values_mut().for_each(remove_unset_options);
+        }
+        Value::Array(array) => array.iter_mut().for_each(remove_unset_options),
+        _ => {}
+    }
+}
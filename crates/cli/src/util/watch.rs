@@ -0,0 +1,141 @@
+use anyhow::Context;
+use datagen_rs::schema::schema_definition::Schema;
+use datagen_rs::util::json_deserialize::from_reader;
+use log::{debug, warn};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// The time to wait for further changes before regenerating,
+/// as editors often write a file in multiple steps.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+
+/// Watches a schema file and all files it depends on.
+pub struct SchemaWatcher {
+    watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    files: BTreeSet<PathBuf>,
+    directories: BTreeSet<PathBuf>,
+}
+
+impl SchemaWatcher {
+    pub fn new() -> anyhow::Result<Self> {
+        let (sender, events) = channel();
+
+        Ok(Self {
+            watcher: notify::recommended_watcher(sender)
+                .context("Failed to create the file watcher")?,
+            events,
+            files: BTreeSet::new(),
+            directories: BTreeSet::new(),
+        })
+    }
+
+    /// Watch the schema file and all files included or read by the schema.
+    /// Files which are no longer used by the schema are not watched anymore.
+    pub fn watch(&mut self, schema_file: &str, schema: Option<&Schema>) -> anyhow::Result<()> {
+        let mut files = BTreeSet::from([absolute_path(schema_file)]);
+        if let Some(schema) = schema {
+            collect_paths(&serde_json::to_value(schema)?, &mut files);
+        }
+
+        // The parent directories are watched, as editors
+        // often replace files instead of writing to them
+        let directories = files
+            .iter()
+            .filter_map(|file| file.parent().map(Path::to_path_buf))
+            .collect::<BTreeSet<_>>();
+        for removed in self.directories.difference(&directories) {
+            self.watcher.unwatch(removed).ok();
+        }
+        for added in directories.difference(&self.directories) {
+            debug!("Watching directory '{}'", added.display());
+            self.watcher
+                .watch(added, RecursiveMode::NonRecursive)
+                .with_context(|| format!("Failed to watch '{}'", added.display()))?;
+        }
+
+        self.files = files;
+        self.directories = directories;
+        Ok(())
+    }
+
+    /// The files which are currently watched.
+    pub fn files(&self) -> &BTreeSet<PathBuf> {
+        &self.files
+    }
+
+    /// Block until one of the watched files changes.
+    pub fn wait_for_change(&self) -> anyhow::Result<()> {
+        loop {
+            let event = self
+                .events
+                .recv()
+                .context("The file watcher stopped unexpectedly")?;
+            if self.is_relevant(event) {
+                break;
+            }
+        }
+
+        // Skip the events of the same change
+        loop {
+            match self.events.recv_timeout(DEBOUNCE_DURATION) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Timeout) => return Ok(()),
+                Err(RecvTimeoutError::Disconnected) => {
+                    anyhow::bail!("The file watcher stopped unexpectedly")
+                }
+            }
+        }
+    }
+
+    fn is_relevant(&self, event: notify::Result<Event>) -> bool {
+        match event {
+            Ok(event) => {
+                !event.kind.is_access()
+                    && event
+                        .paths
+                        .iter()
+                        .any(|path| self.files.contains(&absolute_path(path)))
+            }
+            Err(err) => {
+                warn!("Failed to watch files: {err}");
+                false
+            }
+        }
+    }
+}
+
+fn absolute_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    std::path::absolute(&path).unwrap_or_else(|_| path.as_ref().to_path_buf())
+}
+
+/// Collect the paths of all `include` and `file` schemas.
+/// Included files are searched recursively.
+fn collect_paths(value: &Value, files: &mut BTreeSet<PathBuf>) {
+    match value {
+        Value::Object(object) => {
+            let ty = object.get("type").and_then(Value::as_str);
+            let path = object.get("path").and_then(Value::as_str);
+            if let (Some(ty @ ("include" | "file")), Some(path)) = (ty, path) {
+                // Stop at files which were already visited, to prevent include cycles
+                if files.insert(absolute_path(path)) && ty == "include" {
+                    match File::open(path).map(from_reader::<_, Value>) {
+                        Ok(Ok(included)) => collect_paths(&included, files),
+                        _ => debug!("Failed to read the included file '{path}'"),
+                    }
+                }
+            }
+
+            object
+                .values()
+                .for_each(|value| collect_paths(value, files));
+        }
+        Value::Array(array) => array.iter().for_each(|value| collect_paths(value, files)),
+        _ => {}
+    }
+}
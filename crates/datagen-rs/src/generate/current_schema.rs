@@ -1,6 +1,8 @@
 #[cfg(not(feature = "map-schema"))]
 use crate::bail_unsupported;
 use crate::generate::datagen_context::{DatagenContext, DatagenContextRef};
+#[cfg(feature = "map-schema")]
+use crate::generate::datagen_rng::DatagenRng;
 use crate::generate::generated_schema::GeneratedSchema;
 use crate::generate::resolved_reference::ResolvedReference;
 use crate::generate::schema_path::SchemaPath;
@@ -25,6 +27,8 @@ pub struct CurrentSchema {
     value: Arc<Mutex<SchemaValue>>,
     options: Arc<SchemaOptions>,
     plugins: Arc<PluginList>,
+    #[cfg(feature = "map-schema")]
+    rng: DatagenRng,
     finalized: AtomicBool,
 }
 
@@ -36,6 +40,25 @@ pub type CurrentSchemaRef = Arc<CurrentSchema>;
 impl CurrentSchema {
     #[cfg(feature = "generate")]
     pub fn root(options: Arc<SchemaOptions>, plugins: Arc<PluginList>) -> CurrentSchemaRef {
+        Self::root_with_rng(options, plugins, DatagenRng::default())
+    }
+
+    /// Create a root schema which generates the same values for the same seed.
+    #[cfg(feature = "generate")]
+    pub fn seeded_root(
+        options: Arc<SchemaOptions>,
+        plugins: Arc<PluginList>,
+        seed: u64,
+    ) -> CurrentSchemaRef {
+        Self::root_with_rng(options, plugins, DatagenRng::seeded(seed))
+    }
+
+    #[cfg(feature = "generate")]
+    fn root_with_rng(
+        options: Arc<SchemaOptions>,
+        plugins: Arc<PluginList>,
+        rng: DatagenRng,
+    ) -> CurrentSchemaRef {
         Self {
             parent: None,
             value: Arc::new(Mutex::new(SchemaValue {
@@ -44,6 +67,7 @@ impl CurrentSchema {
             })),
             options,
             plugins,
+            rng,
             finalized: AtomicBool::default(),
         }
         .into()
@@ -65,6 +89,7 @@ impl CurrentSchema {
             })),
             options: parent.options.clone(),
             plugins: parent.plugins.clone(),
+            rng: parent.rng.clone(),
             finalized: AtomicBool::default(),
         }
     }
@@ -199,6 +224,7 @@ impl DatagenContext for CurrentSchemaRef {
             })),
             options: self.options.clone(),
             plugins: self.plugins.clone(),
+            rng: self.rng.clone(),
             finalized: AtomicBool::default(),
         })))
     }
@@ -257,6 +283,11 @@ impl DatagenContext for CurrentSchemaRef {
         Ok(CurrentSchema::options(self.as_ref()).clone())
     }
 
+    #[cfg(feature = "map-schema")]
+    fn rng(&self) -> DatagenRng {
+        self.rng.clone()
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -1,3 +1,5 @@
+#[cfg(feature = "map-schema")]
+use crate::generate::datagen_rng::DatagenRng;
 use crate::generate::generated_schema::GeneratedSchema;
 use crate::generate::resolved_reference::ResolvedReference;
 use crate::generate::schema_path::SchemaPath;
@@ -27,6 +29,13 @@ pub trait DatagenContext: DynClone + Send + Sync + Any {
 
     fn options(&self) -> anyhow::Result<Arc<SchemaOptions>>;
 
+    /// The random number generator to generate values with.
+    /// Defaults to the thread-local generator.
+    #[cfg(feature = "map-schema")]
+    fn rng(&self) -> DatagenRng {
+        DatagenRng::default()
+    }
+
     fn as_any(&self) -> &dyn Any;
 
     #[doc(hidden)]
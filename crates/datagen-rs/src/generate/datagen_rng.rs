@@ -0,0 +1,46 @@
+use rand::{thread_rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use std::sync::{Arc, Mutex};
+
+/// The random number generator used to generate values.
+/// Uses the thread-local generator, unless it was created from a seed.
+/// Clones of a seeded generator share their state, so all values
+/// generated from the same root schema use the same sequence of numbers.
+#[derive(Clone, Default)]
+pub struct DatagenRng {
+    seeded: Option<Arc<Mutex<ChaCha20Rng>>>,
+}
+
+impl DatagenRng {
+    /// Create a generator which always generates the same values for the same seed.
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            seeded: Some(Arc::new(Mutex::new(ChaCha20Rng::seed_from_u64(seed)))),
+        }
+    }
+
+    fn with<T>(&self, func: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        match &self.seeded {
+            Some(rng) => func(&mut *rng.lock().unwrap()),
+            None => func(&mut thread_rng()),
+        }
+    }
+}
+
+impl RngCore for DatagenRng {
+    fn next_u32(&mut self) -> u32 {
+        self.with(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.with(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.with(|rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.with(|rng| rng.try_fill_bytes(dest))
+    }
+}
@@ -9,11 +9,12 @@ use serde_json::Value;
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum GeneratedSchema {
+    #[default]
     None,
     Number(OrderedFloat<f64>),
     Integer(i64),
@@ -80,6 +81,7 @@ pub trait IntoRandom {
 pub mod generate {
     use crate::generate::datagen_context::DatagenContextRef;
     use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+    use crate::generate::profiler;
     use crate::util::traits::generate::TransformTrait;
     use crate::util::traits::GetTransform;
     use std::sync::Arc;
@@ -101,6 +103,12 @@ pub mod generate {
         fn should_finalize(&self) -> bool {
             true
         }
+
+        /// Whether the transforms should be applied to the generated value.
+        /// Schemas which apply their transforms themselves return false.
+        fn should_transform(&self) -> bool {
+            true
+        }
     }
 
     impl<T> IntoGeneratedArc for T
@@ -124,7 +132,11 @@ pub mod generate {
         T: IntoGeneratedArc,
     {
         fn into_random(self, schema: DatagenContextRef) -> anyhow::Result<Arc<GeneratedSchema>> {
-            let transform = self.get_transform();
+            let _scope = profiler::path_scope(&schema);
+            let transform = self
+                .should_transform()
+                .then(|| self.get_transform())
+                .flatten();
             let should_finalize = self.should_finalize();
 
             let mut res = self.into_generated_arc(schema.clone())?;
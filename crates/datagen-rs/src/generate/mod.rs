@@ -1,6 +1,10 @@
 pub mod current_schema;
 pub mod datagen_context;
+#[cfg(feature = "map-schema")]
+pub mod datagen_rng;
 pub mod generated_schema;
+#[cfg(feature = "map-schema")]
+pub mod profiler;
 pub mod resolved_reference;
 #[cfg(feature = "map-schema")]
 pub mod schema_mapper;
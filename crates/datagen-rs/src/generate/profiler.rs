@@ -0,0 +1,261 @@
+//! Records the time spent generating each part of a schema.
+//!
+//! Profiling is disabled by default, enable it using [`start`]
+//! and collect the results using [`finish`] after generating the data.
+//!
+//! # Example
+//! ```
+//! use datagen_rs::generate::profiler;
+//! use datagen_rs::util::helpers::generate_random_data;
+//! use serde_json::{from_value, json};
+//!
+//! let schema = from_value(json!({
+//!     "type": "array",
+//!     "length": 10,
+//!     "items": { "type": "integer", "min": 0, "max": 10 },
+//! }))
+//! .unwrap();
+//!
+//! profiler::start();
+//! generate_random_data(schema, None).unwrap();
+//! let profile = profiler::finish();
+//!
+//! let items = profile
+//!     .entries
+//!     .iter()
+//!     .find(|entry| entry.name == "[]")
+//!     .unwrap();
+//! assert_eq!(items.calls, 10);
+//! ```
+use crate::generate::datagen_context::DatagenContextRef;
+use crate::generate::schema_path::SchemaPath;
+use once_cell::sync::Lazy;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDED: Lazy<Mutex<Recorded>> = Lazy::new(Default::default);
+
+thread_local! {
+    static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The kind of work a profile entry was recorded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProfileCategory {
+    /// Generating the value at a schema path.
+    /// Array indices are replaced by `[]`, so all
+    /// items of an array are recorded as a single path.
+    Path,
+    /// Applying a transform.
+    Transform,
+    /// Calling a plugin.
+    Plugin,
+}
+
+impl Display for ProfileCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ProfileCategory::Path => "path",
+            ProfileCategory::Transform => "transform",
+            ProfileCategory::Plugin => "plugin",
+        })
+    }
+}
+
+/// The recorded calls of a path, transform or plugin.
+#[derive(Debug, Clone)]
+pub struct ProfileEntry {
+    pub category: ProfileCategory,
+    pub name: String,
+    /// The number of calls.
+    pub calls: u64,
+    /// The time spent in all calls, including nested calls.
+    pub total: Duration,
+    /// The time spent in all calls, excluding nested calls.
+    pub self_time: Duration,
+}
+
+/// The time spent in a stack of nested calls, excluding the time of further nested calls.
+#[derive(Debug, Clone)]
+pub struct ProfileStack {
+    /// The names of the calls, starting with the outermost call.
+    pub frames: Vec<String>,
+    pub self_time: Duration,
+}
+
+/// The results of a profiling session.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    /// The recorded entries, sorted by their self time, highest first.
+    pub entries: Vec<ProfileEntry>,
+    pub stacks: Vec<ProfileStack>,
+}
+
+#[derive(Default)]
+struct Recorded {
+    entries: HashMap<(ProfileCategory, String), ProfileEntry>,
+    stacks: HashMap<Vec<String>, Duration>,
+}
+
+struct Frame {
+    category: ProfileCategory,
+    name: String,
+    start: Instant,
+    children: Duration,
+}
+
+impl Frame {
+    fn label(&self) -> String {
+        match self.category {
+            ProfileCategory::Path => self.name.clone(),
+            category => format!("{category}:{}", self.name),
+        }
+    }
+}
+
+/// Start recording, discarding the results of previous sessions.
+pub fn start() {
+    *RECORDED.lock().unwrap() = Recorded::default();
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Stop recording and return the results.
+pub fn finish() -> Profile {
+    ENABLED.store(false, Ordering::SeqCst);
+    let recorded = std::mem::take(&mut *RECORDED.lock().unwrap());
+
+    let mut entries = recorded.entries.into_values().collect::<Vec<_>>();
+    entries.sort_by(|a, b| b.self_time.cmp(&a.self_time).then(a.name.cmp(&b.name)));
+    let mut stacks = recorded
+        .stacks
+        .into_iter()
+        .map(|(frames, self_time)| ProfileStack { frames, self_time })
+        .collect::<Vec<_>>();
+    stacks.sort_by(|a, b| a.frames.cmp(&b.frames));
+
+    Profile { entries, stacks }
+}
+
+/// Whether profiling is enabled.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records the time until it is dropped.
+pub(crate) struct ProfileScope {
+    // The scope must be dropped on the thread it was created on
+    _not_send: PhantomData<*const ()>,
+}
+
+/// Start recording a call, if profiling is enabled.
+/// Calls nested directly into a call of the same name are recorded as a single call.
+pub(crate) fn scope<F: FnOnce() -> String>(
+    category: ProfileCategory,
+    name: F,
+) -> Option<ProfileScope> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let name = name();
+    STACK.with_borrow_mut(|stack| {
+        if stack
+            .last()
+            .is_some_and(|top| top.category == category && top.name == name)
+        {
+            return None;
+        }
+
+        stack.push(Frame {
+            category,
+            name,
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+
+        Some(ProfileScope {
+            _not_send: PhantomData,
+        })
+    })
+}
+
+/// Start recording the generation of the value at the current path.
+pub(crate) fn path_scope(schema: &DatagenContextRef) -> Option<ProfileScope> {
+    scope(ProfileCategory::Path, || {
+        schema
+            .path()
+            .map(|path| profile_path(&path))
+            .unwrap_or_default()
+    })
+}
+
+/// Start recording a call of a plugin method.
+pub(crate) fn plugin_scope(plugin: &str, method: &str) -> Option<ProfileScope> {
+    scope(ProfileCategory::Plugin, || format!("{plugin}.{method}"))
+}
+
+/// Get the name of a path, with array indices replaced by `[]`.
+/// The root path is named `(root)`.
+fn profile_path(path: &SchemaPath) -> String {
+    let mut name = String::new();
+    for segment in &path.path {
+        if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) {
+            name.push_str("[]");
+        } else {
+            if !name.is_empty() {
+                name.push('.');
+            }
+            name.push_str(segment);
+        }
+    }
+
+    if name.is_empty() {
+        "(root)".into()
+    } else {
+        name
+    }
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        STACK.with_borrow_mut(|stack| {
+            let labels = stack.iter().map(Frame::label).collect::<Vec<_>>();
+            let Some(frame) = stack.pop() else {
+                return;
+            };
+
+            let total = frame.start.elapsed();
+            let self_time = total.saturating_sub(frame.children);
+            if let Some(parent) = stack.last_mut() {
+                parent.children += total;
+            }
+
+            // Calls which finish after profiling was stopped are discarded
+            if !is_enabled() {
+                return;
+            }
+
+            let mut recorded = RECORDED.lock().unwrap();
+            let entry = recorded
+                .entries
+                .entry((frame.category, frame.name.clone()))
+                .or_insert_with(|| ProfileEntry {
+                    category: frame.category,
+                    name: frame.name,
+                    calls: 0,
+                    total: Duration::ZERO,
+                    self_time: Duration::ZERO,
+                });
+            entry.calls += 1;
+            entry.total += total;
+            entry.self_time += self_time;
+            *recorded.stacks.entry(labels).or_default() += self_time;
+        });
+    }
+}
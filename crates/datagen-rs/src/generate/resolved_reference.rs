@@ -14,6 +14,7 @@ pub mod map_schema {
     use crate::generate::resolved_reference::ResolvedReference;
     use anyhow::anyhow;
     use rand::seq::SliceRandom;
+    use rand::Rng;
     use std::sync::Arc;
 
     impl ResolvedReference {
@@ -29,11 +30,14 @@ pub mod map_schema {
             Self::Multiple(schemas)
         }
 
-        pub fn into_random(self) -> anyhow::Result<Arc<GeneratedSchema>> {
+        pub fn into_random<R: Rng + ?Sized>(
+            self,
+            rng: &mut R,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
             Ok(match self {
                 Self::Single(schema) => schema,
                 Self::Multiple(schemas) => schemas
-                    .choose(&mut rand::thread_rng())
+                    .choose(rng)
                     .ok_or(anyhow!("Failed to choose random schema value"))?
                     .clone(),
                 Self::None => Arc::new(GeneratedSchema::None),
@@ -28,7 +28,7 @@ pub mod generate {
     use crate::generate::datagen_context::DatagenContextRef;
     use crate::generate::generated_schema::GeneratedSchema;
     use crate::generate::schema_mapper::MapSchema;
-    use crate::schema::transform::MaybeValidTransform;
+    use crate::schema::transform::{MaybeValidTransform, Transform};
     use crate::util::traits::generate::TransformTrait;
     use indexmap::IndexMap;
     use std::sync::Arc;
@@ -89,8 +89,19 @@ pub mod generate {
 
             if finalize {
                 let mut res = GeneratedSchema::Array(res).into();
-                if let Some(transform) = transform {
-                    res = transform.transform(self.clone(), res)?;
+                let regenerate = |i: usize| {
+                    let current_schema = self.child(None, &i.to_string())?;
+                    func(&current_schema, value.clone())
+                };
+
+                for transform in transform.into_iter().flatten() {
+                    res = match transform {
+                        // Unique needs to be able to generate new items
+                        MaybeValidTransform::Valid(Transform::Unique(unique)) => {
+                            unique.transform_with_refill(self.clone(), res, Some(&regenerate))?
+                        }
+                        transform => transform.transform(self.clone(), res)?,
+                    };
                 }
 
                 self.finalize(res)
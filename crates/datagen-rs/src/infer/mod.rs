@@ -0,0 +1,272 @@
+//! Infer a schema from sample data.
+//!
+//! # Example
+//! ```
+//! use datagen_rs::infer::{infer_schema, InferOptions};
+//! use serde_json::json;
+//!
+//! let sample = json!([
+//!     { "id": 1, "email": "jane@example.com", "active": true },
+//!     { "id": 2, "email": "john@example.com", "active": false },
+//! ]);
+//!
+//! let schema = infer_schema(&sample, &InferOptions::default());
+//! println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+//! ```
+mod string;
+
+use crate::infer::string::StringStats;
+use crate::schema::any::{Any, MaybeValidAny};
+use crate::schema::any_of::AnyOf;
+use crate::schema::any_value::AnyValue;
+use crate::schema::array::{Array, ArrayLength, RandomArray};
+use crate::schema::bool::Bool;
+use crate::schema::integer::Integer;
+use crate::schema::number::Number;
+use crate::schema::object::Object;
+use crate::schema::schema_definition::Schema;
+use indexmap::IndexMap;
+use serde_json::Value;
+
+/// The maximum precision of inferred numbers.
+const MAX_PRECISION: u8 = 10;
+
+/// Options for inferring a schema.
+#[derive(Debug, Clone)]
+pub struct InferOptions {
+    /// The maximum number of distinct values of a string
+    /// to be considered an enum. Defaults to `10`.
+    pub max_enum_values: usize,
+    /// Whether to choose strings which are neither an enum nor of a known kind
+    /// from the observed values. As this copies the sample data into the schema,
+    /// random strings are generated instead by default.
+    pub include_samples: bool,
+}
+
+impl Default for InferOptions {
+    fn default() -> Self {
+        Self {
+            max_enum_values: 10,
+            include_samples: false,
+        }
+    }
+}
+
+/// Infer a schema generating data shaped like the sample.
+///
+/// All elements of an array are merged into a single item schema,
+/// which allows passing a list of sample records as an array.
+/// Values which are `null` or missing in some of the samples
+/// may be generated as `null`.
+pub fn infer_schema(sample: &Value, options: &InferOptions) -> Schema {
+    let mut stats = Stats::default();
+    stats.observe(sample);
+
+    Schema {
+        value: stats.into_value(1, options),
+        options: None,
+    }
+}
+
+/// The values observed at a position in the sample.
+#[derive(Debug, Default)]
+struct Stats {
+    /// The number of observed values, including `null`.
+    count: usize,
+    nulls: usize,
+    trues: usize,
+    falses: usize,
+    integers: Option<(i64, i64)>,
+    numbers: Option<(f64, f64)>,
+    precision: u8,
+    strings: StringStats,
+    arrays: Option<ArrayStats>,
+    objects: Option<ObjectStats>,
+}
+
+#[derive(Debug)]
+struct ArrayStats {
+    min_length: usize,
+    max_length: usize,
+    items: Box<Stats>,
+}
+
+#[derive(Debug, Default)]
+struct ObjectStats {
+    count: usize,
+    properties: IndexMap<String, Stats>,
+}
+
+impl Stats {
+    fn observe(&mut self, value: &Value) {
+        self.count += 1;
+
+        match value {
+            Value::Null => self.nulls += 1,
+            Value::Bool(true) => self.trues += 1,
+            Value::Bool(false) => self.falses += 1,
+            Value::Number(number) => match number.as_i64() {
+                Some(int) => self.integers = Some(extend_range(self.integers, int)),
+                None => {
+                    let float = number.as_f64().unwrap_or_default();
+                    self.numbers = Some(extend_range(self.numbers, float));
+                    self.precision = self.precision.max(precision_of(float));
+                }
+            },
+            Value::String(string) => self.strings.observe(string),
+            Value::Array(array) => {
+                let stats = self.arrays.get_or_insert_with(|| ArrayStats {
+                    min_length: array.len(),
+                    max_length: array.len(),
+                    items: Default::default(),
+                });
+
+                stats.min_length = stats.min_length.min(array.len());
+                stats.max_length = stats.max_length.max(array.len());
+                array.iter().for_each(|item| stats.items.observe(item));
+            }
+            Value::Object(object) => {
+                let stats = self.objects.get_or_insert_with(Default::default);
+                stats.count += 1;
+
+                for (key, value) in object {
+                    stats
+                        .properties
+                        .entry(key.clone())
+                        .or_default()
+                        .observe(value);
+                }
+            }
+        }
+    }
+
+    /// Convert the observed values into a schema.
+    /// `total` is the number of times the parent was observed,
+    /// values observed less often are missing in some samples.
+    fn into_value(self, total: usize, options: &InferOptions) -> AnyValue {
+        let mut values = Vec::new();
+
+        if self.trues + self.falses > 0 {
+            let probability = self.trues as f64 / (self.trues + self.falses) as f64;
+            values.push(any(Any::Bool(Bool::Random {
+                probability: Some((probability * 100.0).round() / 100.0),
+                transform: None,
+            })));
+        }
+
+        match (self.integers, self.numbers) {
+            (Some((min, max)), None) => values.push(any(Any::Integer(Integer::Random {
+                min: Some(min),
+                max: Some(max),
+                transform: None,
+            }))),
+            (integers, Some(numbers)) => {
+                let (min, max) = integers.map_or(numbers, |(min, max)| {
+                    (numbers.0.min(min as f64), numbers.1.max(max as f64))
+                });
+
+                // The range of random numbers excludes the maximum
+                values.push(any(Any::Number(if min < max {
+                    Number::Random {
+                        min: Some(min),
+                        max: Some(max),
+                        precision: Some(self.precision),
+                        transform: None,
+                    }
+                } else {
+                    Number::Constant {
+                        value: min,
+                        transform: None,
+                    }
+                })));
+            }
+            (None, None) => {}
+        }
+
+        if let Some(value) = self.strings.into_value(options) {
+            values.push(value);
+        }
+
+        if let Some(arrays) = self.arrays {
+            let length = if arrays.min_length == arrays.max_length {
+                ArrayLength::Constant {
+                    value: arrays.min_length as _,
+                }
+            } else {
+                ArrayLength::Random {
+                    min: arrays.min_length as _,
+                    max: arrays.max_length as _,
+                }
+            };
+
+            // Every array is a parent of its items, as arrays may be empty
+            let total = arrays.items.count;
+            values.push(any(Any::Array(Box::new(Array::RandomArray(RandomArray {
+                length,
+                items: arrays.items.into_value(total, options),
+                transform: None,
+            })))));
+        }
+
+        if let Some(objects) = self.objects {
+            let properties = objects
+                .properties
+                .into_iter()
+                .map(|(key, stats)| (key, stats.into_value(objects.count, options)))
+                .collect();
+
+            values.push(any(Any::Object(Box::new(Object {
+                properties,
+                transform: None,
+            }))));
+        }
+
+        let nullable = self.nulls > 0 || self.count < total;
+        match values.len() {
+            0 => AnyValue::Null,
+            1 if !nullable => values.remove(0),
+            _ => {
+                // Merge enums with null instead of nesting them
+                if let [AnyValue::Any(MaybeValidAny::Valid(Any::AnyOf(any_of)))] =
+                    values.as_mut_slice()
+                {
+                    values = std::mem::take(&mut any_of.values);
+                }
+                if nullable {
+                    values.push(AnyValue::Null);
+                }
+
+                any(Any::AnyOf(AnyOf {
+                    values,
+                    num: None,
+                    allow_null: None,
+                    transform: None,
+                }))
+            }
+        }
+    }
+}
+
+fn any(value: Any) -> AnyValue {
+    AnyValue::Any(MaybeValidAny::Valid(value))
+}
+
+fn extend_range<T: PartialOrd + Copy>(range: Option<(T, T)>, value: T) -> (T, T) {
+    match range {
+        Some((min, max)) => (
+            if value < min { value } else { min },
+            if value > max { value } else { max },
+        ),
+        None => (value, value),
+    }
+}
+
+/// The number of decimal places of a number.
+fn precision_of(value: f64) -> u8 {
+    let formatted = value.to_string();
+    let decimals = formatted
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len());
+
+    decimals.min(MAX_PRECISION as usize) as u8
+}
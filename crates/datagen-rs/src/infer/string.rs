@@ -0,0 +1,200 @@
+use crate::infer::{any, InferOptions};
+use crate::schema::any::Any;
+use crate::schema::any_of::AnyOf;
+use crate::schema::any_value::AnyValue;
+use crate::schema::string::{StringGenerator, StringSchema};
+use crate::schema::transform::{MaybeValidTransform, Transform};
+use crate::transform::string_transform::TruncateTransform;
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use fake::locales::{Data, EN};
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// The maximum number of observed values to choose from
+/// if the strings are neither an enum nor of a known kind
+/// and the observed values should be included in the schema.
+const MAX_SAMPLE_VALUES: usize = 100;
+
+/// The length of the strings created by the uuid generator.
+const UUID_LENGTH: usize = 36;
+
+/// The minimum share of names for strings to be considered names,
+/// as the known names are far from complete.
+const MIN_NAME_SHARE: f64 = 0.8;
+
+static UUID: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+        .unwrap()
+});
+static EMAIL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap());
+static FIRST_NAMES: Lazy<HashSet<&str>> =
+    Lazy::new(|| EN::NAME_FIRST_NAME.iter().copied().collect());
+static LAST_NAMES: Lazy<HashSet<&str>> = Lazy::new(|| EN::NAME_LAST_NAME.iter().copied().collect());
+
+/// The observed strings, with the number of times each string was observed.
+#[derive(Debug, Default)]
+pub(super) struct StringStats {
+    count: usize,
+    values: IndexMap<String, usize>,
+}
+
+impl StringStats {
+    pub fn observe(&mut self, value: &str) {
+        self.count += 1;
+        *self.values.entry(value.to_string()).or_default() += 1;
+    }
+
+    /// Convert the observed strings into a schema.
+    /// Strings of a known kind are generated using the matching generator.
+    /// Observed strings are only copied into the schema if they are an enum
+    /// or [`InferOptions::include_samples`] is set, otherwise random strings are generated.
+    pub fn into_value(self, options: &InferOptions) -> Option<AnyValue> {
+        if self.values.is_empty() {
+            return None;
+        }
+
+        if let Some(generator) = self.generator() {
+            return Some(any(Any::String(StringSchema::Generated {
+                generator,
+                transform: None,
+            })));
+        }
+
+        let is_enum =
+            self.values.len() <= options.max_enum_values && self.values.len() * 2 <= self.count;
+        if !is_enum && !options.include_samples {
+            return Some(self.random_string());
+        }
+
+        if self.values.len() == 1 && (is_enum || self.count == 1) {
+            return self.values.into_keys().next().map(constant);
+        }
+
+        let values = if is_enum {
+            self.values.into_keys().map(constant).collect()
+        } else {
+            // Prefer the most common values
+            let mut values = self.values.into_iter().collect::<Vec<_>>();
+            values.sort_by(|(_, a), (_, b)| b.cmp(a));
+            values
+                .into_iter()
+                .take(MAX_SAMPLE_VALUES)
+                .map(|(value, _)| constant(value))
+                .collect()
+        };
+
+        Some(any(Any::AnyOf(AnyOf {
+            values,
+            num: None,
+            allow_null: None,
+            transform: None,
+        })))
+    }
+
+    fn generator(&self) -> Option<StringGenerator> {
+        let values = || self.values.keys().map(String::as_str);
+
+        if values().all(|value| UUID.is_match(value)) {
+            Some(StringGenerator::Uuid)
+        } else if values().all(|value| EMAIL.is_match(value)) {
+            Some(StringGenerator::Email)
+        } else if let Some(dates) = values()
+            .map(|value| DateTime::parse_from_rfc3339(value).ok())
+            .collect::<Option<Vec<_>>>()
+        {
+            let min = dates.iter().min()?.with_timezone(&Utc);
+            let max = dates.iter().max()?.with_timezone(&Utc);
+            // The range must span at least one minute
+            let (from, to) = if max - min >= Duration::minutes(1) {
+                (
+                    Some(min.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                    Some(max.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                )
+            } else {
+                (None, None)
+            };
+
+            Some(StringGenerator::DateTime {
+                format: None,
+                from,
+                to,
+            })
+        } else if let Some(dates) = values()
+            .map(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+            .collect::<Option<Vec<_>>>()
+        {
+            let min = dates.iter().min()?.and_hms_opt(0, 0, 0)?.and_utc();
+            let max = dates.iter().max()?.and_hms_opt(23, 59, 59)?.and_utc();
+
+            Some(StringGenerator::DateTime {
+                format: Some("%Y-%m-%d".into()),
+                from: Some(min.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                to: Some(max.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            })
+        } else if self.share_of(|value| FIRST_NAMES.contains(value)) >= MIN_NAME_SHARE {
+            Some(StringGenerator::FirstName)
+        } else if self.share_of(|value| LAST_NAMES.contains(value)) >= MIN_NAME_SHARE {
+            Some(StringGenerator::LastName)
+        } else if self.share_of(is_full_name) >= MIN_NAME_SHARE {
+            Some(StringGenerator::FullName)
+        } else {
+            None
+        }
+    }
+
+    /// Random strings which are at most as long as the longest observed string.
+    fn random_string(&self) -> AnyValue {
+        let length = self
+            .values
+            .keys()
+            .map(|value| value.chars().count())
+            .max()
+            .unwrap_or_default();
+        let transform = (length < UUID_LENGTH).then(|| {
+            vec![MaybeValidTransform::Valid(Transform::Truncate(
+                TruncateTransform {
+                    length: length.max(1),
+                    ellipsis: None,
+                    serialize_non_strings: None,
+                    recursive: None,
+                },
+            ))]
+        });
+
+        any(Any::String(StringSchema::Generated {
+            generator: StringGenerator::Uuid,
+            transform,
+        }))
+    }
+
+    /// The share of distinct values matching the predicate.
+    fn share_of<F: Fn(&str) -> bool>(&self, predicate: F) -> f64 {
+        let matching = self
+            .values
+            .keys()
+            .filter(|value| predicate(value.as_str()))
+            .count();
+
+        matching as f64 / self.values.len() as f64
+    }
+}
+
+/// Whether the value is a capitalized first and last name.
+/// Only one of the names has to be known.
+fn is_full_name(value: &str) -> bool {
+    let parts = value.split_whitespace().collect::<Vec<_>>();
+    let capitalized = |part: &&str| part.starts_with(|c: char| c.is_uppercase());
+
+    matches!(parts.as_slice(), [first, last]
+        if capitalized(first) && capitalized(last)
+            && (FIRST_NAMES.contains(first) || LAST_NAMES.contains(last)))
+}
+
+fn constant(value: String) -> AnyValue {
+    any(Any::String(StringSchema::Constant {
+        value,
+        transform: None,
+    }))
+}
@@ -22,6 +22,10 @@
 /// println!("{}", data);
 /// ```
 pub mod generate;
+#[cfg(feature = "infer")]
+pub mod infer;
+#[cfg(feature = "lint")]
+pub mod lint;
 pub mod plugins;
 pub mod schema;
 #[cfg(test)]
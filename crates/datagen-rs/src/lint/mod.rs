@@ -0,0 +1,684 @@
+//! Find likely mistakes in a schema without generating any data.
+//!
+//! The linter reports references which never resolve to a value,
+//! references to values which are generated after the reference,
+//! plugins which are never used, transforms which can't be applied
+//! to the type of a value, ranges no value can be generated from
+//! and files which include themselves.
+//!
+//! # Example
+//! ```
+//! use datagen_rs::lint::{lint_schema, LintKind};
+//! use serde_json::{from_value, json};
+//!
+//! let schema = from_value(json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "greeting": {
+//!             "type": "string",
+//!             "generator": {
+//!                 "type": "format",
+//!                 "format": "Hello {{name}}",
+//!                 "args": { "name": "ref:./name" },
+//!             },
+//!         },
+//!         "name": { "type": "string", "generator": { "type": "firstName" } },
+//!     },
+//! }))
+//! .unwrap();
+//!
+//! let findings = lint_schema(&schema);
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].kind, LintKind::ForwardReference);
+//! ```
+mod ranges;
+mod references;
+
+use crate::generate::generated_schema::GeneratedSchema;
+use crate::lint::references::{ContextId, Contexts, Target};
+use crate::schema::any::{Any, MaybeValidAny};
+use crate::schema::any_value::AnyValue;
+use crate::schema::array::{Array, ArrayLength};
+use crate::schema::flatten::FlattenableValue;
+use crate::schema::include::Include;
+use crate::schema::object::Object;
+use crate::schema::reference::{Reference, StringOrNumber};
+use crate::schema::schema_definition::Schema;
+use crate::schema::serializer::Serializer;
+use crate::schema::string::{FormatArg, StringGenerator, StringSchema};
+use crate::schema::transform::validate::validate_transform_types;
+use crate::schema::transform::{MaybeValidTransform, ReferenceOrString, Transform};
+use crate::transform::filter::FilterTransform;
+use crate::transform::object_transform::JsonPatchOperation;
+use crate::validation::path::ValidationPath;
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// How severe a finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// The schema works, but likely not as intended.
+    Warning,
+    /// Generating data fails or produces missing values.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// The kind of mistake a finding was reported for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintKind {
+    /// A reference which doesn't resolve to any value.
+    UnknownReference,
+    /// A reference to a value which is generated after the reference
+    /// is resolved, as values are generated in the order they are defined in.
+    ForwardReference,
+    /// A plugin which is loaded, but never used.
+    UnusedPlugin,
+    /// A transform which can't be applied to the type of the value.
+    TransformType,
+    /// A range which no value can be generated from.
+    ImpossibleRange,
+    /// A file which includes itself, directly or through other files.
+    IncludeCycle,
+}
+
+impl Display for LintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LintKind::UnknownReference => "unknown-reference",
+            LintKind::ForwardReference => "forward-reference",
+            LintKind::UnusedPlugin => "unused-plugin",
+            LintKind::TransformType => "transform-type",
+            LintKind::ImpossibleRange => "impossible-range",
+            LintKind::IncludeCycle => "include-cycle",
+        })
+    }
+}
+
+/// A likely mistake in a schema.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finding {
+    pub kind: LintKind,
+    pub severity: Severity,
+    /// The path of the schema value the finding was reported for.
+    /// Uses the same format as validation errors.
+    pub path: String,
+    pub message: String,
+    /// A suggestion how to fix the mistake, if one is known.
+    pub fix: Option<String>,
+}
+
+impl Finding {
+    fn new<S: ToString>(
+        kind: LintKind,
+        severity: Severity,
+        path: &ValidationPath,
+        message: S,
+    ) -> Self {
+        Self {
+            kind,
+            severity,
+            path: path.to_string(),
+            message: message.to_string(),
+            fix: None,
+        }
+    }
+
+    fn error<S: ToString>(kind: LintKind, path: &ValidationPath, message: S) -> Self {
+        Self::new(kind, Severity::Error, path, message)
+    }
+
+    fn with_fix<S: ToString>(mut self, fix: S) -> Self {
+        self.fix = Some(fix.to_string());
+        self
+    }
+}
+
+/// Lint a schema.
+/// Errors are returned first, otherwise the findings
+/// are returned in the order of the schema.
+///
+/// Included files are linted as part of the schema if they can be read.
+/// Values created by plugins, files and included files which can't
+/// be read may contain any value, so references into them are not checked.
+pub fn lint_schema(schema: &Schema) -> Vec<Finding> {
+    let mut linter = Linter::default();
+    let root = linter.contexts.root();
+    linter.value(&schema.value, root, &ValidationPath::root());
+
+    let mut findings = linter.findings;
+    findings.extend(
+        linter
+            .references
+            .into_iter()
+            .filter(|(_, check)| !check.resolved)
+            .map(|((path, reference), check)| {
+                reference_finding(&linter.contexts, &path, &reference, &check)
+            }),
+    );
+
+    if let Some(options) = &schema.options {
+        if let Some(Serializer::Plugin { plugin_name, .. }) = &options.serializer {
+            linter.plugins.insert(plugin_name.clone());
+        }
+
+        let path = ValidationPath::root().append("options", "plugins");
+        findings.extend(
+            options
+                .plugins
+                .iter()
+                .flat_map(IndexMap::keys)
+                .filter(|name| !linter.plugins.contains(*name))
+                .map(|name| {
+                    Finding::new(
+                        LintKind::UnusedPlugin,
+                        Severity::Warning,
+                        &path.append_single(name),
+                        format!("The plugin '{name}' is loaded, but never used"),
+                    )
+                    .with_fix(format!("Remove '{name}' from options.plugins"))
+                }),
+        );
+    }
+
+    findings.sort_by_key(|finding| Reverse(finding.severity));
+    findings
+}
+
+/// A reference found in the schema.
+struct ReferenceCheck {
+    /// The last context the reference was resolved in.
+    context: ContextId,
+    target: Target,
+    /// Whether the reference resolved to a value in any context.
+    resolved: bool,
+}
+
+#[derive(Default)]
+struct Linter {
+    contexts: Contexts,
+    /// The references, by their path and value.
+    references: IndexMap<(String, String), ReferenceCheck>,
+    /// The names of the used plugins.
+    plugins: HashSet<String>,
+    /// The canonical paths of the files which are currently included.
+    includes: Vec<PathBuf>,
+    findings: Vec<Finding>,
+}
+
+impl Linter {
+    /// Lint a value generated in its own context.
+    fn value(
+        &mut self,
+        value: &AnyValue,
+        context: ContextId,
+        path: &ValidationPath,
+    ) -> Option<&'static str> {
+        let (value_type, opaque) = self.generate(value, context, path);
+        self.contexts.finalize(context, value_type, opaque);
+
+        value_type
+    }
+
+    /// Lint a value, returning its type if it is known and
+    /// whether its structure is unknown.
+    fn generate(
+        &mut self,
+        value: &AnyValue,
+        context: ContextId,
+        path: &ValidationPath,
+    ) -> (Option<&'static str>, bool) {
+        match value {
+            AnyValue::String(string) if string.starts_with("ref:") => {
+                (self.reference(context, string, path), false)
+            }
+            AnyValue::String(_) => (Some("String"), false),
+            AnyValue::Number(_) => (Some("Number"), false),
+            AnyValue::Bool(_) => (Some("Bool"), false),
+            AnyValue::Null => (Some("None"), false),
+            AnyValue::Any(MaybeValidAny::Valid(any)) => self.any(any, context, path),
+            AnyValue::Any(MaybeValidAny::Invalid(_)) => (None, false),
+        }
+    }
+
+    fn any(
+        &mut self,
+        any: &Any,
+        context: ContextId,
+        path: &ValidationPath,
+    ) -> (Option<&'static str>, bool) {
+        let mut opaque = false;
+        let value_type = match any {
+            Any::Number(number) => {
+                self.findings.extend(ranges::number(number, path));
+                any.generated_type()
+            }
+            Any::Integer(integer) => {
+                self.findings.extend(ranges::integer(integer, path));
+                any.generated_type()
+            }
+            Any::Bool(bool) => {
+                self.findings.extend(ranges::bool(bool, path));
+                any.generated_type()
+            }
+            Any::String(string) => {
+                self.string(string, context, path);
+                any.generated_type()
+            }
+            Any::AnyOf(any_of) => {
+                self.findings.extend(ranges::any_of(any_of, path));
+                let types = any_of
+                    .values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| self.generate(value, context, &path.append("values", i)).0)
+                    .collect::<Vec<_>>();
+
+                match any_of.num {
+                    _ if any_of.allow_null.unwrap_or(false) => None,
+                    None | Some(1) => types
+                        .first()
+                        .copied()
+                        .flatten()
+                        .filter(|first| types.iter().all(|t| *t == Some(first))),
+                    Some(_) => Some("Array"),
+                }
+            }
+            Any::Reference(reference) => self.reference_schema(reference, context, path),
+            Any::Plugin(plugin) => {
+                self.plugins.insert(plugin.plugin_name.clone());
+                opaque = true;
+                None
+            }
+            Any::Array(array) => {
+                self.array(array, context, path);
+                any.generated_type()
+            }
+            Any::Object(object) => {
+                self.object(object, context, path);
+                any.generated_type()
+            }
+            Any::Flatten(flatten) => {
+                for (i, value) in flatten.values.iter().enumerate() {
+                    let path = path.append("values", i);
+                    match value {
+                        FlattenableValue::Object(object) => self.object(object, context, &path),
+                        FlattenableValue::Array(array) => self.array(array, context, &path),
+                        FlattenableValue::Reference(reference) => {
+                            self.reference_schema(reference, context, &path);
+                        }
+                        FlattenableValue::Plugin(plugin) => {
+                            self.plugins.insert(plugin.plugin_name.clone());
+                            opaque = true;
+                        }
+                        FlattenableValue::Include(include) => {
+                            opaque |= self.include(include, context, &path).1;
+                        }
+                    }
+                }
+
+                None
+            }
+            Any::File(_) => {
+                opaque = true;
+                None
+            }
+            Any::Include(include) => return self.include(include, context, path),
+            Any::Counter(_) => any.generated_type(),
+        };
+
+        let transforms = any.transforms().unwrap_or_default();
+        (
+            self.transforms(&transforms, value_type, context, path),
+            opaque,
+        )
+    }
+
+    /// Lint an included file as part of the schema.
+    /// Files which can't be read or include themselves are opaque.
+    fn include(
+        &mut self,
+        include: &Include,
+        context: ContextId,
+        path: &ValidationPath,
+    ) -> (Option<&'static str>, bool) {
+        let file = std::fs::canonicalize(&include.path).unwrap_or_else(|_| (&include.path).into());
+        if let Some(start) = self.includes.iter().position(|included| *included == file) {
+            let cycle = self.includes[start..]
+                .iter()
+                .chain([&file])
+                .map(|included| format!("'{}'", included.display()))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            self.findings.push(
+                Finding::error(
+                    LintKind::IncludeCycle,
+                    path,
+                    format!("The file '{}' includes itself: {cycle}", include.path),
+                )
+                .with_fix(format!("Remove the include of '{}'", include.path)),
+            );
+
+            return (None, true);
+        }
+
+        let Ok(MaybeValidAny::Valid(any)) = include.as_schema() else {
+            return (None, true);
+        };
+
+        self.includes.push(file);
+        let res = self.any(&any, context, path);
+        self.includes.pop();
+
+        res
+    }
+
+    fn string(&mut self, string: &StringSchema, context: ContextId, path: &ValidationPath) {
+        let StringSchema::Generated { generator, .. } = string else {
+            return;
+        };
+
+        match generator {
+            StringGenerator::Format { args, .. } => {
+                for (name, arg) in args {
+                    let path = path.append("args", name);
+                    match arg {
+                        FormatArg::String(string) => {
+                            self.reference(context, string, &path);
+                        }
+                        FormatArg::StringSchema(string) => self.string(string, context, &path),
+                        FormatArg::Reference(reference) => {
+                            self.reference_schema(reference, context, &path);
+                        }
+                        FormatArg::Integer(_) | FormatArg::Number(_) => {}
+                    }
+                }
+            }
+            StringGenerator::DateTime { from, to, .. } => {
+                self.findings.extend(ranges::date_time(from, to, path));
+            }
+            _ => {}
+        }
+    }
+
+    fn object(&mut self, object: &Object, context: ContextId, path: &ValidationPath) {
+        // The properties of an object can reference each other
+        let store = self.contexts.store();
+        for (key, value) in &object.properties {
+            let child = self.contexts.child(context, store, key.clone());
+            self.value(value, child, &path.append("properties", key));
+        }
+    }
+
+    fn array(&mut self, array: &Array, context: ContextId, path: &ValidationPath) {
+        match array {
+            Array::RandomArray(array) => {
+                self.findings
+                    .extend(ranges::array_length(&array.length, path));
+
+                // Items may reference values generated by the previous items,
+                // so the items are linted twice if there may be more than one
+                let max_length = match array.length {
+                    ArrayLength::ShortConstant(value) | ArrayLength::Constant { value } => value,
+                    ArrayLength::Random { min, max } => min.max(max),
+                };
+
+                for i in 0..max_length.clamp(1, 2) {
+                    let store = self.contexts.store();
+                    let child = self.contexts.child(context, store, i.to_string());
+                    self.value(&array.items, child, &path.append_single("items"));
+                }
+            }
+            Array::ArrayWithValues(array) => {
+                for (i, value) in array.values.iter().enumerate() {
+                    let store = self.contexts.store();
+                    let child = self.contexts.child(context, store, i.to_string());
+                    self.value(value, child, &path.append("items", i));
+                }
+            }
+        }
+    }
+
+    fn reference_schema(
+        &mut self,
+        reference: &Reference,
+        context: ContextId,
+        path: &ValidationPath,
+    ) -> Option<&'static str> {
+        let value = if reference.reference.starts_with("ref:") {
+            reference.reference.clone()
+        } else {
+            format!("ref:{}", reference.reference)
+        };
+
+        let value_type = self.reference(context, &value, path);
+        for (i, except) in reference.except.iter().flatten().enumerate() {
+            if let StringOrNumber::String(except) = except {
+                self.reference(context, except, &path.append("except", i));
+            }
+        }
+
+        if reference.keep_all.unwrap_or(false) {
+            Some("Array")
+        } else {
+            value_type
+        }
+    }
+
+    /// Check a reference, returning the type of the value it resolves to.
+    fn reference(
+        &mut self,
+        context: ContextId,
+        reference: &str,
+        path: &ValidationPath,
+    ) -> Option<&'static str> {
+        let target = self.contexts.target(context, reference)?;
+        let resolved = self.contexts.lookup(&target).cloned();
+
+        let check = self
+            .references
+            .entry((path.to_string(), reference.to_string()))
+            .or_insert(ReferenceCheck {
+                context,
+                target: target.clone(),
+                resolved: false,
+            });
+        check.context = context;
+        check.target = target;
+        check.resolved |= resolved.is_some();
+
+        resolved.and_then(|resolved| resolved.value_type)
+    }
+
+    /// Check the types of the transforms, returning the type of the transformed value.
+    fn transforms(
+        &mut self,
+        transforms: &[MaybeValidTransform],
+        input: Option<&'static str>,
+        context: ContextId,
+        path: &ValidationPath,
+    ) -> Option<&'static str> {
+        self.transform_values(transforms, context, path);
+        if let Err(errors) = validate_transform_types(transforms, input, path) {
+            self.findings
+                .extend(errors.errors.into_iter().map(|error| Finding {
+                    kind: LintKind::TransformType,
+                    severity: Severity::Error,
+                    path: error.path,
+                    message: error.message,
+                    fix: None,
+                }));
+        }
+
+        transforms
+            .iter()
+            .try_fold(input?, |current, transform| match transform {
+                MaybeValidTransform::Valid(transform) => transform.output_type(current),
+                MaybeValidTransform::Invalid(_) => None,
+            })
+    }
+
+    /// Check the plugins and references used by the transforms.
+    /// Transforms resolve references in the context of the transformed value.
+    fn transform_values(
+        &mut self,
+        transforms: &[MaybeValidTransform],
+        context: ContextId,
+        path: &ValidationPath,
+    ) {
+        for (i, transform) in transforms.iter().enumerate() {
+            let MaybeValidTransform::Valid(transform) = transform else {
+                continue;
+            };
+
+            let path = path.append("transform", i);
+            match transform {
+                Transform::Plugin(plugin) => {
+                    self.plugins.insert(plugin.name.clone());
+                }
+                Transform::Filter(filter) => self.filter(filter, context, &path),
+                Transform::When(when) => {
+                    if let Some(condition) = &when.condition {
+                        self.filter(condition, context, &path.append_single("condition"));
+                    }
+
+                    self.transform_values(&when.transform, context, &path);
+                }
+                Transform::Reshape(reshape) => {
+                    for (key, value) in reshape.set.iter().flatten() {
+                        if let GeneratedSchema::String(value) = value {
+                            self.reference(context, value, &path.append("set", key));
+                        }
+                    }
+                }
+                Transform::Merge(merge) => {
+                    let path = path.append_single("value");
+                    match &merge.value {
+                        AnyValue::String(reference) => {
+                            self.reference(context, reference, &path);
+                        }
+                        value => {
+                            let store = self.contexts.store();
+                            let child = self.contexts.child(context, store, "merge".into());
+                            self.value(value, child, &path);
+                        }
+                    }
+                }
+                Transform::JsonPatch(patch) => {
+                    for (i, operation) in patch.operations.iter().enumerate() {
+                        if let JsonPatchOperation::Add { value, .. }
+                        | JsonPatchOperation::Replace { value, .. }
+                        | JsonPatchOperation::Test { value, .. } = operation
+                        {
+                            if let Value::String(value) = value {
+                                let path = path.append("operations", i).append_single("value");
+                                self.reference(context, value, &path);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn filter(&mut self, filter: &FilterTransform, context: ContextId, path: &ValidationPath) {
+        match &filter.field {
+            Some(ReferenceOrString::Reference(reference)) => {
+                self.reference_schema(reference, context, &path.append_single("field"));
+            }
+            Some(ReferenceOrString::String(field)) => {
+                self.reference(context, field, &path.append_single("field"));
+            }
+            None => {}
+        }
+
+        if let GeneratedSchema::String(other) = &filter.other {
+            self.reference(context, other, &path.append_single("other"));
+        }
+
+        for (i, filter) in filter.filters.iter().flatten().enumerate() {
+            self.filter(filter, context, &path.append("filters", i));
+        }
+    }
+}
+
+fn reference_finding(
+    contexts: &Contexts,
+    path: &str,
+    reference: &str,
+    check: &ReferenceCheck,
+) -> Finding {
+    let path = path.to_string();
+    let Some(target) = contexts.lookup(&check.target) else {
+        let message = match &check.target {
+            Target::Key { key, .. } => {
+                format!("The reference '{reference}' doesn't match any value named '{key}'")
+            }
+            Target::AboveRoot { .. } => {
+                format!("The reference '{reference}' goes above the root of the schema")
+            }
+        };
+
+        return Finding {
+            kind: LintKind::UnknownReference,
+            severity: Severity::Error,
+            path,
+            message,
+            fix: contexts
+                .suggest(check.context, &check.target)
+                .map(|suggestion| format!("Did you mean '{suggestion}'?")),
+        };
+    };
+
+    // Find the properties containing the reference and the referenced value
+    let source = contexts.path(check.context);
+    let common = source
+        .iter()
+        .zip(&target.path)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let (message, fix) = match (source.get(common), target.path.get(common)) {
+        (_, None) => (
+            format!("The reference '{reference}' points to a value containing the reference, which is generated after it"),
+            None,
+        ),
+        (Some(source), Some(target)) if !is_index(source) && !is_index(target) => (
+            format!("The reference '{reference}' is resolved before '{target}' is generated, as properties are generated in the order they are defined in"),
+            Some(format!("Move '{source}' after '{target}'")),
+        ),
+        _ => (
+            format!("The reference '{reference}' is resolved before the referenced value is generated"),
+            None,
+        ),
+    };
+
+    Finding {
+        kind: LintKind::ForwardReference,
+        severity: Severity::Error,
+        path,
+        message,
+        fix,
+    }
+}
+
+fn is_index(segment: &str) -> bool {
+    segment.chars().all(|c| c.is_numeric())
+}
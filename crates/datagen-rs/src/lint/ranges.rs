@@ -0,0 +1,154 @@
+use crate::lint::{Finding, LintKind};
+use crate::schema::any_of::AnyOf;
+use crate::schema::array::ArrayLength;
+use crate::schema::bool::Bool;
+use crate::schema::integer::Integer;
+use crate::schema::number::Number;
+use crate::validation::path::ValidationPath;
+use chrono::{DateTime, Timelike};
+
+pub(super) fn integer(integer: &Integer, path: &ValidationPath) -> Option<Finding> {
+    let Integer::Random {
+        min: Some(min),
+        max: Some(max),
+        ..
+    } = integer
+    else {
+        return None;
+    };
+
+    (min > max).then(|| {
+        Finding::error(
+            LintKind::ImpossibleRange,
+            path,
+            format!("The minimum {min} is greater than the maximum {max}"),
+        )
+        .with_fix("Swap min and max")
+    })
+}
+
+pub(super) fn number(number: &Number, path: &ValidationPath) -> Option<Finding> {
+    let Number::Random { min, max, .. } = number else {
+        return None;
+    };
+
+    // The defaults used while generating
+    let low = min.unwrap_or(0.0);
+    let high = max.unwrap_or(1.0);
+    if low < high || low.is_nan() || high.is_nan() {
+        return None;
+    }
+
+    // Random numbers are generated in a half-open range, which is empty if both bounds are equal
+    if low == high {
+        return Some(
+            Finding::error(
+                LintKind::ImpossibleRange,
+                path,
+                format!("The range is empty, as the minimum and the maximum are both {low}"),
+            )
+            .with_fix(format!("Use a constant number with the value {low}")),
+        );
+    }
+
+    let fix = match (min, max) {
+        (Some(_), Some(_)) => "Swap min and max".to_string(),
+        (Some(_), None) => format!("Set max to a value greater than {low}, it defaults to 1"),
+        _ => format!("Set min to a value less than {high}, it defaults to 0"),
+    };
+
+    Some(
+        Finding::error(
+            LintKind::ImpossibleRange,
+            path,
+            format!("The minimum {low} is greater than the maximum {high}"),
+        )
+        .with_fix(fix),
+    )
+}
+
+pub(super) fn bool(bool: &Bool, path: &ValidationPath) -> Option<Finding> {
+    let Bool::Random {
+        probability: Some(probability),
+        ..
+    } = bool
+    else {
+        return None;
+    };
+
+    (!(0.0..=1.0).contains(probability)).then(|| {
+        Finding::error(
+            LintKind::ImpossibleRange,
+            &path.append_single("probability"),
+            format!("The probability {probability} is not between 0 and 1"),
+        )
+        .with_fix("Set the probability to a value between 0 and 1")
+    })
+}
+
+pub(super) fn array_length(length: &ArrayLength, path: &ValidationPath) -> Option<Finding> {
+    let ArrayLength::Random { min, max } = length else {
+        return None;
+    };
+
+    (min > max).then(|| {
+        Finding::error(
+            LintKind::ImpossibleRange,
+            &path.append_single("length"),
+            format!("The minimum length {min} is greater than the maximum length {max}"),
+        )
+        .with_fix("Swap min and max")
+    })
+}
+
+pub(super) fn any_of(any_of: &AnyOf, path: &ValidationPath) -> Option<Finding> {
+    let num = any_of.num.unwrap_or(1);
+    let len = any_of.values.len();
+    if num <= len as i64 {
+        return None;
+    }
+
+    let finding = Finding::error(
+        LintKind::ImpossibleRange,
+        &path.append_single("num"),
+        format!("{num} values are requested, but only {len} values are defined"),
+    );
+
+    Some(if len > 0 {
+        finding.with_fix(format!("Set num to {len}"))
+    } else {
+        finding.with_fix("Add values to choose from")
+    })
+}
+
+pub(super) fn date_time(
+    from: &Option<String>,
+    to: &Option<String>,
+    path: &ValidationPath,
+) -> Option<Finding> {
+    let (from, to) = (from.as_ref()?, to.as_ref()?);
+    let min = DateTime::parse_from_rfc3339(from).ok()?;
+    let max = DateTime::parse_from_rfc3339(to).ok()?;
+
+    // The seconds are ignored while generating
+    if min.with_second(0)? < max.with_second(0)? {
+        return None;
+    }
+
+    let path = path.append_single("from");
+    Some(if min > max {
+        Finding::error(
+            LintKind::ImpossibleRange,
+            &path,
+            format!("The date '{from}' is after the date '{to}'"),
+        )
+        .with_fix("Swap from and to")
+    } else {
+        Finding::error(
+            LintKind::ImpossibleRange,
+            &path,
+            "The dates are less than one minute apart",
+        )
+        .with_fix("Set to at least one minute after from")
+    })
+}
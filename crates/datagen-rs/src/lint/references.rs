@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+/// The id of a context in [`Contexts`].
+pub(super) type ContextId = usize;
+
+/// A value which is visible to references once it has been generated.
+#[derive(Debug, Clone)]
+pub(super) struct GeneratedValue {
+    /// The path of the value, including array indices.
+    pub path: Vec<String>,
+    /// The type of the value, if it is known.
+    pub value_type: Option<&'static str>,
+    /// Whether the structure of the value is unknown,
+    /// like the structure of values created by plugins.
+    pub opaque: bool,
+}
+
+/// The values a reference may be resolved against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Target {
+    /// A key in the store of generated values.
+    Key { store: usize, key: String },
+    /// The reference goes above the root of the schema.
+    AboveRoot { key: String },
+}
+
+struct Context {
+    parent: Option<ContextId>,
+    store: usize,
+    path: Vec<String>,
+}
+
+/// A static model of the contexts created while generating data.
+///
+/// Like while generating, the properties of an object share a store
+/// of generated values, while array items and the root have their own store.
+/// A generated value is recorded in the stores of all parent contexts,
+/// using its path relative to the context, without array indices.
+#[derive(Default)]
+pub(super) struct Contexts {
+    contexts: Vec<Context>,
+    stores: Vec<HashMap<String, GeneratedValue>>,
+}
+
+impl Contexts {
+    pub fn root(&mut self) -> ContextId {
+        let store = self.store();
+        self.push(None, store, vec![])
+    }
+
+    /// Create a new store, which is shared by all contexts created using it.
+    pub fn store(&mut self) -> usize {
+        self.stores.push(HashMap::new());
+        self.stores.len() - 1
+    }
+
+    pub fn child(&mut self, parent: ContextId, store: usize, segment: String) -> ContextId {
+        let mut path = self.contexts[parent].path.clone();
+        path.push(segment);
+
+        self.push(Some(parent), store, path)
+    }
+
+    fn push(&mut self, parent: Option<ContextId>, store: usize, path: Vec<String>) -> ContextId {
+        self.contexts.push(Context {
+            parent,
+            store,
+            path,
+        });
+        self.contexts.len() - 1
+    }
+
+    pub fn path(&self, context: ContextId) -> &[String] {
+        &self.contexts[context].path
+    }
+
+    /// Record the value generated in the context.
+    pub fn finalize(&mut self, context: ContextId, value_type: Option<&'static str>, opaque: bool) {
+        let path = self.contexts[context].path.clone();
+        let mut current = Some(context);
+
+        while let Some(id) = current {
+            let depth = self.contexts[id].path.len();
+            let key = normalize(&path[depth.saturating_sub(1)..]);
+            if !key.is_empty() {
+                self.stores[self.contexts[id].store]
+                    .entry(key)
+                    .or_insert_with(|| GeneratedValue {
+                        path: path.clone(),
+                        value_type,
+                        opaque,
+                    });
+            }
+
+            current = self.contexts[id].parent;
+        }
+    }
+
+    /// Get the values a reference is resolved against.
+    /// Returns `None` if the value is a plain string.
+    pub fn target(&self, context: ContextId, reference: &str) -> Option<Target> {
+        let stripped = reference.strip_prefix("ref:")?;
+
+        Some(if let Some(key) = stripped.strip_prefix("./") {
+            Target::Key {
+                store: self.contexts[context].store,
+                key: key.to_string(),
+            }
+        } else if stripped.starts_with("../") {
+            let mut current = context;
+            let mut key = stripped;
+            while let Some(rest) = key.strip_prefix("../") {
+                let Some(parent) = self.contexts[current].parent else {
+                    return Some(Target::AboveRoot {
+                        key: stripped.trim_start_matches("../").to_string(),
+                    });
+                };
+
+                current = parent;
+                key = rest;
+            }
+
+            Target::Key {
+                store: self.contexts[current].store,
+                key: key.to_string(),
+            }
+        } else {
+            Target::Key {
+                store: self.contexts[0].store,
+                key: stripped.to_string(),
+            }
+        })
+    }
+
+    /// Get the value a reference currently resolves to.
+    /// Keys inside of values with an unknown structure resolve to these values.
+    pub fn lookup(&self, target: &Target) -> Option<&GeneratedValue> {
+        let Target::Key { store, key } = target else {
+            return None;
+        };
+
+        let store = &self.stores[*store];
+        store.get(key).or_else(|| {
+            key.match_indices('.')
+                .map(|(i, _)| &key[..i])
+                .filter_map(|prefix| store.get(prefix))
+                .find(|value| value.opaque)
+        })
+    }
+
+    /// Suggest a reference which resolves to a value, for a reference which doesn't.
+    /// The same key in another store is preferred over similar keys.
+    pub fn suggest(&self, context: ContextId, target: &Target) -> Option<String> {
+        let (Target::Key { key, .. } | Target::AboveRoot { key }) = target;
+
+        let candidates = self.candidates(context);
+        if let Some((prefix, _)) = candidates
+            .iter()
+            .find(|(_, store)| self.stores[*store].contains_key(key))
+        {
+            return Some(format!("ref:{prefix}{key}"));
+        }
+
+        let max_distance = (key.chars().count() / 3).max(1);
+        let intended = match target {
+            Target::Key { store, .. } => Some(*store),
+            Target::AboveRoot { .. } => None,
+        };
+
+        // Prefer keys in the store the reference points to
+        candidates
+            .iter()
+            .filter(|(_, store)| Some(*store) == intended)
+            .chain(
+                candidates
+                    .iter()
+                    .filter(|(_, store)| Some(*store) != intended),
+            )
+            .enumerate()
+            .flat_map(|(i, (prefix, store))| {
+                self.stores[*store]
+                    .keys()
+                    .map(move |candidate| (edit_distance(key, candidate), i, prefix, candidate))
+            })
+            .filter(|(distance, ..)| *distance <= max_distance)
+            .min_by(|(a, a_index, _, a_key), (b, b_index, _, b_key)| {
+                a.cmp(b).then(a_index.cmp(b_index)).then(a_key.cmp(b_key))
+            })
+            .map(|(_, _, prefix, candidate)| format!("ref:{prefix}{candidate}"))
+    }
+
+    /// Get the stores visible from a context, with the prefix of references into them.
+    /// The root store is referenced using absolute references.
+    fn candidates(&self, context: ContextId) -> Vec<(String, usize)> {
+        let root = self.contexts[0].store;
+        let mut candidates = vec![];
+        let mut prefix = "./".to_string();
+        let mut current = Some(context);
+
+        while let Some(id) = current {
+            let store = self.contexts[id].store;
+            if store != root {
+                candidates.push((prefix.clone(), store));
+            }
+
+            prefix = if prefix == "./" {
+                "../".into()
+            } else {
+                format!("../{prefix}")
+            };
+            current = self.contexts[id].parent;
+        }
+
+        candidates.push((String::new(), root));
+        candidates
+    }
+}
+
+/// Join the path, skipping array indices.
+fn normalize(path: &[String]) -> String {
+    path.iter()
+        .filter(|segment| !segment.chars().all(|c| c.is_numeric()))
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// The number of insertions, deletions, substitutions and
+/// transpositions of adjacent characters needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
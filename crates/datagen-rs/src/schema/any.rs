@@ -132,10 +132,47 @@ pub mod generate {
 #[cfg(feature = "validate-schema")]
 pub mod validate {
     use crate::schema::any::{Any, MaybeValidAny};
+    use crate::schema::transform::validate::validate_transform_types;
+    use crate::schema::transform::MaybeValidTransform;
+    use crate::util::traits::GetTransform;
     use crate::validation::path::ValidationPath;
     use crate::validation::result::{IterValidate, ValidationResult};
     use crate::validation::validate::{Validate, ValidateGenerateSchema};
 
+    impl Any {
+        /// Get the type of the value generated by this schema
+        /// before any transforms are applied, if it is known.
+        pub(crate) fn generated_type(&self) -> Option<&'static str> {
+            match self {
+                Any::Number(_) => Some("Number"),
+                Any::Integer(_) | Any::Counter(_) => Some("Integer"),
+                Any::Bool(_) => Some("Bool"),
+                Any::String(_) => Some("String"),
+                Any::Array(_) => Some("Array"),
+                Any::Object(_) => Some("Object"),
+                _ => None,
+            }
+        }
+
+        pub(crate) fn transforms(&self) -> Option<Vec<MaybeValidTransform>> {
+            match self {
+                Any::Number(number) => number.get_transform(),
+                Any::Integer(integer) => integer.get_transform(),
+                Any::Counter(counter) => counter.get_transform(),
+                Any::Bool(bool) => bool.get_transform(),
+                Any::String(string) => string.get_transform(),
+                Any::AnyOf(any_of) => any_of.get_transform(),
+                Any::Reference(reference) => reference.get_transform(),
+                Any::Plugin(plugin) => plugin.get_transform(),
+                Any::Array(array) => array.get_transform(),
+                Any::Object(object) => object.get_transform(),
+                Any::Flatten(flatten) => flatten.get_transform(),
+                Any::File(file) => file.get_transform(),
+                Any::Include(include) => include.get_transform(),
+            }
+        }
+    }
+
     impl ValidateGenerateSchema for MaybeValidAny {
         fn validate_generate_schema(&self, path: &ValidationPath) -> ValidationResult {
             match self {
@@ -167,6 +204,11 @@ pub mod validate {
                 Any::File(file) => file.validate(path),
                 Any::Include(include) => include.validate(path),
             }
+            .concat(validate_transform_types(
+                &self.transforms().unwrap_or_default(),
+                self.generated_type(),
+                path,
+            ))
         }
     }
 }
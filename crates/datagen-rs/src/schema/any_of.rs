@@ -47,7 +47,8 @@ pub mod generate {
             mut self,
             schema: DatagenContextRef,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            self.values.shuffle(&mut rand::thread_rng());
+            let mut rng = schema.rng();
+            self.values.shuffle(&mut rng);
             let min = if self.allow_null.unwrap_or(false) {
                 0
             } else {
@@ -57,9 +58,7 @@ pub mod generate {
             let mut num = self.num.unwrap_or(1);
             match num.cmp(&0) {
                 Ordering::Equal => num = self.values.len() as i64,
-                Ordering::Less => {
-                    num = rand::thread_rng().gen_range(min..=self.values.len() as i64)
-                }
+                Ordering::Less => num = rng.gen_range(min..=self.values.len() as i64),
                 _ => {}
             }
 
@@ -39,7 +39,9 @@ pub mod generate {
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
             match self {
                 AnyValue::Any(any) => any.into_random(schema),
-                AnyValue::String(string) => schema.resolve_ref(&string)?.into_random(),
+                AnyValue::String(string) => {
+                    schema.resolve_ref(&string)?.into_random(&mut schema.rng())
+                }
                 AnyValue::Number(number) => {
                     schema.finalize(GeneratedSchema::Number(number.into()).into())
                 }
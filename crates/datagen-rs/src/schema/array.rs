@@ -76,14 +76,11 @@ pub mod generate {
     use super::{ArrayWithValues, RandomArray};
 
     impl ArrayLength {
-        pub fn get_length(&self) -> u32 {
+        pub fn get_length<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
             match self {
                 ArrayLength::ShortConstant(value) => *value,
                 ArrayLength::Constant { value } => *value,
-                ArrayLength::Random { min, max } => {
-                    let mut rng = rand::thread_rng();
-                    rng.gen_range(*min..=*max)
-                }
+                ArrayLength::Random { min, max } => rng.gen_range(*min..=*max),
             }
         }
     }
@@ -100,6 +97,16 @@ pub mod generate {
                 }
             }
         }
+
+        // Random arrays apply their transforms and finalize themselves,
+        // as some transforms need to generate new items
+        fn should_finalize(&self) -> bool {
+            !matches!(self, Array::RandomArray(_))
+        }
+
+        fn should_transform(&self) -> bool {
+            !matches!(self, Array::RandomArray(_))
+        }
     }
 
     impl IntoGeneratedArc for RandomArray {
@@ -107,10 +114,14 @@ pub mod generate {
             self,
             schema: DatagenContextRef,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            let length = self.length.get_length();
-            schema.map_array(length as _, self.items, None, false, |cur, value| {
-                value.into_random(cur.clone())
-            })
+            let length = self.length.get_length(&mut schema.rng());
+            schema.map_array(
+                length as _,
+                self.items,
+                self.transform,
+                true,
+                |cur, value| value.into_random(cur.clone()),
+            )
         }
     }
 
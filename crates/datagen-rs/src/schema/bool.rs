@@ -38,11 +38,11 @@ pub mod generate {
     use rand::Rng;
 
     impl IntoGenerated for Bool {
-        fn into_generated(self, _: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
+        fn into_generated(self, schema: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
             Ok(match self {
                 Bool::Constant { value, .. } => GeneratedSchema::Bool(value),
                 Bool::Random { probability, .. } => {
-                    let mut rng = rand::thread_rng();
+                    let mut rng = schema.rng();
                     let value = rng.gen_bool(probability.unwrap_or(0.5));
                     GeneratedSchema::Bool(value)
                 }
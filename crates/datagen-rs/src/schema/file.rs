@@ -48,7 +48,7 @@ pub mod generate {
         Lazy::new(|| Mutex::new(HashMap::new()));
 
     impl IntoGenerated for File {
-        fn into_generated(self, _: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
+        fn into_generated(self, schema: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
             let mut lock = FILES.lock().unwrap();
             let data = match lock.get_mut(&self.path) {
                 Some(val) => val,
@@ -62,7 +62,7 @@ pub mod generate {
 
             let value = match self.mode.unwrap_or_default() {
                 FileMode::Sequential => data.next_value().clone(),
-                FileMode::Random => data.random().clone(),
+                FileMode::Random => data.random(&mut schema.rng()).clone(),
             };
 
             Ok(GeneratedSchema::Value(value))
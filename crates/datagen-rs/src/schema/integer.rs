@@ -41,11 +41,11 @@ pub mod generate {
     use rand::Rng;
 
     impl IntoGenerated for Integer {
-        fn into_generated(self, _: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
+        fn into_generated(self, schema: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
             Ok(match self {
                 Integer::Constant { value, .. } => GeneratedSchema::Integer(value),
                 Integer::Random { min, max, .. } => {
-                    let mut rng = rand::thread_rng();
+                    let mut rng = schema.rng();
                     let min = min.unwrap_or(i64::MIN);
                     let max = max.unwrap_or(i64::MAX);
                     let value = rng.gen_range(min..=max);
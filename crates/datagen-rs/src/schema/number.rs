@@ -43,7 +43,7 @@ pub mod generate {
     use rand::Rng;
 
     impl IntoGenerated for Number {
-        fn into_generated(self, _: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
+        fn into_generated(self, schema: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
             Ok(match self {
                 Number::Constant { value, .. } => GeneratedSchema::Number(value.into()),
                 Number::Random {
@@ -52,7 +52,7 @@ pub mod generate {
                     precision,
                     ..
                 } => {
-                    let mut rng = rand::thread_rng();
+                    let mut rng = schema.rng();
                     let min = min.unwrap_or(0_f64);
                     let max = max.unwrap_or(1_f64);
                     let mut value = rng.gen_range(min..max);
@@ -27,6 +27,7 @@ pub mod generate {
     use crate::generate::datagen_context::DatagenContextRef;
     use crate::generate::generated_schema::generate::IntoGeneratedArc;
     use crate::generate::generated_schema::GeneratedSchema;
+    use crate::generate::profiler;
     use crate::schema::plugin::Plugin;
     use std::sync::Arc;
 
@@ -35,9 +36,10 @@ pub mod generate {
             self,
             schema: DatagenContextRef,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            schema
-                .get_plugin(&self.plugin_name)?
-                .generate(schema, self.args.unwrap_or_default())
+            let plugin = schema.get_plugin(&self.plugin_name)?;
+            let _scope = profiler::plugin_scope(&self.plugin_name, "generate");
+
+            plugin.generate(schema, self.args.unwrap_or_default())
         }
     }
 }
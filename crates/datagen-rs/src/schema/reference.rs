@@ -52,7 +52,7 @@ pub mod generate {
 
             let resolved = schema.resolve_ref(&reference)?;
             let Some(except) = self.except else {
-                return resolved.into_random();
+                return resolved.into_random(&mut schema.rng());
             };
 
             let Some(resolved) = resolved.into_vec() else {
@@ -82,7 +82,7 @@ pub mod generate {
 
             Ok(if self.keep_all.unwrap_or(false) {
                 Arc::new(GeneratedSchema::Array(resolved))
-            } else if let Some(resolved) = resolved.choose(&mut rand::thread_rng()) {
+            } else if let Some(resolved) = resolved.choose(&mut schema.rng()) {
                 resolved.clone()
             } else {
                 Arc::new(GeneratedSchema::None)
@@ -79,12 +79,20 @@ impl GetTransform for Schema {
 pub mod validate {
     use crate::schema::schema_definition::Schema;
     use crate::validation::path::ValidationPath;
+    #[cfg(feature = "generate")]
+    use crate::validation::result::IterValidate;
     use crate::validation::result::ValidationResult;
     use crate::validation::validate::{Validate, ValidateGenerateSchema};
 
     impl ValidateGenerateSchema for Schema {
         fn validate_generate_schema(&self, path: &ValidationPath) -> ValidationResult {
-            self.value.validate(path)
+            let res = self.value.validate(path);
+            #[cfg(feature = "generate")]
+            if let Some(serializer) = self.options.as_ref().and_then(|o| o.serializer.as_ref()) {
+                return res.concat(serializer.validate(&path.append("options", "serializer")));
+            }
+
+            res
         }
     }
 }
@@ -1,3 +1,4 @@
+use indexmap::IndexMap;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 #[cfg(feature = "serialize")]
@@ -22,6 +23,8 @@ pub enum Serializer {
     Yaml,
     /// The XML serializer.
     /// The root element must be specified.
+    /// CDATA sections are only written if at least one of
+    /// the options besides `root_element` and `pretty` is set.
     #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
     Xml {
         /// The name of the root element.
@@ -29,6 +32,38 @@ pub enum Serializer {
         /// Whether to pretty print the XML.
         /// If not specified, the default is false.
         pretty: Option<bool>,
+        /// The prefix of object keys which are written as attributes.
+        /// If not specified, the default is `@`.
+        attribute_prefix: Option<String>,
+        /// The object key whose value is written as the text content of the element.
+        /// If not specified, the default is `$text`.
+        text_key: Option<String>,
+        /// The object key whose value is written as a CDATA section.
+        /// If not specified, the default is `$cdata`.
+        cdata_key: Option<String>,
+        /// The element names of array items, keyed by the name of the array.
+        /// Arrays with an item element are wrapped in an element named after the array.
+        /// Arrays without an item element are written as repeated elements.
+        item_elements: Option<IndexMap<String, String>>,
+        /// The namespaces to declare on the root element, keyed by their prefix.
+        /// Use an empty prefix to declare the default namespace.
+        namespaces: Option<IndexMap<String, String>>,
+    },
+    /// The Avro serializer.
+    /// Writes an Avro Object Container File. If the generated data
+    /// is an array, every item is written as a separate record.
+    #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+    Avro {
+        /// The path to an `.avsc` file containing the schema to use.
+        /// If not specified, the schema is inferred from the generated data.
+        schema: Option<String>,
+        /// The name of the inferred record.
+        /// Ignored if a schema is specified.
+        /// If not specified, the default is `Record`.
+        record_name: Option<String>,
+        /// The namespace of the inferred record.
+        /// Ignored if a schema is specified.
+        namespace: Option<String>,
     },
     /// A plugin serializer.
     #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
@@ -40,6 +75,25 @@ pub enum Serializer {
     },
 }
 
+impl Serializer {
+    /// Whether this serializer produces binary data
+    /// which can not be represented as a string.
+    pub fn is_binary(&self) -> bool {
+        matches!(self, Serializer::Avro { .. })
+    }
+
+    /// The MIME type of the serialized data.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Serializer::Json { .. } => "application/json",
+            Serializer::Yaml => "application/yaml",
+            Serializer::Xml { .. } => "application/xml",
+            Serializer::Avro { .. } => "avro/binary",
+            Serializer::Plugin { .. } => "text/plain",
+        }
+    }
+}
+
 impl Default for Serializer {
     fn default() -> Self {
         Serializer::Json { pretty: None }
@@ -55,11 +109,14 @@ impl Default for &Serializer {
 #[cfg(feature = "generate")]
 pub mod generate {
     use crate::generate::generated_schema::GeneratedSchema;
+    use crate::generate::profiler;
     use crate::plugins::plugin::PluginSerializeCallback;
     use crate::plugins::plugin_list::PluginList;
     use crate::schema::serializer::Serializer;
+    use crate::util::avro::{write_container_file, AvroSchema};
+    use crate::util::xml::{to_xml_string, XmlOptions};
     use anyhow::{anyhow, Context};
-    use std::io::Read;
+    use std::io::{BufWriter, Read, Write};
     use std::sync::Arc;
     use xml::{EmitterConfig, ParserConfig};
 
@@ -100,9 +157,33 @@ pub mod generate {
                 Serializer::Xml {
                     root_element,
                     pretty,
+                    attribute_prefix,
+                    text_key,
+                    cdata_key,
+                    item_elements,
+                    namespaces,
                 } => {
-                    let res = quick_xml::se::to_string_with_root(root_element, &generated)
-                        .map_err(anyhow::Error::new)?;
+                    let res = if attribute_prefix.is_none()
+                        && text_key.is_none()
+                        && cdata_key.is_none()
+                        && item_elements.is_none()
+                        && namespaces.is_none()
+                    {
+                        quick_xml::se::to_string_with_root(root_element, &generated)
+                            .map_err(anyhow::Error::new)?
+                    } else {
+                        to_xml_string(
+                            &serde_json::to_value(&generated)?,
+                            root_element,
+                            &XmlOptions {
+                                attribute_prefix: attribute_prefix.as_deref().unwrap_or("@"),
+                                text_key: text_key.as_deref().unwrap_or("$text"),
+                                cdata_key: cdata_key.as_deref().unwrap_or("$cdata"),
+                                item_elements: item_elements.as_ref(),
+                                namespaces: namespaces.as_ref(),
+                            },
+                        )?
+                    };
 
                     if pretty.unwrap_or(false) {
                         format_xml(res.as_bytes())
@@ -110,16 +191,89 @@ pub mod generate {
                         Ok(res)
                     }
                 }
-                Serializer::Plugin { plugin_name, args } => plugins
-                    .ok_or(anyhow!("A plugin serializer is not allowed at this point"))?
-                    .get(plugin_name)?
-                    .serialize(&generated, args.clone().unwrap_or_default())
-                    .with_context(|| {
-                        anyhow!("Failed to serialize data using plugin '{plugin_name}'")
-                    }),
+                Serializer::Avro { .. } => Err(anyhow!(
+                    "The avro serializer produces binary data which can not be serialized to a string"
+                )),
+                Serializer::Plugin { plugin_name, args } => {
+                    let plugins = plugins
+                        .ok_or(anyhow!("A plugin serializer is not allowed at this point"))?;
+                    let plugin = plugins.get(plugin_name)?;
+                    let _scope = profiler::plugin_scope(plugin_name, "serialize");
+
+                    plugin
+                        .serialize(&generated, args.clone().unwrap_or_default())
+                        .with_context(|| {
+                            anyhow!("Failed to serialize data using plugin '{plugin_name}'")
+                        })
+                }
             }
         }
 
+        /// Serialize the generated data to bytes.
+        /// In contrast to [`Serializer::serialize_generated`],
+        /// this also supports binary serializers.
+        pub fn serialize_generated_bytes(
+            &self,
+            generated: Arc<GeneratedSchema>,
+            plugins: Option<Arc<PluginList>>,
+        ) -> anyhow::Result<Vec<u8>> {
+            match self {
+                Serializer::Avro {
+                    schema,
+                    record_name,
+                    namespace,
+                } => {
+                    let data = serde_json::to_value(&generated)?;
+                    let (avro_schema, schema_json) = match schema {
+                        Some(path) => AvroSchema::from_file(path)?,
+                        None => {
+                            let inferred = AvroSchema::infer(
+                                &data,
+                                record_name.as_deref().unwrap_or("Record"),
+                                namespace.clone(),
+                            )
+                            .context("Failed to infer the avro schema")?;
+                            let json = inferred.to_json();
+
+                            (inferred, json)
+                        }
+                    };
+
+                    write_container_file(&avro_schema, &schema_json, &data)
+                        .context("Failed to serialize data using the avro serializer")
+                }
+                _ => self
+                    .serialize_generated(generated, plugins)
+                    .map(String::into_bytes),
+            }
+        }
+
+        /// Serialize the generated data into a writer.
+        /// JSON and YAML are written to the writer while serializing,
+        /// all other formats are serialized using [`Serializer::serialize_generated_bytes`]
+        /// before being written.
+        pub fn serialize_generated_to<W: Write>(
+            &self,
+            generated: Arc<GeneratedSchema>,
+            plugins: Option<Arc<PluginList>>,
+            writer: W,
+        ) -> anyhow::Result<()> {
+            let mut writer = BufWriter::new(writer);
+            match self {
+                Serializer::Json { pretty } if pretty.unwrap_or(false) => {
+                    serde_json::to_writer_pretty(&mut writer, &generated)?
+                }
+                Serializer::Json { .. } => serde_json::to_writer(&mut writer, &generated)?,
+                Serializer::Yaml => serde_yaml::to_writer(&mut writer, &generated)?,
+                _ => writer.write_all(&self.serialize_generated_bytes(generated, plugins)?)?,
+            }
+
+            // Don't flush the inner writer, compressing writers would end the current block
+            writer.into_inner().map_err(|err| err.into_error())?;
+
+            Ok(())
+        }
+
         pub fn serialize_generated_with_progress(
             &self,
             generated: Arc<GeneratedSchema>,
@@ -127,17 +281,73 @@ pub mod generate {
             callback: PluginSerializeCallback,
         ) -> anyhow::Result<String> {
             match self {
-                Serializer::Plugin { plugin_name, args } => plugins
-                    .ok_or(anyhow!("A plugin serializer is not allowed at this point"))?
-                    .get(plugin_name)?
-                    .serialize_with_progress(&generated, args.clone().unwrap_or_default(), callback)
-                    .with_context(|| {
-                        anyhow!("Failed to serialize data using plugin '{plugin_name}'")
-                    }),
+                Serializer::Plugin { plugin_name, args } => {
+                    let plugins = plugins
+                        .ok_or(anyhow!("A plugin serializer is not allowed at this point"))?;
+                    let plugin = plugins.get(plugin_name)?;
+                    let _scope = profiler::plugin_scope(plugin_name, "serialize");
+
+                    plugin
+                        .serialize_with_progress(
+                            &generated,
+                            args.clone().unwrap_or_default(),
+                            callback,
+                        )
+                        .with_context(|| {
+                            anyhow!("Failed to serialize data using plugin '{plugin_name}'")
+                        })
+                }
                 _ => self
                     .serialize_generated(generated, plugins)
                     .context("Failed to serialize data"),
             }
         }
+
+        /// Serialize the generated data into a writer,
+        /// reporting the progress of plugin serializers.
+        pub fn serialize_generated_to_with_progress<W: Write>(
+            &self,
+            generated: Arc<GeneratedSchema>,
+            plugins: Option<Arc<PluginList>>,
+            callback: PluginSerializeCallback,
+            mut writer: W,
+        ) -> anyhow::Result<()> {
+            match self {
+                Serializer::Plugin { .. } => writer
+                    .write_all(
+                        self.serialize_generated_with_progress(generated, plugins, callback)?
+                            .as_bytes(),
+                    )
+                    .map_err(Into::into),
+                _ => self.serialize_generated_to(generated, plugins, writer),
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "validate-schema", feature = "generate"))]
+pub mod validate {
+    use crate::schema::serializer::Serializer;
+    use crate::util::avro::AvroSchema;
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+    use serde_json::Value;
+
+    impl Validate for Serializer {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            match self {
+                Serializer::Avro {
+                    schema: Some(schema),
+                    ..
+                } => ValidationResult::ensure_ok(
+                    AvroSchema::from_file(schema),
+                    "Invalid avro schema",
+                    &path.append_single("schema"),
+                    Some(Value::String(schema.clone())),
+                ),
+                _ => Ok(()),
+            }
+        }
     }
 }
@@ -105,12 +105,10 @@ impl GetTransform for FormatArg {
     }
 }
 
-#[cfg(feature = "generate")]
-pub mod generate {
-    use crate::generate::datagen_context::DatagenContextRef;
-    use crate::generate::generated_schema::generate::{IntoGenerated, IntoGeneratedArc};
-    use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
-    use crate::schema::string::{FormatArg, StringGenerator, StringSchema};
+#[cfg(feature = "map-schema")]
+pub mod generate_with_rng {
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::schema::string::StringGenerator;
     use anyhow::{anyhow, Context};
     use chrono::{DateTime, SecondsFormat, Timelike, Utc};
     use fake::faker::address::en::{
@@ -123,6 +121,110 @@ pub mod generate {
     use fake::faker::phone_number::en::PhoneNumber;
     use fake::uuid::UUIDv4;
     use fake::Fake;
+    use rand::Rng;
+
+    impl StringGenerator {
+        /// Generate a value using the given random number generator.
+        /// The `format` generator can't be used with this method,
+        /// as it needs to resolve references.
+        pub fn generate_with_rng<R: Rng + ?Sized>(
+            &self,
+            rng: &mut R,
+        ) -> anyhow::Result<GeneratedSchema> {
+            Ok(match self {
+                StringGenerator::Uuid => GeneratedSchema::String(UUIDv4.fake_with_rng(rng)),
+                StringGenerator::Email => GeneratedSchema::String(FreeEmail().fake_with_rng(rng)),
+                StringGenerator::FirstName => {
+                    GeneratedSchema::String(FirstName().fake_with_rng(rng))
+                }
+                StringGenerator::LastName => GeneratedSchema::String(LastName().fake_with_rng(rng)),
+                StringGenerator::FullName => GeneratedSchema::String(Name().fake_with_rng(rng)),
+                StringGenerator::Username => GeneratedSchema::String(Username().fake_with_rng(rng)),
+                StringGenerator::CompanyName => {
+                    GeneratedSchema::String(CompanyName().fake_with_rng(rng))
+                }
+                StringGenerator::Industry => GeneratedSchema::String(Industry().fake_with_rng(rng)),
+                StringGenerator::City => GeneratedSchema::String(CityName().fake_with_rng(rng)),
+                StringGenerator::Country => {
+                    GeneratedSchema::String(CountryName().fake_with_rng(rng))
+                }
+                StringGenerator::CountryCode => {
+                    GeneratedSchema::String(CountryCode().fake_with_rng(rng))
+                }
+                StringGenerator::Street => GeneratedSchema::String(StreetName().fake_with_rng(rng)),
+                StringGenerator::State => GeneratedSchema::String(StateName().fake_with_rng(rng)),
+                StringGenerator::ZipCode => GeneratedSchema::String(ZipCode().fake_with_rng(rng)),
+                StringGenerator::Latitude => {
+                    GeneratedSchema::Number(Latitude().fake_with_rng::<f64, _>(rng).into())
+                }
+                StringGenerator::Longitude => {
+                    GeneratedSchema::Number(Longitude().fake_with_rng::<f64, _>(rng).into())
+                }
+                StringGenerator::Phone => GeneratedSchema::String(PhoneNumber().fake_with_rng(rng)),
+                StringGenerator::DateTime { format, from, to } => {
+                    let date: DateTime<Utc> = if from.is_some() && to.is_some() {
+                        let min: DateTime<Utc> =
+                            DateTime::parse_from_rfc3339(from.as_ref().unwrap())
+                                .context("Failed to parse 'to' date")?
+                                .into();
+                        let max: DateTime<Utc> = DateTime::parse_from_rfc3339(to.as_ref().unwrap())
+                            .context("Failed to parse 'to' date")?
+                            .into();
+
+                        if min
+                            .with_second(0)
+                            .ok_or(anyhow!("Failed to set seconds of 'from' date"))?
+                            >= max
+                                .with_second(0)
+                                .ok_or(anyhow!("Failed to set seconds of 'to' date"))?
+                        {
+                            return Err(anyhow!(
+                                "'from' date must be at least one minute before the 'to' date"
+                            ));
+                        }
+
+                        DateTimeBetween(min, max).fake_with_rng(rng)
+                    } else if let Some(min) = from {
+                        DateTimeAfter(
+                            DateTime::parse_from_rfc3339(min)
+                                .context("Failed to parse 'from' date")?
+                                .into(),
+                        )
+                        .fake_with_rng(rng)
+                    } else if let Some(max) = to {
+                        DateTimeBefore(
+                            DateTime::parse_from_rfc3339(max)
+                                .context("Failed to parse 'to' date")?
+                                .into(),
+                        )
+                        .fake_with_rng(rng)
+                    } else {
+                        DateTimeBetween(DateTime::UNIX_EPOCH, Utc::now()).fake_with_rng(rng)
+                    };
+
+                    if let Some(format) = format {
+                        GeneratedSchema::String(date.format(format).to_string())
+                    } else {
+                        GeneratedSchema::String(date.to_rfc3339_opts(SecondsFormat::Secs, true))
+                    }
+                }
+                StringGenerator::Format { .. } => {
+                    return Err(anyhow!(
+                        "The format generator can't be used without a schema context"
+                    ))
+                }
+            })
+        }
+    }
+}
+
+#[cfg(feature = "generate")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::generate::{IntoGenerated, IntoGeneratedArc};
+    use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+    use crate::schema::string::{FormatArg, StringGenerator, StringSchema};
+    use anyhow::anyhow;
     use handlebars::Handlebars;
     use std::collections::HashMap;
     use std::sync::Arc;
@@ -133,7 +235,9 @@ pub mod generate {
             schema: DatagenContextRef,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
             match self {
-                StringSchema::Constant { value, .. } => schema.resolve_ref(&value)?.into_random(),
+                StringSchema::Constant { value, .. } => {
+                    schema.resolve_ref(&value)?.into_random(&mut schema.rng())
+                }
                 StringSchema::Generated { generator, .. } => generator.into_random(schema),
             }
         }
@@ -142,27 +246,6 @@ pub mod generate {
     impl IntoGenerated for StringGenerator {
         fn into_generated(self, schema: DatagenContextRef) -> anyhow::Result<GeneratedSchema> {
             Ok(match self {
-                StringGenerator::Uuid => GeneratedSchema::String(UUIDv4.fake()),
-                StringGenerator::Email => GeneratedSchema::String(FreeEmail().fake()),
-                StringGenerator::FirstName => GeneratedSchema::String(FirstName().fake()),
-                StringGenerator::LastName => GeneratedSchema::String(LastName().fake()),
-                StringGenerator::FullName => GeneratedSchema::String(Name().fake()),
-                StringGenerator::Username => GeneratedSchema::String(Username().fake()),
-                StringGenerator::CompanyName => GeneratedSchema::String(CompanyName().fake()),
-                StringGenerator::Industry => GeneratedSchema::String(Industry().fake()),
-                StringGenerator::City => GeneratedSchema::String(CityName().fake()),
-                StringGenerator::Country => GeneratedSchema::String(CountryName().fake()),
-                StringGenerator::CountryCode => GeneratedSchema::String(CountryCode().fake()),
-                StringGenerator::Street => GeneratedSchema::String(StreetName().fake()),
-                StringGenerator::State => GeneratedSchema::String(StateName().fake()),
-                StringGenerator::ZipCode => GeneratedSchema::String(ZipCode().fake()),
-                StringGenerator::Latitude => {
-                    GeneratedSchema::Number(Latitude().fake::<f64>().into())
-                }
-                StringGenerator::Longitude => {
-                    GeneratedSchema::Number(Longitude().fake::<f64>().into())
-                }
-                StringGenerator::Phone => GeneratedSchema::String(PhoneNumber().fake()),
                 StringGenerator::Format {
                     format,
                     args,
@@ -184,9 +267,9 @@ pub mod generate {
                                         FormatArg::Integer(num) => {
                                             GeneratedSchema::String(num.to_string()).into()
                                         }
-                                        FormatArg::String(str) => {
-                                            schema.resolve_ref(&str)?.into_random()?
-                                        }
+                                        FormatArg::String(str) => schema
+                                            .resolve_ref(&str)?
+                                            .into_random(&mut schema.rng())?,
                                         FormatArg::StringSchema(str) => {
                                             let res = str.into_generated_arc(schema.clone())?;
                                             match res.as_ref() {
@@ -227,52 +310,7 @@ pub mod generate {
 
                     GeneratedSchema::String(hbs.render("template", &data)?)
                 }
-                StringGenerator::DateTime { format, from, to } => {
-                    let date: DateTime<Utc> = if from.is_some() && to.is_some() {
-                        let min: DateTime<Utc> = DateTime::parse_from_rfc3339(&from.unwrap())
-                            .context("Failed to parse 'to' date")?
-                            .into();
-                        let max: DateTime<Utc> = DateTime::parse_from_rfc3339(&to.unwrap())
-                            .context("Failed to parse 'to' date")?
-                            .into();
-
-                        if min
-                            .with_second(0)
-                            .ok_or(anyhow!("Failed to set seconds of 'from' date"))?
-                            >= max
-                                .with_second(0)
-                                .ok_or(anyhow!("Failed to set seconds of 'to' date"))?
-                        {
-                            return Err(anyhow!(
-                                "'from' date must be at least one minute before the 'to' date"
-                            ));
-                        }
-
-                        DateTimeBetween(min, max).fake()
-                    } else if let Some(min) = from {
-                        DateTimeAfter(
-                            DateTime::parse_from_rfc3339(&min)
-                                .context("Failed to parse 'from' date")?
-                                .into(),
-                        )
-                        .fake()
-                    } else if let Some(max) = to {
-                        DateTimeBefore(
-                            DateTime::parse_from_rfc3339(&max)
-                                .context("Failed to parse 'to' date")?
-                                .into(),
-                        )
-                        .fake()
-                    } else {
-                        DateTimeBetween(DateTime::UNIX_EPOCH, Utc::now()).fake()
-                    };
-
-                    if let Some(format) = format {
-                        GeneratedSchema::String(date.format(&format).to_string())
-                    } else {
-                        GeneratedSchema::String(date.to_rfc3339_opts(SecondsFormat::Secs, true))
-                    }
-                }
+                generator => generator.generate_with_rng(&mut schema.rng())?,
             })
         }
 
@@ -1,14 +1,34 @@
 use crate::schema::reference::Reference;
+use crate::transform::aggregate::AggregateTransform;
+use crate::transform::array_transform::{
+    ChunkTransform, SampleTransform, SkipTransform, SliceTransform, TakeTransform,
+};
 use crate::transform::choose_from_array::ChooseFromArray;
+use crate::transform::coerce::{CoerceTransform, FormatDateTransform, ParseDateTransform};
+use crate::transform::corrupt::CorruptTransform;
 use crate::transform::filter::FilterTransform;
+use crate::transform::numeric_transform::{
+    ClampTransform, FormatNumberTransform, RoundTransform, ScaleTransform,
+};
+use crate::transform::object_transform::{
+    FlattenKeysTransform, JsonPatchTransform, MergeTransform, UnflattenKeysTransform,
+};
 use crate::transform::plugin_transform::PluginTransform;
+use crate::transform::pseudonymize::PseudonymizeTransform;
 use crate::transform::random_remove::RandomRemoveTransform;
 use crate::transform::regex_filter::RegexFilter;
 use crate::transform::remove_all::RemoveAllTransform;
+use crate::transform::reshape::ReshapeTransform;
 use crate::transform::sort::SortTransform;
 use crate::transform::string_case_transform::ToLowerCase;
 use crate::transform::string_case_transform::ToUpperCase;
+use crate::transform::string_transform::{
+    EncodeTransform, HashTransform, PadTransform, RegexReplaceTransform, SlugifyTransform,
+    SubstringTransform, TrimTransform, TruncateTransform,
+};
 use crate::transform::to_string::ToStringTransform;
+use crate::transform::unique::UniqueTransform;
+use crate::transform::when::WhenTransform;
 #[cfg(feature = "schema")]
 use schemars::gen::SchemaGenerator;
 #[cfg(feature = "schema")]
@@ -57,6 +77,106 @@ pub enum Transform {
     RandomRemove(RandomRemoveTransform),
     RemoveAll(RemoveAllTransform),
     ChooseFromArray(ChooseFromArray),
+    Reshape(ReshapeTransform),
+    Round(RoundTransform),
+    Floor(RoundTransform),
+    Ceil(RoundTransform),
+    Clamp(ClampTransform),
+    Scale(ScaleTransform),
+    /// Get the absolute value of a number.
+    /// This can only be used on numbers.
+    Abs,
+    FormatNumber(FormatNumberTransform),
+    Trim(TrimTransform),
+    Substring(SubstringTransform),
+    Truncate(TruncateTransform),
+    Pad(PadTransform),
+    RegexReplace(RegexReplaceTransform),
+    Slugify(SlugifyTransform),
+    Encode(EncodeTransform),
+    Hash(HashTransform),
+    #[cfg_attr(feature = "serialize", serde(alias = "dedupe"))]
+    Unique(UniqueTransform),
+    Aggregate(AggregateTransform),
+    /// Shuffle the elements of an array.
+    /// This can only be used on arrays.
+    Shuffle,
+    Sample(SampleTransform),
+    Slice(SliceTransform),
+    Take(TakeTransform),
+    Skip(SkipTransform),
+    /// Reverse the order of the elements of an array.
+    /// This can only be used on arrays.
+    Reverse,
+    Chunk(ChunkTransform),
+    When(WhenTransform),
+    ToInteger(CoerceTransform),
+    ToNumber(CoerceTransform),
+    ToBool(CoerceTransform),
+    ParseDate(ParseDateTransform),
+    FormatDate(FormatDateTransform),
+    Corrupt(CorruptTransform),
+    Pseudonymize(PseudonymizeTransform),
+    Merge(MergeTransform),
+    JsonPatch(JsonPatchTransform),
+    FlattenKeys(FlattenKeysTransform),
+    UnflattenKeys(UnflattenKeysTransform),
+}
+
+impl Transform {
+    /// Get the name of the transform, as used in the schema.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Transform::Filter(_) => "filter",
+            Transform::RegexFilter(_) => "regexFilter",
+            Transform::FilterNonNull => "filterNonNull",
+            Transform::ToString(_) => "toString",
+            Transform::ToUpperCase(_) => "toUpperCase",
+            Transform::ToLowerCase(_) => "toLowerCase",
+            Transform::Sort(_) => "sort",
+            Transform::Plugin(_) => "plugin",
+            Transform::RandomRemove(_) => "randomRemove",
+            Transform::RemoveAll(_) => "removeAll",
+            Transform::ChooseFromArray(_) => "chooseFromArray",
+            Transform::Reshape(_) => "reshape",
+            Transform::Round(_) => "round",
+            Transform::Floor(_) => "floor",
+            Transform::Ceil(_) => "ceil",
+            Transform::Clamp(_) => "clamp",
+            Transform::Scale(_) => "scale",
+            Transform::Abs => "abs",
+            Transform::FormatNumber(_) => "formatNumber",
+            Transform::Trim(_) => "trim",
+            Transform::Substring(_) => "substring",
+            Transform::Truncate(_) => "truncate",
+            Transform::Pad(_) => "pad",
+            Transform::RegexReplace(_) => "regexReplace",
+            Transform::Slugify(_) => "slugify",
+            Transform::Encode(_) => "encode",
+            Transform::Hash(_) => "hash",
+            Transform::Unique(_) => "unique",
+            Transform::Aggregate(_) => "aggregate",
+            Transform::Shuffle => "shuffle",
+            Transform::Sample(_) => "sample",
+            Transform::Slice(_) => "slice",
+            Transform::Take(_) => "take",
+            Transform::Skip(_) => "skip",
+            Transform::Reverse => "reverse",
+            Transform::Chunk(_) => "chunk",
+            Transform::When(_) => "when",
+            Transform::ToInteger(_) => "toInteger",
+            Transform::ToNumber(_) => "toNumber",
+            Transform::ToBool(_) => "toBool",
+            Transform::ParseDate(_) => "parseDate",
+            Transform::FormatDate(_) => "formatDate",
+            Transform::Corrupt(_) => "corrupt",
+            Transform::Pseudonymize(_) => "pseudonymize",
+            Transform::Merge(_) => "merge",
+            Transform::JsonPatch(_) => "jsonPatch",
+            Transform::FlattenKeys(_) => "flattenKeys",
+            Transform::UnflattenKeys(_) => "unflattenKeys",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -73,7 +193,11 @@ pub mod generate {
     use crate::generate::datagen_context::DatagenContextRef;
     use crate::generate::generated_schema::generate::IntoGeneratedArc;
     use crate::generate::generated_schema::GeneratedSchema;
+    use crate::generate::profiler::{self, ProfileCategory};
     use crate::schema::transform::{MaybeValidTransform, ReferenceOrString, Transform};
+    use crate::transform::array_transform::generate::{reverse, shuffle};
+    use crate::transform::coerce::generate as coerce;
+    use crate::transform::numeric_transform::generate::{abs, RoundMode};
     use crate::util::traits::generate::{ResolveRef, TransformTrait};
     use anyhow::anyhow;
     use indexmap::IndexMap;
@@ -96,6 +220,7 @@ pub mod generate {
             schema: DatagenContextRef,
             value: Arc<GeneratedSchema>,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let _scope = profiler::scope(ProfileCategory::Transform, || self.name().into());
             match self {
                 Transform::Filter(filter) => filter.transform(schema, value),
                 Transform::FilterNonNull => match value.as_ref() {
@@ -130,6 +255,47 @@ pub mod generate {
                 Transform::ChooseFromArray(choose_from_array) => {
                     choose_from_array.transform(schema, value)
                 }
+                Transform::Reshape(reshape) => reshape.transform(schema, value),
+                Transform::Round(round) => {
+                    round.transform_with_mode(RoundMode::Round, schema, value)
+                }
+                Transform::Floor(floor) => {
+                    floor.transform_with_mode(RoundMode::Floor, schema, value)
+                }
+                Transform::Ceil(ceil) => ceil.transform_with_mode(RoundMode::Ceil, schema, value),
+                Transform::Clamp(clamp) => clamp.transform(schema, value),
+                Transform::Scale(scale) => scale.transform(schema, value),
+                Transform::Abs => abs(schema, value),
+                Transform::FormatNumber(format_number) => format_number.transform(schema, value),
+                Transform::Trim(trim) => trim.transform(schema, value),
+                Transform::Substring(substring) => substring.transform(schema, value),
+                Transform::Truncate(truncate) => truncate.transform(schema, value),
+                Transform::Pad(pad) => pad.transform(schema, value),
+                Transform::RegexReplace(regex_replace) => regex_replace.transform(schema, value),
+                Transform::Slugify(slugify) => slugify.transform(schema, value),
+                Transform::Encode(encode) => encode.transform(schema, value),
+                Transform::Hash(hash) => hash.transform(schema, value),
+                Transform::Unique(unique) => unique.transform_with_refill(schema, value, None),
+                Transform::Aggregate(aggregate) => aggregate.transform(schema, value),
+                Transform::Shuffle => shuffle(schema, value),
+                Transform::Sample(sample) => sample.transform(schema, value),
+                Transform::Slice(slice) => slice.transform(schema, value),
+                Transform::Take(take) => take.transform(schema, value),
+                Transform::Skip(skip) => skip.transform(schema, value),
+                Transform::Reverse => reverse(schema, value),
+                Transform::Chunk(chunk) => chunk.transform(schema, value),
+                Transform::When(when) => when.transform(schema, value),
+                Transform::ToInteger(to_integer) => coerce::to_integer(to_integer, schema, value),
+                Transform::ToNumber(to_number) => coerce::to_number(to_number, schema, value),
+                Transform::ToBool(to_bool) => coerce::to_bool(to_bool, schema, value),
+                Transform::ParseDate(parse_date) => parse_date.transform(schema, value),
+                Transform::FormatDate(format_date) => format_date.transform(schema, value),
+                Transform::Corrupt(corrupt) => corrupt.transform(schema, value),
+                Transform::Pseudonymize(pseudonymize) => pseudonymize.transform(schema, value),
+                Transform::Merge(merge) => merge.transform(schema, value),
+                Transform::JsonPatch(json_patch) => json_patch.transform(schema, value),
+                Transform::FlattenKeys(flatten_keys) => flatten_keys.transform(schema, value),
+                Transform::UnflattenKeys(unflatten_keys) => unflatten_keys.transform(schema, value),
             }
         }
     }
@@ -154,9 +320,14 @@ pub mod generate {
 
 #[cfg(feature = "validate-schema")]
 pub mod validate {
+    use crate::schema::string::StringGenerator;
     use crate::schema::transform::{MaybeValidTransform, ReferenceOrString, Transform};
+    use crate::transform::aggregate::{AggregateOutput, AggregateTransform};
+    use crate::transform::numeric_transform::{RoundTransform, ScaleTransform};
+    use crate::transform::object_transform::JsonPatchOperation;
+    use crate::transform::pseudonymize::PseudonymizeTransform;
     use crate::validation::path::ValidationPath;
-    use crate::validation::result::{ValidationErrors, ValidationResult};
+    use crate::validation::result::{IterValidate, ValidationErrors, ValidationResult};
     use crate::validation::validate::Validate;
 
     impl Validate for Transform {
@@ -173,10 +344,204 @@ pub mod validate {
                 Transform::RandomRemove(random_remove) => random_remove.validate(path),
                 Transform::RemoveAll(remove_all) => remove_all.validate(path),
                 Transform::ChooseFromArray(_) => Ok(()),
+                Transform::Reshape(reshape) => reshape.validate(path),
+                Transform::Round(round) | Transform::Floor(round) | Transform::Ceil(round) => {
+                    round.validate(path)
+                }
+                Transform::Clamp(clamp) => clamp.validate(path),
+                Transform::Scale(scale) => scale.validate(path),
+                Transform::Abs => Ok(()),
+                Transform::FormatNumber(format_number) => format_number.validate(path),
+                Transform::Substring(substring) => substring.validate(path),
+                Transform::Truncate(truncate) => truncate.validate(path),
+                Transform::RegexReplace(regex_replace) => regex_replace.validate(path),
+                Transform::Unique(unique) => unique.validate(path),
+                Transform::Aggregate(aggregate) => aggregate.validate(path),
+                Transform::Sample(sample) => sample.validate(path),
+                Transform::Slice(slice) => slice.validate(path),
+                Transform::Chunk(chunk) => chunk.validate(path),
+                Transform::When(when) => when.validate(path),
+                Transform::ParseDate(parse_date) => parse_date.validate(path),
+                Transform::FormatDate(format_date) => format_date.validate(path),
+                Transform::Corrupt(corrupt) => corrupt.validate(path),
+                Transform::Pseudonymize(pseudonymize) => pseudonymize.validate(path),
+                Transform::Merge(merge) => merge.validate(path),
+                Transform::JsonPatch(json_patch) => json_patch.validate(path),
+                Transform::FlattenKeys(flatten_keys) => flatten_keys.validate(path),
+                Transform::UnflattenKeys(unflatten_keys) => unflatten_keys.validate(path),
+                Transform::ToInteger(_) | Transform::ToNumber(_) | Transform::ToBool(_) => Ok(()),
+                Transform::Shuffle
+                | Transform::Take(_)
+                | Transform::Skip(_)
+                | Transform::Reverse => Ok(()),
+                Transform::Trim(_)
+                | Transform::Pad(_)
+                | Transform::Slugify(_)
+                | Transform::Encode(_)
+                | Transform::Hash(_) => Ok(()),
             }
         }
     }
 
+    const NUMERIC_TYPES: &[&str] = &["Number", "Integer"];
+
+    impl Transform {
+        /// Get the types of values this transform can be applied to,
+        /// using the names returned by [`GeneratedSchema::name`](crate::generate::generated_schema::GeneratedSchema).
+        /// Returns `None` if the transform can be applied to any value.
+        pub fn input_types(&self) -> Option<&'static [&'static str]> {
+            match self {
+                Transform::Round(_)
+                | Transform::Floor(_)
+                | Transform::Ceil(_)
+                | Transform::Clamp(_)
+                | Transform::Scale(_)
+                | Transform::Abs
+                | Transform::FormatNumber(_) => Some(NUMERIC_TYPES),
+                Transform::Unique(_)
+                | Transform::Aggregate(_)
+                | Transform::Shuffle
+                | Transform::Sample(_)
+                | Transform::Slice(_)
+                | Transform::Take(_)
+                | Transform::Skip(_)
+                | Transform::Reverse
+                | Transform::Chunk(_) => Some(&["Array"]),
+                Transform::ToInteger(_) | Transform::ToNumber(_) | Transform::ToBool(_) => {
+                    Some(&["String", "Number", "Integer", "Bool"])
+                }
+                Transform::ParseDate(_) => Some(&["String"]),
+                Transform::Pseudonymize(_) => Some(&["String", "Number", "Integer"]),
+                Transform::FormatDate(_) => Some(&["String", "Number", "Integer"]),
+                Transform::Merge(_)
+                | Transform::JsonPatch(_)
+                | Transform::FlattenKeys(_)
+                | Transform::UnflattenKeys(_) => Some(&["Object", "Array", "Value"]),
+                _ => None,
+            }
+        }
+
+        /// Get the type of the value this transform produces
+        /// if it is applied to a value of type `input`.
+        /// Returns `None` if the type can't be determined.
+        pub fn output_type(&self, input: &'static str) -> Option<&'static str> {
+            match self {
+                Transform::ToString(_) | Transform::FormatNumber(_) | Transform::FormatDate(_) => {
+                    Some("String")
+                }
+                Transform::ToInteger(_) | Transform::ParseDate(_) => Some("Integer"),
+                Transform::ToNumber(_) => Some("Number"),
+                Transform::ToBool(_) => Some("Bool"),
+                // String transforms convert primitive values to strings
+                Transform::ToUpperCase(_)
+                | Transform::ToLowerCase(_)
+                | Transform::Trim(_)
+                | Transform::Substring(_)
+                | Transform::Truncate(_)
+                | Transform::Pad(_)
+                | Transform::RegexReplace(_)
+                | Transform::Slugify(_)
+                | Transform::Encode(_)
+                | Transform::Hash(_)
+                    if ["String", "Number", "Integer", "Bool"].contains(&input) =>
+                {
+                    Some("String")
+                }
+                Transform::Plugin(_) | Transform::ChooseFromArray(_) => None,
+                Transform::JsonPatch(json_patch)
+                    if json_patch
+                        .operations
+                        .iter()
+                        .any(JsonPatchOperation::targets_root) =>
+                {
+                    None
+                }
+                Transform::Corrupt(corrupt) if corrupt.changes_type() => None,
+                Transform::Pseudonymize(PseudonymizeTransform {
+                    generator: Some(StringGenerator::Latitude | StringGenerator::Longitude),
+                    ..
+                }) => Some("Number"),
+                Transform::Pseudonymize(PseudonymizeTransform {
+                    generator: Some(_), ..
+                }) => Some("String"),
+                // The value keeps its type only if the transforms don't change it
+                Transform::When(when) => {
+                    let output = when
+                        .transform
+                        .iter()
+                        .try_fold(input, |current, transform| match transform {
+                            MaybeValidTransform::Valid(transform) => transform.output_type(current),
+                            MaybeValidTransform::Invalid(_) => None,
+                        })?;
+
+                    (output == input).then_some(input)
+                }
+                Transform::Aggregate(AggregateTransform {
+                    output: Some(AggregateOutput::Object),
+                    ..
+                }) => Some("Object"),
+                Transform::Aggregate(_) => Some("Array"),
+                Transform::Round(RoundTransform {
+                    step: Some(step), ..
+                })
+                | Transform::Floor(RoundTransform {
+                    step: Some(step), ..
+                })
+                | Transform::Ceil(RoundTransform {
+                    step: Some(step), ..
+                }) if step.fract() != 0.0 => Some("Number"),
+                Transform::Scale(ScaleTransform { factor, offset })
+                    if factor.unwrap_or(1.0).fract() != 0.0
+                        || offset.unwrap_or(0.0).fract() != 0.0 =>
+                {
+                    Some("Number")
+                }
+                _ => Some(input),
+            }
+        }
+    }
+
+    /// Check that all transforms can be applied to the type of the value
+    /// they receive, starting with a value of type `input`.
+    /// Stops checking once the type of the value can't be determined.
+    pub fn validate_transform_types(
+        transforms: &[MaybeValidTransform],
+        input: Option<&'static str>,
+        path: &ValidationPath,
+    ) -> ValidationResult {
+        let mut current = input;
+        let mut res = Ok(());
+        for (i, transform) in transforms.iter().enumerate() {
+            let (Some(value_type), MaybeValidTransform::Valid(transform)) = (current, transform)
+            else {
+                break;
+            };
+
+            if let Transform::When(when) = transform {
+                res = res.concat(validate_transform_types(
+                    &when.transform,
+                    Some(value_type),
+                    &path.append("transform", i),
+                ));
+            }
+
+            if let Some(types) = transform.input_types() {
+                res = res.concat(ValidationResult::ensure(
+                    types.contains(&value_type),
+                    format!(
+                        "The transform can only be applied to {} values, but the value is of type {value_type}",
+                        types.join(" or ")
+                    ),
+                    &path.append("transform", i),
+                ));
+            }
+
+            current = transform.output_type(value_type);
+        }
+
+        res
+    }
+
     impl Validate for MaybeValidTransform {
         fn validate(&self, path: &ValidationPath) -> ValidationResult {
             match self {
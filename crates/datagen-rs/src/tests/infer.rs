@@ -0,0 +1,226 @@
+use crate::infer::{infer_schema, InferOptions};
+use crate::util::helpers::generate_random_data;
+use serde_json::{json, Value};
+
+fn infer(sample: Value) -> Value {
+    serde_json::to_value(infer_schema(&sample, &InferOptions::default())).unwrap()
+}
+
+#[test]
+fn test_infer_primitives() {
+    let schema = infer(json!([
+        {"id": 1, "price": 2.5, "active": true},
+        {"id": 7, "price": 10.25, "active": true},
+        {"id": 3, "price": 4, "active": false},
+        {"id": 5, "price": 1.5, "active": true},
+    ]));
+
+    assert_eq!(schema["type"], "array");
+    assert_eq!(schema["length"], json!({"value": 4}));
+
+    let properties = &schema["items"]["properties"];
+    assert_eq!(properties["id"]["type"], "integer");
+    assert_eq!(properties["id"]["min"], 1);
+    assert_eq!(properties["id"]["max"], 7);
+    assert_eq!(properties["price"]["type"], "number");
+    assert_eq!(properties["price"]["min"], 1.5);
+    assert_eq!(properties["price"]["max"], 10.25);
+    assert_eq!(properties["price"]["precision"], 2);
+    assert_eq!(properties["active"]["type"], "bool");
+    assert_eq!(properties["active"]["probability"], 0.75);
+}
+
+#[test]
+fn test_infer_array_length() {
+    let schema = infer(json!([{"tags": [1, 2]}, {"tags": []}, {"tags": [3, 4, 5, 6]}]));
+
+    let tags = &schema["items"]["properties"]["tags"];
+    assert_eq!(tags["type"], "array");
+    assert_eq!(tags["length"], json!({"min": 0, "max": 4}));
+    assert_eq!(tags["items"]["type"], "integer");
+    assert_eq!(tags["items"]["min"], 1);
+    assert_eq!(tags["items"]["max"], 6);
+}
+
+#[test]
+fn test_infer_enum() {
+    let schema = infer(json!([
+        "active", "inactive", "active", "active", "inactive"
+    ]));
+
+    let items = &schema["items"];
+    assert_eq!(items["type"], "anyOf");
+    assert_eq!(
+        items["values"],
+        json!([
+            {"type": "string", "value": "active", "transform": null},
+            {"type": "string", "value": "inactive", "transform": null},
+        ])
+    );
+}
+
+#[test]
+fn test_infer_too_many_enum_values() {
+    let sample = json!(["a", "b", "c", "a", "b", "c"]);
+
+    let schema = infer_schema(
+        &sample,
+        &InferOptions {
+            max_enum_values: 2,
+            include_samples: true,
+        },
+    );
+    let schema = serde_json::to_value(schema).unwrap();
+    assert_eq!(schema["items"]["type"], "anyOf");
+    assert_eq!(schema["items"]["values"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_infer_without_samples() {
+    let sample = json!([
+        {"id": "AB-1234", "note": "Called on Monday about the invoice of Jane Doe"},
+        {"id": "CD-5678", "note": "Requested a refund"},
+    ]);
+
+    let schema = infer(sample.clone());
+    let properties = &schema["items"]["properties"];
+    assert_eq!(properties["id"]["type"], "string");
+    assert_eq!(properties["id"]["generator"]["type"], "uuid");
+    assert_eq!(properties["id"]["transform"][0]["type"], "truncate");
+    assert_eq!(properties["id"]["transform"][0]["length"], 7);
+    assert_eq!(properties["note"]["generator"]["type"], "uuid");
+    assert_eq!(properties["note"]["transform"], Value::Null);
+
+    // No sample value may be copied into the schema
+    let serialized = schema.to_string();
+    for record in sample.as_array().unwrap() {
+        for value in record.as_object().unwrap().values() {
+            assert!(!serialized.contains(value.as_str().unwrap()));
+        }
+    }
+
+    let generated: Value = serde_json::from_str(
+        &generate_random_data(infer_schema(&sample, &InferOptions::default()), None).unwrap(),
+    )
+    .unwrap();
+    for record in generated.as_array().unwrap() {
+        assert_eq!(record["id"].as_str().unwrap().len(), 7);
+    }
+}
+
+#[test]
+fn test_infer_nullable() {
+    let schema = infer(json!([
+        {"name": "a", "status": "open"},
+        {"name": null, "status": "open"},
+        {"name": "b"},
+    ]));
+
+    let properties = &schema["items"]["properties"];
+    assert_eq!(properties["name"]["type"], "anyOf");
+    assert_eq!(properties["name"]["values"][0]["generator"]["type"], "uuid");
+    assert_eq!(properties["name"]["values"][1], Value::Null);
+    // Missing values are generated as null
+    assert_eq!(properties["status"]["type"], "anyOf");
+    assert_eq!(
+        properties["status"]["values"],
+        json!([{"type": "string", "value": "open", "transform": null}, null])
+    );
+}
+
+#[test]
+fn test_infer_null() {
+    let schema = infer(json!({"value": null}));
+
+    assert_eq!(schema["properties"]["value"], Value::Null);
+}
+
+#[test]
+fn test_infer_mixed_types() {
+    let schema = infer(json!([1, "one", true]));
+
+    let values = schema["items"]["values"].as_array().unwrap();
+    assert_eq!(schema["items"]["type"], "anyOf");
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0]["type"], "bool");
+    assert_eq!(values[1]["type"], "integer");
+    assert_eq!(values[2]["type"], "string");
+}
+
+#[test]
+fn test_infer_string_kinds() {
+    let schema = infer(json!([
+        {
+            "id": "0b6b7e8a-3f0c-4f1e-9d2a-1c3b5d7e9f01",
+            "email": "jane@example.com",
+            "createdAt": "2023-01-01T10:00:00Z",
+            "birthday": "1990-05-01",
+            "firstName": "Jane",
+            "lastName": "Smith",
+            "name": "Jane Smith",
+        },
+        {
+            "id": "6f1c2d3e-4a5b-4c6d-8e7f-901a2b3c4d5e",
+            "email": "john@example.org",
+            "createdAt": "2023-06-30T12:30:00+02:00",
+            "birthday": "1985-12-24",
+            "firstName": "John",
+            "lastName": "Johnson",
+            "name": "John Johnson",
+        },
+    ]));
+
+    let properties = &schema["items"]["properties"];
+    assert_eq!(properties["id"]["generator"]["type"], "uuid");
+    assert_eq!(properties["email"]["generator"]["type"], "email");
+    assert_eq!(
+        properties["createdAt"]["generator"],
+        json!({
+            "type": "dateTime",
+            "format": null,
+            "from": "2023-01-01T10:00:00Z",
+            "to": "2023-06-30T10:30:00Z",
+        })
+    );
+    assert_eq!(
+        properties["birthday"]["generator"],
+        json!({
+            "type": "dateTime",
+            "format": "%Y-%m-%d",
+            "from": "1985-12-24T00:00:00Z",
+            "to": "1990-05-01T23:59:59Z",
+        })
+    );
+    assert_eq!(properties["firstName"]["generator"]["type"], "firstName");
+    assert_eq!(properties["lastName"]["generator"]["type"], "lastName");
+    assert_eq!(properties["name"]["generator"]["type"], "fullName");
+}
+
+#[test]
+fn test_infer_generate() {
+    let sample = json!([
+        {"id": 1, "email": "jane@example.com", "tags": ["a", "b"], "score": null},
+        {"id": 2, "email": "john@example.com", "tags": ["c"], "score": 1.5},
+    ]);
+    let schema = infer_schema(&sample, &InferOptions::default());
+
+    let generated: Value =
+        serde_json::from_str(&generate_random_data(schema, None).unwrap()).unwrap();
+    let generated = generated.as_array().unwrap();
+    assert_eq!(generated.len(), 2);
+    for record in generated {
+        assert!(record["id"]
+            .as_i64()
+            .is_some_and(|id| (1..=2).contains(&id)));
+        assert!(record["email"].as_str().unwrap().contains('@'));
+        assert!((1..=2).contains(&record["tags"].as_array().unwrap().len()));
+    }
+}
+
+#[test]
+fn test_infer_constant_number() {
+    let schema = infer(json!({"value": 1.5}));
+
+    assert_eq!(schema["properties"]["value"]["type"], "number");
+    assert_eq!(schema["properties"]["value"]["value"], 1.5);
+}
@@ -0,0 +1,476 @@
+use crate::lint::{lint_schema, Finding, LintKind, Severity};
+use crate::schema;
+
+fn kinds(findings: &[Finding]) -> Vec<LintKind> {
+    findings.iter().map(|finding| finding.kind).collect()
+}
+
+#[test]
+fn test_lint_valid_schema() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "string", "generator": {"type": "uuid"}},
+            "name": {"type": "string", "generator": {"type": "firstName"}},
+            "label": {
+                "type": "string",
+                "generator": {
+                    "type": "format",
+                    "format": "{{name}} ({{id}})",
+                    "args": {"name": "ref:./name", "id": "ref:./id"}
+                }
+            },
+            "address": {
+                "type": "object",
+                "properties": {
+                    "city": {"type": "string", "generator": {"type": "city"}},
+                    "owner": "ref:../name"
+                }
+            },
+            "city": "ref:address.city",
+            "copy": "ref:./address.city",
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert!(findings.is_empty(), "{findings:?}");
+}
+
+#[test]
+fn test_lint_unknown_reference() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "generator": {"type": "firstName"}},
+            "copy": "ref:./nmae"
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::UnknownReference]);
+    assert_eq!(findings[0].severity, Severity::Error);
+    assert_eq!(findings[0].path, "properties.copy");
+    assert_eq!(
+        findings[0].fix.as_deref(),
+        Some("Did you mean 'ref:./name'?")
+    );
+}
+
+#[test]
+fn test_lint_reference_in_other_scope() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "generator": {"type": "firstName"}},
+            "address": {
+                "type": "object",
+                "properties": {
+                    "owner": "ref:./name"
+                }
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::UnknownReference]);
+    assert_eq!(findings[0].path, "properties.address.properties.owner");
+    assert_eq!(
+        findings[0].fix.as_deref(),
+        Some("Did you mean 'ref:../name'?")
+    );
+}
+
+#[test]
+fn test_lint_reference_above_root() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "copy": "ref:../../name"
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::UnknownReference]);
+    assert!(findings[0].message.contains("above the root"));
+}
+
+#[test]
+fn test_lint_forward_reference() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "label": {
+                "type": "reference",
+                "reference": "./name"
+            },
+            "name": {"type": "string", "generator": {"type": "firstName"}}
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::ForwardReference]);
+    assert_eq!(findings[0].path, "properties.label");
+    assert_eq!(
+        findings[0].fix.as_deref(),
+        Some("Move 'label' after 'name'")
+    );
+}
+
+#[test]
+fn test_lint_forward_reference_in_array() {
+    let schema = schema!({
+        "type": "array",
+        "length": 5,
+        "items": {
+            "type": "object",
+            "properties": {
+                "copy": "ref:./address.city",
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": {"type": "string", "generator": {"type": "city"}}
+                    }
+                }
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::ForwardReference]);
+    assert_eq!(findings[0].path, "items.properties.copy");
+    assert_eq!(
+        findings[0].fix.as_deref(),
+        Some("Move 'copy' after 'address'")
+    );
+}
+
+#[test]
+fn test_lint_reference_to_containing_value() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "address": {
+                "type": "object",
+                "properties": {
+                    "self": "ref:../address"
+                }
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::ForwardReference]);
+    assert_eq!(findings[0].fix, None);
+}
+
+#[test]
+fn test_lint_reference_to_previous_items() {
+    // References to the items of the same array see the previously generated items
+    let schema = schema!({
+        "type": "array",
+        "length": {"min": 10, "max": 20},
+        "items": {
+            "type": "object",
+            "properties": {
+                "id": {"type": "string", "generator": {"type": "uuid"}},
+                "related": {
+                    "type": "array",
+                    "length": {"min": 0, "max": 3},
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "person": {
+                                "type": "reference",
+                                "reference": "id",
+                                "except": ["ref:../../related.person", "ref:../../id"]
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert!(findings.is_empty(), "{findings:?}");
+}
+
+#[test]
+fn test_lint_reference_into_plugin() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "address": {"type": "plugin", "pluginName": "addresses"},
+            "city": "ref:./address.city"
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert!(findings.is_empty(), "{findings:?}");
+}
+
+#[test]
+fn test_lint_unused_plugin() {
+    let schema = schema!({
+        "options": {
+            "plugins": {
+                "used": {},
+                "transformer": {},
+                "serializer": {},
+                "unused": {}
+            },
+            "serializer": {"type": "plugin", "pluginName": "serializer"}
+        },
+        "type": "object",
+        "properties": {
+            "value": {
+                "type": "plugin",
+                "pluginName": "used",
+                "transform": [{"type": "plugin", "name": "transformer"}]
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::UnusedPlugin]);
+    assert_eq!(findings[0].severity, Severity::Warning);
+    assert_eq!(findings[0].path, "options.plugins.unused");
+}
+
+#[test]
+fn test_lint_transform_type_of_reference() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "generator": {"type": "firstName"}},
+            "rounded": {
+                "type": "reference",
+                "reference": "./name",
+                "transform": [{"type": "round"}]
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::TransformType]);
+    assert_eq!(findings[0].path, "properties.rounded.transform.0");
+}
+
+#[test]
+fn test_lint_transform_type() {
+    let schema = schema!({
+        "type": "integer",
+        "min": 0,
+        "max": 10,
+        "transform": [{"type": "toString", "subType": "default"}, {"type": "abs"}]
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::TransformType]);
+    assert_eq!(findings[0].path, "transform.1");
+}
+
+#[test]
+fn test_lint_transform_references() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "generator": {"type": "firstName"}},
+            "filtered": {
+                "type": "integer",
+                "min": 0,
+                "max": 10,
+                "transform": [
+                    {"type": "filter", "field": "ref:./nmae", "operator": "equals", "other": "x"},
+                    {
+                        "type": "when",
+                        "condition": {
+                            "operator": "and",
+                            "filters": [{"operator": "equals", "other": "ref:./age"}]
+                        },
+                        "transform": [{"type": "abs"}]
+                    }
+                ]
+            },
+            "reshaped": {
+                "type": "object",
+                "properties": {},
+                "transform": [
+                    {"type": "reshape", "set": {"owner": "ref:../nmae"}},
+                    {"type": "merge", "value": "ref:./name"},
+                    {
+                        "type": "jsonPatch",
+                        "operations": [{"op": "add", "path": "/id", "value": "ref:./id"}]
+                    }
+                ]
+            }
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(
+        findings
+            .iter()
+            .map(|finding| (finding.kind, finding.path.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                LintKind::UnknownReference,
+                "properties.filtered.transform.0.field"
+            ),
+            (
+                LintKind::UnknownReference,
+                "properties.filtered.transform.1.condition.filters.0.other"
+            ),
+            (
+                LintKind::UnknownReference,
+                "properties.reshaped.transform.0.set.owner"
+            ),
+            (
+                LintKind::UnknownReference,
+                "properties.reshaped.transform.2.operations.0.value"
+            ),
+        ]
+    );
+    assert_eq!(
+        findings[0].fix.as_deref(),
+        Some("Did you mean 'ref:./name'?")
+    );
+}
+
+#[test]
+fn test_lint_merge_schema_references() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "address": {
+                "type": "object",
+                "properties": {},
+                "transform": [{
+                    "type": "merge",
+                    "value": {
+                        "type": "object",
+                        "properties": {"city": "ref:../../city"}
+                    }
+                }]
+            },
+            "city": {"type": "string", "generator": {"type": "city"}}
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::ForwardReference]);
+    assert_eq!(
+        findings[0].path,
+        "properties.address.transform.0.value.properties.city"
+    );
+}
+
+#[test]
+fn test_lint_impossible_ranges() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "integer": {"type": "integer", "min": 10, "max": 1},
+            "number": {"type": "number", "min": 5},
+            "empty": {"type": "number", "min": 2, "max": 2},
+            "array": {"type": "array", "length": {"min": 3, "max": 1}, "items": 1},
+            "anyOf": {"type": "anyOf", "values": [1, 2], "num": 3},
+            "bool": {"type": "bool", "probability": 2},
+            "date": {
+                "type": "string",
+                "generator": {
+                    "type": "dateTime",
+                    "from": "2024-01-01T00:00:00Z",
+                    "to": "2023-01-01T00:00:00Z"
+                }
+            },
+            "valid": {"type": "number", "min": -1, "max": 1}
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    let fixes = findings
+        .iter()
+        .map(|finding| {
+            assert_eq!(finding.kind, LintKind::ImpossibleRange);
+            (finding.path.as_str(), finding.fix.as_deref().unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        fixes,
+        vec![
+            ("properties.integer", "Swap min and max"),
+            (
+                "properties.number",
+                "Set max to a value greater than 5, it defaults to 1"
+            ),
+            ("properties.empty", "Use a constant number with the value 2"),
+            ("properties.array.length", "Swap min and max"),
+            ("properties.anyOf.num", "Set num to 2"),
+            (
+                "properties.bool.probability",
+                "Set the probability to a value between 0 and 1"
+            ),
+            ("properties.date.from", "Swap from and to"),
+        ]
+    );
+}
+
+#[test]
+fn test_lint_errors_first() {
+    let schema = schema!({
+        "options": {
+            "plugins": {"unused": {}}
+        },
+        "type": "object",
+        "properties": {
+            "copy": "ref:./missing"
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(
+        kinds(&findings),
+        vec![LintKind::UnknownReference, LintKind::UnusedPlugin]
+    );
+}
+
+#[test]
+fn test_lint_include_cycle() {
+    let schema = schema!({
+        "type": "include",
+        "path": "src/tests/schema/include/cycle_a.json"
+    });
+
+    let findings = lint_schema(&schema);
+    assert_eq!(kinds(&findings), vec![LintKind::IncludeCycle]);
+    assert_eq!(findings[0].severity, Severity::Error);
+    assert_eq!(findings[0].path, "properties.b.values.0");
+    assert!(
+        findings[0]
+            .message
+            .starts_with("The file 'src/tests/schema/include/cycle_a.json' includes itself"),
+        "{}",
+        findings[0].message
+    );
+    assert_eq!(
+        findings[0].fix.as_deref(),
+        Some("Remove the include of 'src/tests/schema/include/cycle_a.json'")
+    );
+}
+
+#[test]
+fn test_lint_repeated_include() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "first": {"type": "include", "path": "src/tests/schema/include/simple.json"},
+            "second": {"type": "include", "path": "src/tests/schema/include/simple.json"}
+        }
+    });
+
+    let findings = lint_schema(&schema);
+    assert!(findings.is_empty(), "{findings:?}");
+}
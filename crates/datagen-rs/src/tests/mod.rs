@@ -1,5 +1,11 @@
+#[cfg(feature = "infer")]
+mod infer;
+#[cfg(feature = "lint")]
+mod lint;
 mod plugins;
+mod profiler;
 mod schema;
+mod serializer;
 mod transform;
 mod util;
 mod validation;
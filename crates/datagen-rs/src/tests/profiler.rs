@@ -0,0 +1,89 @@
+use crate::generate::profiler::{self, ProfileCategory, ProfileEntry};
+use crate::schema;
+use crate::util::helpers::generate_random_data;
+use std::sync::Mutex;
+
+/// Profiling is global, so the tests must not run in parallel.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn find<'a>(entries: &'a [ProfileEntry], name: &str) -> &'a ProfileEntry {
+    entries
+        .iter()
+        .find(|entry| entry.name == name)
+        .unwrap_or_else(|| panic!("No entry named '{name}' was recorded"))
+}
+
+#[test]
+fn test_profile_paths() {
+    let _lock = LOCK.lock().unwrap();
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "profiledUsers": {
+                "type": "array",
+                "length": 3,
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "profiledName": {
+                            "type": "string",
+                            "value": "name",
+                            "transform": [{"type": "toUpperCase"}]
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    profiler::start();
+    generate_random_data(schema, None).unwrap();
+    let profile = profiler::finish();
+
+    let array = find(&profile.entries, "profiledUsers");
+    assert_eq!(array.category, ProfileCategory::Path);
+    assert_eq!(array.calls, 1);
+    assert!(array.self_time <= array.total);
+
+    let items = find(&profile.entries, "profiledUsers[]");
+    assert_eq!(items.calls, 3);
+    assert!(items.total <= array.total);
+
+    let name = find(&profile.entries, "profiledUsers[].profiledName");
+    assert_eq!(name.calls, 3);
+
+    let transform = find(&profile.entries, "toUpperCase");
+    assert_eq!(transform.category, ProfileCategory::Transform);
+    assert!(transform.calls >= 3);
+
+    assert!(profile.stacks.iter().any(|stack| stack.frames
+        == [
+            "(root)",
+            "profiledUsers",
+            "profiledUsers[]",
+            "profiledUsers[].profiledName",
+            "transform:toUpperCase"
+        ]));
+}
+
+#[test]
+fn test_profile_disabled() {
+    let _lock = LOCK.lock().unwrap();
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "notProfiled": {"type": "integer", "value": 1}
+        }
+    });
+
+    profiler::start();
+    let _ = profiler::finish();
+    generate_random_data(schema, None).unwrap();
+
+    profiler::start();
+    let profile = profiler::finish();
+    assert!(profile
+        .entries
+        .iter()
+        .all(|entry| entry.name != "notProfiled"));
+}
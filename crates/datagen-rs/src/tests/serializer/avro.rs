@@ -0,0 +1,355 @@
+use crate::generate::generated_schema::IntoRandom;
+use crate::schema::serializer::Serializer;
+use crate::util::avro::{AvroEncoder, AvroSchema};
+use crate::validation::validate::Validate;
+use crate::{generate_schema, schema};
+use serde_json::{json, Value};
+
+const SCHEMA_FILE: &str = "src/tests/serializer/user.avsc";
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn read_long(&mut self) -> i64 {
+        let mut res = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.0[0];
+            self.0 = &self.0[1..];
+            res |= ((byte & 0x7F) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        ((res >> 1) as i64) ^ -((res & 1) as i64)
+    }
+
+    fn read_bytes(&mut self) -> &[u8] {
+        let len = self.read_long() as usize;
+        let (res, rest) = self.0.split_at(len);
+        self.0 = rest;
+        res
+    }
+
+    fn read_string(&mut self) -> String {
+        String::from_utf8(self.read_bytes().to_vec()).unwrap()
+    }
+
+    fn read_header(&mut self) -> (Value, [u8; 16]) {
+        assert_eq!(&self.0[..4], b"Obj\x01");
+        self.0 = &self.0[4..];
+
+        let mut schema = None;
+        let count = self.read_long();
+        for _ in 0..count {
+            let key = self.read_string();
+            let value = self.read_string();
+            if key == "avro.schema" {
+                schema = Some(serde_json::from_str(&value).unwrap());
+            } else {
+                assert_eq!(key, "avro.codec");
+                assert_eq!(value, "null");
+            }
+        }
+        assert_eq!(self.read_long(), 0);
+
+        let mut sync = [0u8; 16];
+        sync.copy_from_slice(&self.0[..16]);
+        self.0 = &self.0[16..];
+
+        (schema.unwrap(), sync)
+    }
+}
+
+fn serialize(serializer: Serializer, value: Value) -> anyhow::Result<Vec<u8>> {
+    let generated = generate_schema!(value).unwrap();
+    serializer.serialize_generated_bytes(generated, None)
+}
+
+#[test]
+fn test_avro_inferred_schema() {
+    let serialized = serialize(
+        Serializer::Avro {
+            schema: None,
+            record_name: Some("User".into()),
+            namespace: None,
+        },
+        json!({
+            "type": "array",
+            "length": 2,
+            "items": {
+                "type": "object",
+                "properties": {
+                    "id": {
+                        "type": "integer",
+                        "value": 1
+                    },
+                    "name": "test"
+                }
+            }
+        }),
+    )
+    .unwrap();
+
+    let mut reader = Reader(&serialized);
+    let (schema, sync) = reader.read_header();
+    assert_eq!(
+        schema,
+        json!({
+            "type": "record",
+            "name": "User",
+            "fields": [
+                { "name": "id", "type": "long" },
+                { "name": "name", "type": "string" }
+            ]
+        })
+    );
+
+    assert_eq!(reader.read_long(), 2);
+    let mut block = Reader(reader.read_bytes());
+    assert_eq!(block.read_long(), 1);
+    assert_eq!(block.read_string(), "test");
+    assert_eq!(block.read_long(), 1);
+    assert_eq!(block.read_string(), "test");
+    assert!(block.0.is_empty());
+    assert_eq!(reader.0, sync);
+}
+
+#[test]
+fn test_avro_inferred_nullable_field() {
+    let schema = AvroSchema::infer(
+        &json!([{ "value": 1 }, { "value": null }, { "value": 1.5, "other": true }]),
+        "Record",
+        Some("com.example".into()),
+    )
+    .unwrap();
+
+    assert_eq!(
+        schema.to_json(),
+        json!({
+            "type": "record",
+            "name": "Record",
+            "namespace": "com.example",
+            "fields": [
+                { "name": "value", "type": ["null", "double"] },
+                { "name": "other", "type": ["null", "boolean"] }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_avro_inferred_sanitized_names() {
+    let serialized = serialize(
+        Serializer::Avro {
+            schema: None,
+            record_name: None,
+            namespace: None,
+        },
+        json!({
+            "type": "object",
+            "properties": {
+                "first-name": "John",
+                "@id": {
+                    "type": "integer",
+                    "value": 1
+                },
+                "1st": true
+            }
+        }),
+    )
+    .unwrap();
+
+    let mut reader = Reader(&serialized);
+    let (schema, _) = reader.read_header();
+    assert_eq!(
+        schema,
+        json!({
+            "type": "record",
+            "name": "Record",
+            "fields": [
+                { "name": "first_name", "type": "string" },
+                { "name": "_id", "type": "long" },
+                { "name": "_1st", "type": "boolean" }
+            ]
+        })
+    );
+
+    assert_eq!(reader.read_long(), 1);
+    let mut block = Reader(reader.read_bytes());
+    assert_eq!(block.read_string(), "John");
+    assert_eq!(block.read_long(), 1);
+    assert_eq!(block.0, [1]);
+}
+
+#[test]
+fn test_avro_inferred_name_collision() {
+    let err = AvroSchema::infer(
+        &json!({ "first-name": "John", "first_name": "Jane" }),
+        "User",
+        None,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "The keys 'first-name' and 'first_name' of record 'User' both map to the avro field 'first_name'"
+    );
+}
+
+#[test]
+fn test_avro_schema_file() {
+    let serialized = serialize(
+        Serializer::Avro {
+            schema: Some(SCHEMA_FILE.into()),
+            record_name: None,
+            namespace: None,
+        },
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "value": 42
+                },
+                "name": "John",
+                "status": "INACTIVE"
+            }
+        }),
+    )
+    .unwrap();
+
+    let mut reader = Reader(&serialized);
+    let (schema, _) = reader.read_header();
+    assert_eq!(schema["name"], "User");
+
+    assert_eq!(reader.read_long(), 1);
+    let mut block = Reader(reader.read_bytes());
+    assert_eq!(block.read_long(), 42);
+    assert_eq!(block.read_string(), "John");
+    assert_eq!(block.read_long(), 1);
+    // The email defaults to null, which is the first union branch
+    assert_eq!(block.read_long(), 0);
+    assert!(block.0.is_empty());
+}
+
+#[test]
+fn test_avro_schema_mismatch() {
+    let err = serialize(
+        Serializer::Avro {
+            schema: Some(SCHEMA_FILE.into()),
+            record_name: None,
+            namespace: None,
+        },
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "value": 42
+                },
+                "name": "John",
+                "status": "DELETED"
+            }
+        }),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        format!("{:#}", err),
+        "Failed to serialize data using the avro serializer: Failed to encode record #0 using the avro schema: Value \"DELETED\" at path 'status' does not match the avro type 'Status'"
+    );
+}
+
+#[test]
+fn test_avro_string_serialization_fails() {
+    let generated = generate_schema!("test").unwrap();
+    let serializer = Serializer::Avro {
+        schema: None,
+        record_name: None,
+        namespace: None,
+    };
+
+    assert!(serializer.is_binary());
+    assert!(serializer.serialize_generated(generated, None).is_err());
+}
+
+#[test]
+fn test_validate_avro_schema_file() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "options": {
+            "serializer": {
+                "type": "avro",
+                "schema": "src/tests/serializer/missing.avsc"
+            }
+        }
+    });
+
+    let error = schema.validate_root().unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(error[0].message, "Invalid avro schema");
+    assert_eq!(error[0].path, "options.serializer.schema");
+}
+
+#[test]
+fn test_avro_union_of_records() {
+    let schema = AvroSchema::parse(&json!({
+        "type": "record",
+        "name": "Payment",
+        "fields": [
+            {
+                "name": "method",
+                "type": [
+                    {
+                        "type": "record",
+                        "name": "Card",
+                        "fields": [{"name": "number", "type": "string"}]
+                    },
+                    {
+                        "type": "record",
+                        "name": "Transfer",
+                        "fields": [
+                            {"name": "iban", "type": "string"},
+                            {"name": "amount", "type": "long"}
+                        ]
+                    }
+                ]
+            }
+        ]
+    }))
+    .unwrap();
+    let encoder = AvroEncoder::new(&schema);
+
+    let mut buf = Vec::new();
+    encoder
+        .encode(&json!({"method": {"iban": "DE00", "amount": 5}}), &mut buf)
+        .unwrap();
+    let mut reader = Reader(&buf);
+    assert_eq!(reader.read_long(), 1);
+    assert_eq!(reader.read_string(), "DE00");
+    assert_eq!(reader.read_long(), 5);
+    assert!(reader.0.is_empty());
+
+    let mut buf = Vec::new();
+    encoder
+        .encode(&json!({"method": {"number": "4111"}}), &mut buf)
+        .unwrap();
+    let mut reader = Reader(&buf);
+    assert_eq!(reader.read_long(), 0);
+    assert_eq!(reader.read_string(), "4111");
+    assert!(reader.0.is_empty());
+
+    assert!(encoder
+        .encode(&json!({"method": {"iban": "DE00"}}), &mut Vec::new())
+        .is_err());
+    assert!(encoder
+        .encode(
+            &json!({"method": {"iban": "DE00", "amount": "5"}}),
+            &mut Vec::new()
+        )
+        .is_err());
+}
@@ -0,0 +1,312 @@
+use crate::generate::generated_schema::IntoRandom;
+use crate::generate_schema;
+use crate::schema::serializer::Serializer;
+use serde_json::{from_value, json, Value};
+
+fn serialize(serializer: Value, value: Value) -> anyhow::Result<String> {
+    let serializer: Serializer = from_value(serializer).unwrap();
+    let generated = generate_schema!(value).unwrap();
+
+    serializer.serialize_generated(generated, None)
+}
+
+#[test]
+fn test_xml_simple() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "root"
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": "John & Jane",
+                "tags": {
+                    "type": "array",
+                    "length": 2,
+                    "items": "tag"
+                }
+            }
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        "<root><name>John &amp; Jane</name><tags>tag</tags><tags>tag</tags></root>"
+    );
+}
+
+#[test]
+fn test_xml_attributes_and_text() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "item"
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "@id": {
+                    "type": "integer",
+                    "value": 1
+                },
+                "@label": "\"quoted\"",
+                "$text": "content"
+            }
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        "<item id=\"1\" label=\"&quot;quoted&quot;\">content</item>"
+    );
+}
+
+#[test]
+fn test_xml_item_elements() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "user",
+            "itemElements": {
+                "tags": "tag"
+            }
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "length": 2,
+                    "items": "value"
+                },
+                "empty": {
+                    "type": "array",
+                    "length": 0,
+                    "items": "value"
+                }
+            }
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        "<user><tags><tag>value</tag><tag>value</tag></tags></user>"
+    );
+}
+
+#[test]
+fn test_xml_namespaces_and_cdata() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "soap:Envelope",
+            "cdataKey": "#cdata",
+            "namespaces": {
+                "soap": "http://www.w3.org/2003/05/soap-envelope",
+                "": "http://example.com"
+            }
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "soap:Body": {
+                    "type": "object",
+                    "properties": {
+                        "message": {
+                            "type": "object",
+                            "properties": {
+                                "#cdata": "<b>bold</b>]]>"
+                            }
+                        }
+                    }
+                }
+            }
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        "<soap:Envelope xmlns:soap=\"http://www.w3.org/2003/05/soap-envelope\" xmlns=\"http://example.com\"><soap:Body><message><![CDATA[<b>bold</b>]]]]><![CDATA[>]]></message></soap:Body></soap:Envelope>"
+    );
+}
+
+#[test]
+fn test_xml_pretty() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "root",
+            "pretty": true,
+            "attributePrefix": "_"
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "_id": "1",
+                "child": "value"
+            }
+        }),
+    )
+    .unwrap();
+
+    assert!(res.starts_with("<?xml"));
+    assert!(res.ends_with("?>\n<root id=\"1\">\n  <child>value</child>\n</root>"));
+}
+
+#[test]
+fn test_xml_invalid_attribute() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "root",
+            "attributePrefix": "@"
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "@invalid": {
+                    "type": "object",
+                    "properties": {}
+                }
+            }
+        }),
+    );
+
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "The attribute '@invalid' of element 'root' must not be an array or object"
+    );
+}
+
+#[test]
+fn test_xml_invalid_names() {
+    let serializer = json!({
+        "type": "xml",
+        "rootElement": "root",
+        "attributePrefix": "@"
+    });
+
+    for (key, name) in [
+        ("first name", "first name"),
+        ("1st", "1st"),
+        ("<b>", "<b>"),
+        ("@a b", "a b"),
+    ] {
+        let res = serialize(
+            serializer.clone(),
+            json!({
+                "type": "object",
+                "properties": {
+                    key: "value"
+                }
+            }),
+        );
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            format!("'{name}' is not a valid XML name")
+        );
+    }
+
+    let res = serialize(
+        serializer,
+        json!({
+            "type": "object",
+            "properties": {
+                "first-name": "John",
+                "ns:last_name.value": "Doe"
+            }
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        "<root><first-name>John</first-name><ns:last_name.value>Doe</ns:last_name.value></root>"
+    );
+}
+
+#[test]
+fn test_xml_default_output() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "root"
+        }),
+        json!({
+            "type": "object",
+            "properties": {
+                "@id": "1",
+                "null": null,
+                "bool": true,
+                "number": 1.5,
+                "object": {
+                    "type": "object",
+                    "properties": {}
+                },
+                "empty": {
+                    "type": "array",
+                    "length": 0,
+                    "items": "value"
+                },
+                "text": "it's <b>"
+            }
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        "<root id=\"1\"><null/><bool>true</bool><number>1.5</number><object/><text>it's &lt;b&gt;</text></root>"
+    );
+}
+
+#[test]
+fn test_xml_default_array() {
+    let res = serialize(
+        json!({
+            "type": "xml",
+            "rootElement": "item"
+        }),
+        json!({
+            "type": "array",
+            "length": 2,
+            "items": {
+                "type": "object",
+                "properties": {
+                    "name": "value"
+                }
+            }
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        "<item><name>value</name></item><item><name>value</name></item>"
+    );
+}
+
+#[test]
+fn test_xml_default_invalid_names() {
+    for key in ["first name", "1st", "@a b", "$cdata"] {
+        let res = serialize(
+            json!({
+                "type": "xml",
+                "rootElement": "root"
+            }),
+            json!({
+                "type": "object",
+                "properties": {
+                    key: "value"
+                }
+            }),
+        );
+
+        assert!(res.unwrap_err().to_string().contains("is not allowed"));
+    }
+}
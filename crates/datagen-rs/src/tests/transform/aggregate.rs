@@ -0,0 +1,155 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+use serde_json::json;
+
+macro_rules! orders {
+    ($transform:tt) => {
+        generate_schema!({
+            "type": "array",
+            "values": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "customer": "alice",
+                        "country": "DE",
+                        "total": { "type": "integer", "value": 10 }
+                    }
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "customer": "bob",
+                        "country": "FR",
+                        "total": { "type": "integer", "value": 5 }
+                    }
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "customer": "alice",
+                        "country": "DE",
+                        "total": { "type": "integer", "value": 20 }
+                    }
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "customer": "carol",
+                        "country": "DE",
+                        "total": { "type": "integer", "value": 3 }
+                    }
+                }
+            ],
+            "transform": [$transform]
+        })
+    };
+}
+
+#[test]
+fn test_aggregate_to_array() {
+    let generated = orders!({
+        "type": "aggregate",
+        "groupBy": ["customer"],
+        "aggregations": {
+            "orders": { "function": "count" },
+            "total": { "function": "sum", "key": "total" },
+            "average": { "function": "avg", "key": "total" },
+            "smallest": { "function": "min", "key": "total" },
+            "largest": { "function": "max", "key": "total" }
+        }
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!([
+            {
+                "customer": "alice",
+                "orders": 2,
+                "total": 30,
+                "average": 15.0,
+                "smallest": 10,
+                "largest": 20
+            },
+            {
+                "customer": "bob",
+                "orders": 1,
+                "total": 5,
+                "average": 5.0,
+                "smallest": 5,
+                "largest": 5
+            },
+            {
+                "customer": "carol",
+                "orders": 1,
+                "total": 3,
+                "average": 3.0,
+                "smallest": 3,
+                "largest": 3
+            }
+        ])
+    );
+}
+
+#[test]
+fn test_aggregate_to_object() {
+    let generated = orders!({
+        "type": "aggregate",
+        "groupBy": ["country", "customer"],
+        "aggregations": {
+            "total": { "function": "sum", "key": "total" }
+        },
+        "output": "object"
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!({
+            "DE": {
+                "alice": { "total": 30 },
+                "carol": { "total": 3 }
+            },
+            "FR": {
+                "bob": { "total": 5 }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_aggregate_without_group() {
+    let generated = orders!({
+        "type": "aggregate",
+        "aggregations": {
+            "customers": { "function": "collect", "key": "customer" },
+            "count": { "function": "count" }
+        }
+    })
+    .unwrap();
+
+    let arr = assert_enum!(generated.as_ref(), GeneratedSchema::Array);
+    assert_eq!(arr.len(), 1);
+    assert_eq!(
+        serde_json::to_value(&arr[0]).unwrap(),
+        json!({
+            "customers": ["alice", "bob", "alice", "carol"],
+            "count": 4
+        })
+    );
+}
+
+#[test]
+fn test_aggregate_sum_non_numeric() {
+    let generated = orders!({
+        "type": "aggregate",
+        "groupBy": ["country"],
+        "aggregations": {
+            "total": { "function": "sum", "key": "customer" }
+        }
+    });
+
+    assert!(generated.is_err());
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("Only numbers can be aggregated using 'sum'. Actual type was String"));
+}
@@ -0,0 +1,109 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+use serde_json::json;
+use std::collections::HashSet;
+
+macro_rules! numbers {
+    ($transform:tt) => {
+        generate_schema!({
+            "type": "array",
+            "values": ["1", "2", "3", "4", "5"],
+            "transform": [$transform]
+        })
+    };
+}
+
+fn strings(generated: &GeneratedSchema) -> Vec<String> {
+    assert_enum!(generated, GeneratedSchema::Array)
+        .iter()
+        .map(|v| assert_enum!(v.as_ref(), GeneratedSchema::String).clone())
+        .collect()
+}
+
+#[test]
+fn test_shuffle() {
+    let generated = numbers!({ "type": "shuffle" }).unwrap();
+
+    let values = strings(&generated);
+    assert_eq!(values.len(), 5);
+    assert_eq!(
+        values.into_iter().collect::<HashSet<_>>(),
+        HashSet::from(["1", "2", "3", "4", "5"].map(String::from))
+    );
+}
+
+#[test]
+fn test_reverse() {
+    let generated = numbers!({ "type": "reverse" }).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!(["5", "4", "3", "2", "1"])
+    );
+}
+
+#[test]
+fn test_sample_n() {
+    let generated = numbers!({ "type": "sample", "n": 3 }).unwrap();
+
+    let values = strings(&generated);
+    assert_eq!(values.len(), 3);
+    assert!(values.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_sample_fraction() {
+    let generated = numbers!({ "type": "sample", "fraction": 0.4 }).unwrap();
+    assert_eq!(strings(&generated).len(), 2);
+
+    let generated = numbers!({ "type": "sample", "n": 10 }).unwrap();
+    assert_eq!(strings(&generated).len(), 5);
+}
+
+#[test]
+fn test_slice() {
+    let generated = numbers!({ "type": "slice", "start": 1, "end": 3 }).unwrap();
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!(["2", "3"]));
+
+    let generated = numbers!({ "type": "slice", "start": -2 }).unwrap();
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!(["4", "5"]));
+
+    let generated = numbers!({ "type": "slice", "start": 4, "end": 2 }).unwrap();
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!([]));
+}
+
+#[test]
+fn test_take_and_skip() {
+    let generated = numbers!({ "type": "take", "n": 2 }).unwrap();
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!(["1", "2"]));
+
+    let generated = numbers!({ "type": "skip", "n": 3 }).unwrap();
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!(["4", "5"]));
+}
+
+#[test]
+fn test_chunk() {
+    let generated = numbers!({ "type": "chunk", "size": 2 }).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!([["1", "2"], ["3", "4"], ["5"]])
+    );
+}
+
+#[test]
+fn test_array_transform_on_non_array() {
+    let generated = generate_schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "reverse"
+            }
+        ]
+    });
+
+    assert!(generated.is_err());
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("reverse can only be applied to arrays. Actual type was String"));
+}
@@ -0,0 +1,126 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+use serde_json::json;
+
+macro_rules! coerce {
+    ($value:expr, $transform:tt) => {
+        generate_schema!({
+            "type": "string",
+            "value": $value,
+            "transform": [$transform]
+        })
+    };
+}
+
+#[test]
+fn test_to_integer() {
+    let generated = coerce!(" 42 ", { "type": "toInteger" }).unwrap();
+    assert_eq!(
+        *assert_enum!(generated.as_ref(), GeneratedSchema::Integer),
+        42
+    );
+
+    let generated = coerce!("7.0", { "type": "toInteger" }).unwrap();
+    assert_eq!(
+        *assert_enum!(generated.as_ref(), GeneratedSchema::Integer),
+        7
+    );
+}
+
+#[test]
+fn test_to_integer_invalid() {
+    let generated = coerce!("abc", { "type": "toInteger" });
+    assert!(generated.is_err());
+    assert_eq!(
+        generated.unwrap_err().to_string(),
+        "Error generating schema at path '': Cannot convert String value \"abc\" to Integer"
+    );
+
+    let generated = coerce!("abc", { "type": "toInteger", "onInvalid": "null" }).unwrap();
+    assert_eq!(generated.as_ref(), &GeneratedSchema::None);
+}
+
+#[test]
+fn test_to_number() {
+    let generated = coerce!("2.5", { "type": "toNumber" }).unwrap();
+    assert_eq!(
+        assert_enum!(generated.as_ref(), GeneratedSchema::Number).0,
+        2.5
+    );
+
+    let generated = coerce!("NaN", { "type": "toNumber", "onInvalid": "null" }).unwrap();
+    assert_eq!(generated.as_ref(), &GeneratedSchema::None);
+}
+
+#[test]
+fn test_to_bool() {
+    for (value, expected) in [("true", true), ("No", false), ("1", true), ("off", false)] {
+        let generated = coerce!(value, { "type": "toBool" }).unwrap();
+        assert_eq!(
+            *assert_enum!(generated.as_ref(), GeneratedSchema::Bool),
+            expected
+        );
+    }
+
+    assert!(coerce!("maybe", { "type": "toBool" }).is_err());
+}
+
+#[test]
+fn test_parse_date() {
+    let generated = coerce!("2024-01-02T03:04:05Z", { "type": "parseDate" }).unwrap();
+    assert_eq!(
+        *assert_enum!(generated.as_ref(), GeneratedSchema::Integer),
+        1704164645
+    );
+
+    let generated = coerce!("02.01.2024", {
+        "type": "parseDate",
+        "format": "%d.%m.%Y",
+        "unit": "milliseconds"
+    })
+    .unwrap();
+    assert_eq!(
+        *assert_enum!(generated.as_ref(), GeneratedSchema::Integer),
+        1704153600000
+    );
+}
+
+#[test]
+fn test_parse_and_format_date() {
+    let generated = coerce!("02.01.2024 13:30", {
+        "type": "formatDate",
+        "inputFormat": "%d.%m.%Y %H:%M",
+        "format": "%Y-%m-%d"
+    })
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!("2024-01-02")
+    );
+
+    let generated = generate_schema!({
+        "type": "integer",
+        "value": 1704164645,
+        "transform": [
+            {
+                "type": "formatDate"
+            }
+        ]
+    })
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!("2024-01-02T03:04:05Z")
+    );
+}
+
+#[test]
+fn test_format_date_invalid_format() {
+    // Only possible if the schema wasn't validated
+    let res = coerce!("2024-01-02T03:04:05Z", {
+        "type": "formatDate",
+        "format": "%Y-%Q"
+    });
+
+    assert_eq!(res.unwrap_err().to_string(), "Invalid date format '%Y-%Q'");
+}
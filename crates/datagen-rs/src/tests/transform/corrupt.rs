@@ -0,0 +1,163 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+use serde_json::json;
+
+#[test]
+fn test_corrupt_rate_zero() {
+    let generated = generate_schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "corrupt",
+                "rate": 0
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!("test"));
+}
+
+#[test]
+fn test_corrupt_rate_out_of_range() {
+    // Only possible if the schema wasn't validated, the rate is clamped
+    for (rate, expected) in [(-1.0, json!("test")), (2.0, json!(""))] {
+        let generated = generate_schema!({
+            "type": "string",
+            "value": "test",
+            "transform": [
+                {
+                    "type": "corrupt",
+                    "rate": rate,
+                    "kinds": ["emptyString"]
+                }
+            ]
+        })
+        .unwrap();
+
+        assert_eq!(serde_json::to_value(&generated).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_corrupt_recursive() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "John",
+            "tags": {
+                "type": "array",
+                "values": ["a", "b"]
+            },
+            "age": {
+                "type": "integer",
+                "value": 42
+            }
+        },
+        "transform": [
+            {
+                "type": "corrupt",
+                "rate": 1,
+                "kinds": ["emptyString"]
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!({
+            "name": "",
+            "tags": ["", ""],
+            "age": ""
+        })
+    );
+}
+
+#[test]
+fn test_corrupt_not_recursive() {
+    let generated = generate_schema!({
+        "type": "array",
+        "values": ["a", "b"],
+        "transform": [
+            {
+                "type": "corrupt",
+                "rate": 1,
+                "recursive": false
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_value(&generated).unwrap(), json!(["a", "b"]));
+}
+
+#[test]
+fn test_corrupt_kinds() {
+    let corrupt = |value: &str, kind: &str| {
+        generate_schema!({
+            "type": "string",
+            "value": value,
+            "transform": [
+                {
+                    "type": "corrupt",
+                    "rate": 1,
+                    "kinds": [
+                        {
+                            "kind": kind,
+                            "weight": 2
+                        }
+                    ]
+                }
+            ]
+        })
+        .unwrap()
+    };
+
+    let typo = corrupt("hello", "typo");
+    let typo = assert_enum!(typo.as_ref(), GeneratedSchema::String);
+    assert_eq!(typo.len(), 5);
+    assert_ne!(typo, "hello");
+
+    let swapped = corrupt("ab", "swapChars");
+    assert_eq!(serde_json::to_value(&swapped).unwrap(), json!("ba"));
+
+    let whitespace = corrupt("hello", "trailingWhitespace");
+    let whitespace = assert_enum!(whitespace.as_ref(), GeneratedSchema::String);
+    assert!(whitespace.starts_with("hello"));
+    assert_ne!(whitespace.trim_end(), whitespace);
+
+    let email = corrupt("john@example.com", "invalidEmail");
+    let email = assert_enum!(email.as_ref(), GeneratedSchema::String);
+    assert_ne!(email, "john@example.com");
+    assert!(email.starts_with("john"));
+
+    let wrong_type = corrupt("hello", "wrongType");
+    assert_enum!(wrong_type.as_ref(), GeneratedSchema::Integer);
+
+    // Kinds which can't be applied leave the value unchanged
+    let unchanged = corrupt("hello", "outOfRange");
+    assert_eq!(serde_json::to_value(&unchanged).unwrap(), json!("hello"));
+}
+
+#[test]
+fn test_corrupt_out_of_range() {
+    let generated = generate_schema!({
+        "type": "integer",
+        "value": 5,
+        "transform": [
+            {
+                "type": "corrupt",
+                "rate": 1,
+                "kinds": ["outOfRange"]
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        assert_enum!(generated.as_ref(), GeneratedSchema::Integer).abs(),
+        6000
+    );
+}
@@ -14,6 +14,7 @@ fn create_filter(operator: FilterTransformOp, other: GeneratedSchema) -> MaybeVa
         operator,
         other,
         field: None,
+        filters: None,
     }))
 }
 
@@ -188,3 +189,212 @@ fn test_filter_complex_reference() {
         serde_json::to_string_pretty(&res).unwrap()
     );
 }
+
+#[test]
+fn test_filter_comparison_operators() {
+    let generate = |operator: &str, other: serde_json::Value| {
+        generate_schema!({
+            "type": "array",
+            "values": [
+                {"type": "integer", "value": 1},
+                {"type": "integer", "value": 5},
+                {"type": "number", "value": 10.5}
+            ],
+            "transform": [
+                {
+                    "type": "filter",
+                    "operator": operator,
+                    "other": other
+                },
+                {
+                    "type": "filterNonNull"
+                }
+            ]
+        })
+        .unwrap()
+    };
+
+    assert_eq!(
+        serde_json::to_value(generate("greaterThan", json!(1))).unwrap(),
+        json!([5, 10.5])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("greaterThanOrEquals", json!(5))).unwrap(),
+        json!([5, 10.5])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("lessThan", json!(5))).unwrap(),
+        json!([1])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("lessThanOrEquals", json!(5))).unwrap(),
+        json!([1, 5])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("between", json!([2, 11]))).unwrap(),
+        json!([5, 10.5])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("equals", json!(10.5))).unwrap(),
+        json!([10.5])
+    );
+    // Equality is strict, the number 5 doesn't equal the integer 5
+    assert_eq!(
+        serde_json::to_value(generate("equals", json!(5))).unwrap(),
+        json!([])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("notEquals", json!(5))).unwrap(),
+        json!([1, 5, 10.5])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("in", json!([1, 10.5]))).unwrap(),
+        json!([1, 10.5])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("notIn", json!([1, 10.5]))).unwrap(),
+        json!([5])
+    );
+}
+
+#[test]
+fn test_filter_string_operators() {
+    let generate = |operator: &str, other: &str| {
+        generate_schema!({
+            "type": "array",
+            "values": ["apple", "banana", "cherry"],
+            "transform": [
+                {
+                    "type": "filter",
+                    "operator": operator,
+                    "other": other
+                },
+                {
+                    "type": "filterNonNull"
+                }
+            ]
+        })
+        .unwrap()
+    };
+
+    assert_eq!(
+        serde_json::to_value(generate("contains", "an")).unwrap(),
+        json!(["banana"])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("startsWith", "ch")).unwrap(),
+        json!(["cherry"])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("endsWith", "e")).unwrap(),
+        json!(["apple"])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("matches", "^(a|b)")).unwrap(),
+        json!(["apple", "banana"])
+    );
+}
+
+#[test]
+fn test_filter_combinators() {
+    let order = |total: i64, active: bool| {
+        json!({
+            "type": "object",
+            "properties": {
+                "total": {"type": "integer", "value": total},
+                "active": active
+            },
+            "transform": [
+                {
+                    "type": "filter",
+                    "operator": "and",
+                    "filters": [
+                        {
+                            "field": "ref:./total",
+                            "operator": "greaterThan",
+                            "other": 100
+                        },
+                        {
+                            "field": "ref:./active",
+                            "operator": "equals",
+                            "other": true
+                        }
+                    ]
+                }
+            ]
+        })
+    };
+
+    assert_eq!(
+        serde_json::to_value(generate_schema!(order(150, true)).unwrap()).unwrap(),
+        json!({"total": 150, "active": true})
+    );
+    assert_eq!(
+        generate_schema!(order(50, true)).unwrap(),
+        GeneratedSchema::None.into()
+    );
+    assert_eq!(
+        generate_schema!(order(200, false)).unwrap(),
+        GeneratedSchema::None.into()
+    );
+
+    let generated = generate_schema!({
+        "type": "array",
+        "values": [1, 5, 10],
+        "transform": [
+            {
+                "type": "filter",
+                "operator": "not",
+                "filters": [
+                    {
+                        "operator": "lessThan",
+                        "other": 2
+                    },
+                    {
+                        "operator": "greaterThan",
+                        "other": 8
+                    }
+                ]
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(generated).unwrap(),
+        json!([null, 5.0, null])
+    );
+}
+
+#[test]
+fn test_filter_in_reference() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "allowed": {
+                "type": "array",
+                "values": ["a", "b"]
+            },
+            "values": {
+                "type": "array",
+                "values": ["a", "b", "c"],
+                "transform": [
+                    {
+                        "type": "filter",
+                        "operator": "in",
+                        "other": "ref:allowed"
+                    }
+                ]
+            }
+        }
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(generated).unwrap(),
+        json!({
+            "allowed": ["a", "b"],
+            "values": ["a", "b", null]
+        })
+    );
+}
@@ -1,7 +1,18 @@
+mod aggregate;
+mod array_transform;
+mod coerce;
+mod corrupt;
 mod filter;
+mod numeric_transform;
+mod object_transform;
+mod pseudonymize;
 mod random_remove;
 mod regex_filter;
 mod remove_all;
+mod reshape;
 mod sort;
 mod string_case_transform;
+mod string_transform;
 mod to_string;
+mod unique;
+mod when;
@@ -0,0 +1,327 @@
+use crate::generate::generated_schema::IntoRandom;
+use crate::generate_schema;
+
+#[test]
+fn test_round_to_decimals() {
+    let generated = generate_schema!({
+        "type": "number",
+        "value": 4.56789,
+        "transform": [
+            {
+                "type": "round",
+                "decimals": 2
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&generated).unwrap(), "4.57");
+}
+
+#[test]
+fn test_round_too_many_decimals() {
+    // Only possible if the schema wasn't validated
+    let res = generate_schema!({
+        "type": "number",
+        "value": 4.56789,
+        "transform": [
+            {
+                "type": "round",
+                "decimals": 400
+            }
+        ]
+    });
+
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "round can't round to more than 15 decimals"
+    );
+}
+
+#[test]
+fn test_round_to_step() {
+    let generated = generate_schema!({
+        "type": "number",
+        "value": 19.23,
+        "transform": [
+            {
+                "type": "round",
+                "step": 0.05
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&generated).unwrap(), "19.25");
+}
+
+#[test]
+fn test_round_integer_to_step() {
+    let generated = generate_schema!({
+        "type": "integer",
+        "value": 1234,
+        "transform": [
+            {
+                "type": "round",
+                "step": 100
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&generated).unwrap(), "1200");
+}
+
+#[test]
+fn test_floor_and_ceil() {
+    let floor = generate_schema!({
+        "type": "number",
+        "value": 2.789,
+        "transform": [
+            {
+                "type": "floor",
+                "decimals": 1
+            }
+        ]
+    })
+    .unwrap();
+    let ceil = generate_schema!({
+        "type": "number",
+        "value": -2.789,
+        "transform": [
+            {
+                "type": "ceil"
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&floor).unwrap(), "2.7");
+    assert_eq!(serde_json::to_string(&ceil).unwrap(), "-2.0");
+}
+
+#[test]
+fn test_clamp() {
+    let generated = generate_schema!({
+        "type": "array",
+        "length": {
+            "value": 3
+        },
+        "items": {
+            "type": "integer",
+            "min": 0,
+            "max": 100,
+            "transform": [
+                {
+                    "type": "clamp",
+                    "min": 40,
+                    "max": 60
+                }
+            ]
+        }
+    })
+    .unwrap();
+
+    let generated = serde_json::to_value(&generated).unwrap();
+    for value in generated.as_array().unwrap() {
+        let value = value.as_i64().unwrap();
+        assert!((40..=60).contains(&value));
+    }
+}
+
+#[test]
+fn test_clamp_without_integer_in_range() {
+    let integer = generate_schema!({
+        "type": "integer",
+        "value": 5,
+        "transform": [
+            {
+                "type": "clamp",
+                "min": 1.5,
+                "max": 1.7
+            }
+        ]
+    });
+    let number = generate_schema!({
+        "type": "number",
+        "value": 5.0,
+        "transform": [
+            {
+                "type": "clamp",
+                "min": 1.5,
+                "max": 1.7
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        integer.unwrap_err().to_string(),
+        "clamp can't be applied to integers, there is no integer between min and max"
+    );
+    assert_eq!(serde_json::to_string(&number).unwrap(), "1.7");
+}
+
+#[test]
+fn test_clamp_inverted_range() {
+    // Only possible if the schema wasn't validated
+    let res = generate_schema!({
+        "type": "number",
+        "value": 5.0,
+        "transform": [
+            {
+                "type": "clamp",
+                "min": 2,
+                "max": 1
+            }
+        ]
+    });
+
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "clamp requires min to be less than or equal to max"
+    );
+}
+
+#[test]
+fn test_scale() {
+    let integer = generate_schema!({
+        "type": "integer",
+        "value": 5,
+        "transform": [
+            {
+                "type": "scale",
+                "factor": 3,
+                "offset": -1
+            }
+        ]
+    })
+    .unwrap();
+    let number = generate_schema!({
+        "type": "integer",
+        "value": 5,
+        "transform": [
+            {
+                "type": "scale",
+                "factor": 0.5
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&integer).unwrap(), "14");
+    assert_eq!(serde_json::to_string(&number).unwrap(), "2.5");
+}
+
+#[test]
+fn test_scale_large_integer() {
+    let generated = generate_schema!({
+        "type": "integer",
+        "value": 9007199254740993i64,
+        "transform": [
+            {
+                "type": "scale",
+                "factor": 1,
+                "offset": 2
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        "9007199254740995"
+    );
+}
+
+#[test]
+fn test_scale_integer_overflow() {
+    let res = generate_schema!({
+        "type": "integer",
+        "value": i64::MAX / 2 + 1,
+        "transform": [
+            {
+                "type": "scale",
+                "factor": 2
+            }
+        ]
+    });
+
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("does not fit into an integer"));
+}
+
+#[test]
+fn test_abs() {
+    let generated = generate_schema!({
+        "type": "integer",
+        "value": -42,
+        "transform": [
+            {
+                "type": "abs"
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&generated).unwrap(), "42");
+}
+
+#[test]
+fn test_format_number() {
+    let en = generate_schema!({
+        "type": "number",
+        "value": -1234567.891,
+        "transform": [
+            {
+                "type": "formatNumber",
+                "decimals": 2
+            }
+        ]
+    })
+    .unwrap();
+    let de = generate_schema!({
+        "type": "integer",
+        "value": 1234567,
+        "transform": [
+            {
+                "type": "formatNumber",
+                "locale": "de"
+            }
+        ]
+    })
+    .unwrap();
+    let no_grouping = generate_schema!({
+        "type": "number",
+        "value": 1234.5,
+        "transform": [
+            {
+                "type": "formatNumber",
+                "locale": "de",
+                "grouping": false
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(serde_json::to_string(&en).unwrap(), r#""-1,234,567.89""#);
+    assert_eq!(serde_json::to_string(&de).unwrap(), r#""1.234.567""#);
+    assert_eq!(serde_json::to_string(&no_grouping).unwrap(), r#""1234,5""#);
+}
+
+#[test]
+fn test_numeric_transform_on_string() {
+    let generated = generate_schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "abs"
+            }
+        ]
+    });
+
+    assert!(generated.is_err());
+}
@@ -0,0 +1,482 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+use serde_json::json;
+
+#[test]
+fn test_merge_deep() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "defaults": {
+                "type": "object",
+                "properties": {
+                    "address": {
+                        "type": "object",
+                        "properties": {
+                            "country": "DE",
+                            "city": "Munich"
+                        }
+                    },
+                    "tags": {
+                        "type": "array",
+                        "values": ["a", "b"]
+                    }
+                }
+            },
+            "user": {
+                "type": "object",
+                "properties": {
+                    "name": "Jane",
+                    "address": {
+                        "type": "object",
+                        "properties": {
+                            "city": "Berlin"
+                        }
+                    },
+                    "tags": {
+                        "type": "array",
+                        "values": ["b", "c"]
+                    }
+                },
+                "transform": [
+                    {
+                        "type": "merge",
+                        "value": "ref:defaults",
+                        "arrays": "union"
+                    }
+                ]
+            }
+        }
+    })
+    .unwrap();
+
+    let generated = assert_enum!(generated.as_ref(), GeneratedSchema::Object);
+    assert_eq!(
+        serde_json::to_value(generated.get("user").unwrap()).unwrap(),
+        json!({
+            "name": "Jane",
+            "address": {
+                "city": "Munich",
+                "country": "DE"
+            },
+            "tags": ["b", "c", "a"]
+        })
+    );
+}
+
+#[test]
+fn test_merge_shallow_with_schema() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "Jane",
+            "address": {
+                "type": "object",
+                "properties": {
+                    "city": "Berlin"
+                }
+            },
+            "tags": {
+                "type": "array",
+                "values": ["a"]
+            }
+        },
+        "transform": [
+            {
+                "type": "merge",
+                "deep": false,
+                "arrays": "concat",
+                "value": {
+                    "type": "object",
+                    "properties": {
+                        "id": {
+                            "type": "integer",
+                            "value": 1
+                        },
+                        "address": {
+                            "type": "object",
+                            "properties": {
+                                "country": "DE"
+                            }
+                        },
+                        "tags": {
+                            "type": "array",
+                            "values": ["b"]
+                        }
+                    }
+                }
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(generated).unwrap(),
+        json!({
+            "name": "Jane",
+            "address": {
+                "country": "DE"
+            },
+            "tags": ["b"],
+            "id": 1
+        })
+    );
+}
+
+#[test]
+fn test_merge_arrays_by_index() {
+    let generated = generate_schema!({
+        "type": "array",
+        "values": [
+            {
+                "type": "object",
+                "properties": {
+                    "a": {"type": "integer", "value": 1}
+                }
+            },
+            {"type": "integer", "value": 2}
+        ],
+        "transform": [
+            {
+                "type": "merge",
+                "arrays": "byIndex",
+                "value": {
+                    "type": "array",
+                    "values": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "b": {"type": "integer", "value": 3}
+                            }
+                        },
+                        {"type": "integer", "value": 4},
+                        {"type": "integer", "value": 5}
+                    ]
+                }
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(generated).unwrap(),
+        json!([{"a": 1, "b": 3}, 4, 5])
+    );
+}
+
+#[test]
+fn test_merge_value() {
+    let generated = generate_schema!({
+        "type": "file",
+        "path": "src/tests/schema/data.json",
+        "mode": "random",
+        "transform": [
+            {
+                "type": "merge",
+                "value": {
+                    "type": "object",
+                    "properties": {
+                        "merged": true
+                    }
+                }
+            }
+        ]
+    })
+    .unwrap();
+
+    let value = assert_enum!(generated.as_ref(), GeneratedSchema::Value);
+    assert_eq!(value.get("merged"), Some(&json!(true)));
+    assert!(value.get("value").unwrap().is_i64());
+}
+
+#[test]
+fn test_merge_invalid_type() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "Jane"
+        },
+        "transform": [
+            {
+                "type": "merge",
+                "value": {
+                    "type": "array",
+                    "values": ["a"]
+                }
+            }
+        ]
+    });
+
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("Cannot merge a value of type Array into a value of type Object"));
+}
+
+#[test]
+fn test_json_patch() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "country": "DE",
+            "user": {
+                "type": "object",
+                "properties": {
+                    "firstName": "Jane",
+                    "zip": "12345",
+                    "address": {
+                        "type": "object",
+                        "properties": {
+                            "city": "Berlin"
+                        }
+                    },
+                    "tags": {
+                        "type": "array",
+                        "values": ["a", "b"]
+                    }
+                },
+                "transform": [
+                    {
+                        "type": "jsonPatch",
+                        "operations": [
+                            {"op": "test", "path": "/firstName", "value": "Jane"},
+                            {"op": "add", "path": "/tags/-", "value": "c"},
+                            {"op": "add", "path": "/tags/0", "value": "z"},
+                            {"op": "remove", "path": "/tags/1"},
+                            {"op": "move", "from": "/zip", "path": "/address/zip"},
+                            {"op": "copy", "from": "/firstName", "path": "/name"},
+                            {"op": "replace", "path": "/firstName", "value": "Joe"},
+                            {"op": "add", "path": "/address/country", "value": "ref:../country"}
+                        ]
+                    }
+                ]
+            }
+        }
+    })
+    .unwrap();
+
+    let generated = assert_enum!(generated.as_ref(), GeneratedSchema::Object);
+    let user = generated.get("user").unwrap();
+    assert_eq!(
+        serde_json::to_string(user).unwrap(),
+        r#"{"firstName":"Joe","address":{"city":"Berlin","zip":"12345","country":"DE"},"tags":["z","b","c"],"name":"Jane"}"#
+    );
+}
+
+#[test]
+fn test_json_patch_failed_test() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "integer", "value": 1}
+        },
+        "transform": [
+            {
+                "type": "jsonPatch",
+                "operations": [
+                    {"op": "test", "path": "/id", "value": 2}
+                ]
+            }
+        ]
+    });
+
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("The test of '/id' failed: expected 2, but the value was 1"));
+}
+
+#[test]
+fn test_json_patch_missing_path() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "integer", "value": 1}
+        },
+        "transform": [
+            {
+                "type": "jsonPatch",
+                "operations": [
+                    {"op": "remove", "path": "/name"}
+                ]
+            }
+        ]
+    });
+
+    assert!(format!("{:?}", generated.unwrap_err()).contains("The path '/name' does not exist"));
+}
+
+#[test]
+fn test_flatten_keys() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "Jane",
+            "address": {
+                "type": "object",
+                "properties": {
+                    "city": "Berlin",
+                    "geo": {
+                        "type": "object",
+                        "properties": {
+                            "lat": {"type": "integer", "value": 52}
+                        }
+                    }
+                }
+            },
+            "tags": {
+                "type": "array",
+                "values": ["a", "b"]
+            },
+            "empty": {
+                "type": "object",
+                "properties": {}
+            }
+        },
+        "transform": [
+            {
+                "type": "flattenKeys"
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        r#"{"name":"Jane","address.city":"Berlin","address.geo.lat":52,"tags.0":"a","tags.1":"b","empty":{}}"#
+    );
+}
+
+#[test]
+fn test_flatten_keys_array_modes() {
+    let generate = |arrays: &str| {
+        generate_schema!({
+            "type": "array",
+            "values": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "items": {
+                            "type": "array",
+                            "values": [
+                                {
+                                    "type": "object",
+                                    "properties": {
+                                        "id": {"type": "integer", "value": 1}
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                }
+            ],
+            "transform": [
+                {
+                    "type": "flattenKeys",
+                    "separator": "_",
+                    "arrays": arrays
+                }
+            ]
+        })
+        .unwrap()
+    };
+
+    assert_eq!(
+        serde_json::to_value(generate("brackets")).unwrap(),
+        json!([{"items[0]_id": 1}])
+    );
+    assert_eq!(
+        serde_json::to_value(generate("keep")).unwrap(),
+        json!([{"items": [{"id": 1}]}])
+    );
+}
+
+#[test]
+fn test_flatten_keys_conflict() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "address.city": "Berlin",
+            "address": {
+                "type": "object",
+                "properties": {
+                    "city": "Paris"
+                }
+            }
+        },
+        "transform": [
+            {
+                "type": "flattenKeys"
+            }
+        ]
+    });
+
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("The key 'address.city' conflicts with another key"));
+}
+
+#[test]
+fn test_unflatten_keys() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "Jane",
+            "address.city": "Berlin",
+            "address.geo.lat": {"type": "integer", "value": 52},
+            "tags.0": "a",
+            "tags.1": "b",
+            "codes.1": "x"
+        },
+        "transform": [
+            {
+                "type": "unflattenKeys"
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        r#"{"name":"Jane","address":{"city":"Berlin","geo":{"lat":52}},"tags":["a","b"],"codes":{"1":"x"}}"#
+    );
+}
+
+#[test]
+fn test_unflatten_keys_brackets() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "items[0]/id": {"type": "integer", "value": 1},
+            "items[1]/id": {"type": "integer", "value": 2},
+            "matrix[0][0]": {"type": "integer", "value": 3}
+        },
+        "transform": [
+            {
+                "type": "unflattenKeys",
+                "separator": "/",
+                "arrays": "brackets"
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(generated).unwrap(),
+        json!({"items": [{"id": 1}, {"id": 2}], "matrix": [[3]]})
+    );
+}
+
+#[test]
+fn test_unflatten_keys_conflict() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "address": "Berlin",
+            "address.city": "Berlin"
+        },
+        "transform": [
+            {
+                "type": "unflattenKeys"
+            }
+        ]
+    });
+
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("The key 'address.city' conflicts with another key"));
+}
@@ -0,0 +1,173 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+
+macro_rules! pseudonymize {
+    ($value:expr, $transform:tt) => {
+        generate_schema!({
+            "type": "string",
+            "value": $value,
+            "transform": [$transform]
+        })
+        .unwrap()
+    };
+}
+
+fn luhn_valid(number: &str) -> bool {
+    let sum: u32 = number
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            1 if digit * 2 > 9 => digit * 2 - 9,
+            1 => digit * 2,
+            _ => digit,
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+#[test]
+fn test_pseudonymize_with_generator() {
+    let transform = |value: &str, salt: &str| {
+        pseudonymize!(value, {
+            "type": "pseudonymize",
+            "salt": salt,
+            "generator": {
+                "type": "email"
+            }
+        })
+    };
+
+    let first = transform("john@example.com", "secret");
+    let email = assert_enum!(first.as_ref(), GeneratedSchema::String);
+    assert!(email.contains('@'));
+    assert_ne!(email, "john@example.com");
+
+    assert_eq!(first, transform("john@example.com", "secret"));
+    assert_ne!(first, transform("jane@example.com", "secret"));
+    assert_ne!(first, transform("john@example.com", "other"));
+}
+
+#[test]
+fn test_pseudonymize_date_time_without_to() {
+    // Dates without 'to' would depend on the current time
+    let res = generate_schema!({
+        "type": "string",
+        "value": "1990-05-17",
+        "transform": [
+            {
+                "type": "pseudonymize",
+                "salt": "secret",
+                "generator": {
+                    "type": "dateTime"
+                }
+            }
+        ]
+    });
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "The dateTime generator requires 'to' to pseudonymize values"
+    );
+
+    let date = pseudonymize!("1990-05-17", {
+        "type": "pseudonymize",
+        "salt": "secret",
+        "generator": {
+            "type": "dateTime",
+            "to": "2000-01-01T00:00:00Z"
+        }
+    });
+    let bounded = pseudonymize!("1990-05-17", {
+        "type": "pseudonymize",
+        "salt": "secret",
+        "generator": {
+            "type": "dateTime",
+            "from": "1970-01-01T00:00:00Z",
+            "to": "2000-01-01T00:00:00Z"
+        }
+    });
+
+    assert_eq!(date, bounded);
+    assert!(assert_enum!(date.as_ref(), GeneratedSchema::String).as_str() < "2000");
+}
+
+#[test]
+fn test_pseudonymize_preserve_format() {
+    let generated = pseudonymize!("AB-1234-cd", {
+        "type": "pseudonymize",
+        "salt": "secret",
+        "keepLast": 2
+    });
+
+    let masked = assert_enum!(generated.as_ref(), GeneratedSchema::String);
+    assert_eq!(masked.len(), 10);
+    assert!(masked.ends_with("-cd"));
+    assert!(masked[..2].chars().all(|c| c.is_ascii_uppercase()));
+    assert_eq!(&masked[2..3], "-");
+    assert!(masked[3..7].chars().all(|c| c.is_ascii_digit()));
+}
+
+#[test]
+fn test_pseudonymize_card_number() {
+    let generated = pseudonymize!("4111 1111 1111 1111", {
+        "type": "pseudonymize",
+        "salt": "secret",
+        "luhn": true
+    });
+
+    let masked = assert_enum!(generated.as_ref(), GeneratedSchema::String);
+    assert_eq!(masked.len(), 19);
+    assert_eq!(masked.matches(' ').count(), 3);
+    assert!(luhn_valid(masked));
+}
+
+#[test]
+fn test_pseudonymize_integer() {
+    let transform = || {
+        generate_schema!({
+            "type": "integer",
+            "value": 123456,
+            "transform": [
+                {
+                    "type": "pseudonymize",
+                    "salt": "secret"
+                }
+            ]
+        })
+        .unwrap()
+    };
+
+    let generated = transform();
+    let masked = assert_enum!(generated.as_ref(), GeneratedSchema::Integer);
+    assert_eq!(masked.to_string().len(), 6);
+    assert_eq!(generated, transform());
+}
+
+#[test]
+fn test_pseudonymize_large_integer() {
+    for salt in 0..50 {
+        let generated = generate_schema!({
+            "type": "integer",
+            "value": i64::MAX,
+            "transform": [
+                {
+                    "type": "pseudonymize",
+                    "salt": salt.to_string()
+                }
+            ]
+        })
+        .unwrap();
+
+        // Masked values which don't fit into an integer are returned as strings
+        match generated.as_ref() {
+            GeneratedSchema::Integer(masked) => assert_eq!(masked.to_string().len(), 19),
+            GeneratedSchema::String(masked) => {
+                assert_eq!(masked.len(), 19);
+                assert!(masked.parse::<i64>().is_err());
+            }
+            invalid => panic!("Unexpected value {invalid:?}"),
+        }
+    }
+}
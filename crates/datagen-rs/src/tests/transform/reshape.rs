@@ -0,0 +1,198 @@
+use crate::generate::generated_schema::IntoRandom;
+use crate::generate_schema;
+use serde_json::json;
+
+#[test]
+fn test_reshape_rename_and_pick() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "internalId": {"type": "integer", "value": 1},
+            "firstName": "John",
+            "lastName": "Doe"
+        },
+        "transform": [
+            {
+                "type": "reshape",
+                "rename": {
+                    "firstName": "first_name",
+                    "lastName": "last_name"
+                },
+                "pick": ["last_name", "first_name"]
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        r#"{"last_name":"Doe","first_name":"John"}"#
+    );
+}
+
+#[test]
+fn test_reshape_nest_and_hoist() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "John",
+            "street": "Main Street",
+            "city": "Springfield",
+            "meta": {
+                "type": "object",
+                "properties": {
+                    "createdBy": "admin"
+                }
+            },
+            "active": true
+        },
+        "transform": [
+            {
+                "type": "reshape",
+                "hoist": ["meta"],
+                "nest": {
+                    "address": ["city", "street"]
+                }
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        r#"{"name":"John","address":{"city":"Springfield","street":"Main Street"},"createdBy":"admin","active":true}"#
+    );
+}
+
+#[test]
+fn test_reshape_set() {
+    let generated = generate_schema!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "integer", "value": 42},
+            "name": "John"
+        },
+        "transform": [
+            {
+                "type": "reshape",
+                "set": {
+                    "userId": "ref:./id",
+                    "version": 2,
+                    "name": "Jane"
+                },
+                "pick": ["userId", "name", "version"]
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(generated).unwrap(),
+        json!({
+            "userId": 42,
+            "name": "Jane",
+            "version": 2.0
+        })
+    );
+}
+
+#[test]
+fn test_reshape_invalid_type() {
+    let res = generate_schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "reshape",
+                "pick": ["test"]
+            }
+        ]
+    });
+
+    assert!(res.is_err());
+    assert!(format!("{:?}", res.unwrap_err())
+        .contains("reshape can only be applied to objects. Actual type was String"));
+}
+
+#[test]
+fn test_reshape_hoist_invalid_type() {
+    let res = generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": "John"
+        },
+        "transform": [
+            {
+                "type": "reshape",
+                "hoist": ["name"]
+            }
+        ]
+    });
+
+    assert!(res.is_err());
+    assert!(format!("{:?}", res.unwrap_err())
+        .contains("Only objects can be hoisted, but 'name' is of type String"));
+}
+
+#[test]
+fn test_reshape_key_collisions() {
+    for (reshape, message) in [
+        (
+            json!({"rename": {"first": "last"}}),
+            "Can't rename a key to 'last', the key already exists",
+        ),
+        (
+            json!({"hoist": ["address"]}),
+            "Can't hoist the key 'city', the key already exists",
+        ),
+        (
+            json!({"nest": {"last": ["first"]}}),
+            "Can't nest keys into 'last', the key already exists",
+        ),
+    ] {
+        let mut transform = reshape;
+        transform["type"] = "reshape".into();
+
+        let res = generate_schema!({
+            "type": "object",
+            "properties": {
+                "first": "John",
+                "last": "Doe",
+                "city": "Berlin",
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": "Paris"
+                    }
+                }
+            },
+            "transform": [transform]
+        });
+
+        assert!(format!("{:?}", res.unwrap_err()).contains(message));
+    }
+}
+
+#[test]
+fn test_reshape_swap_names() {
+    let res = generate_schema!({
+        "type": "object",
+        "properties": {
+            "first": "John",
+            "last": "Doe"
+        },
+        "transform": [
+            {
+                "type": "reshape",
+                "rename": {"first": "last", "last": "first"},
+                "nest": {"name": ["first", "last"]}
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(res).unwrap(),
+        json!({"name": {"last": "John", "first": "Doe"}})
+    );
+}
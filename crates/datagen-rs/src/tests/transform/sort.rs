@@ -1,5 +1,6 @@
 use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
 use crate::{assert_enum, generate_schema};
+use serde_json::json;
 
 #[test]
 fn test_sort_array() {
@@ -163,3 +164,134 @@ fn test_sort_invalid_object_array() {
         "Cannot convert Object to comparable"
     );
 }
+
+/// Convert plain json objects into object schemas.
+fn to_schema(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(obj) => json!({
+            "type": "object",
+            "properties": obj
+                .into_iter()
+                .map(|(k, v)| (k, to_schema(v)))
+                .collect::<serde_json::Map<_, _>>()
+        }),
+        serde_json::Value::Array(arr) => arr.into_iter().map(to_schema).collect(),
+        value => value,
+    }
+}
+
+fn sort_values(
+    values: serde_json::Value,
+    transform: serde_json::Value,
+) -> anyhow::Result<serde_json::Value> {
+    let generated = generate_schema!({
+        "type": "array",
+        "values": to_schema(values),
+        "transform": [transform]
+    })?;
+
+    Ok(serde_json::to_value(&generated)?)
+}
+
+fn names(sorted: serde_json::Value) -> Vec<String> {
+    sorted
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_sort_multiple_keys() {
+    let sorted = sort_values(
+        json!([
+            {"name": "b", "age": 20},
+            {"name": "a", "age": 30},
+            {"name": "c", "age": 30},
+            {"name": "d", "age": 10}
+        ]),
+        json!({
+            "type": "sort",
+            "by": [
+                {"key": "age", "order": "desc"},
+                "name"
+            ]
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(names(sorted), vec!["a", "c", "b", "d"]);
+}
+
+#[test]
+fn test_sort_nested_path() {
+    let sorted = sort_values(
+        json!([
+            {"id": 1, "address": {"city": "Springfield"}},
+            {"id": 2, "address": {"city": "Berlin"}},
+            {"id": 3, "address": {"city": "Paris"}}
+        ]),
+        json!({
+            "type": "sort",
+            "by": "address.city"
+        }),
+    )
+    .unwrap();
+
+    let ids = sorted
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["id"].as_f64().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![2.0, 3.0, 1.0]);
+}
+
+#[test]
+fn test_sort_natural() {
+    let values = json!(["file10", "file2", "file1", "file02"]);
+
+    assert_eq!(
+        sort_values(values.clone(), json!({"type": "sort"})).unwrap(),
+        json!(["file02", "file1", "file10", "file2"])
+    );
+    assert_eq!(
+        sort_values(values, json!({"type": "sort", "natural": true})).unwrap(),
+        json!(["file1", "file2", "file02", "file10"])
+    );
+}
+
+#[test]
+fn test_sort_nulls() {
+    let values = json!([
+        {"name": "b", "age": 20},
+        {"name": "a", "age": null},
+        {"name": "c", "age": 10}
+    ]);
+
+    assert_eq!(
+        names(sort_values(values.clone(), json!({"type": "sort", "by": "age"})).unwrap()),
+        vec!["c", "b", "a"]
+    );
+    assert_eq!(
+        names(
+            sort_values(
+                values.clone(),
+                json!({"type": "sort", "by": "age", "nulls": "first"})
+            )
+            .unwrap()
+        ),
+        vec!["a", "c", "b"]
+    );
+    assert_eq!(
+        names(
+            sort_values(
+                values,
+                json!({"type": "sort", "by": [{"key": "age", "order": "desc", "nulls": "last"}]})
+            )
+            .unwrap()
+        ),
+        vec!["b", "c", "a"]
+    );
+}
@@ -0,0 +1,227 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::generate_schema;
+use serde_json::json;
+use std::sync::Arc;
+
+fn transform_string(value: &str, transform: serde_json::Value) -> anyhow::Result<String> {
+    let generated = generate_schema!({
+        "type": "string",
+        "value": value,
+        "transform": [transform]
+    })?;
+
+    match generated.as_ref() {
+        GeneratedSchema::String(str) => Ok(str.clone()),
+        other => anyhow::bail!("Expected a string, got {}", other.name()),
+    }
+}
+
+#[test]
+fn test_trim() {
+    assert_eq!(
+        transform_string("  test  ", json!({"type": "trim"})).unwrap(),
+        "test"
+    );
+    assert_eq!(
+        transform_string("  test  ", json!({"type": "trim", "side": "start"})).unwrap(),
+        "test  "
+    );
+    assert_eq!(
+        transform_string(
+            "--test__",
+            json!({"type": "trim", "side": "end", "characters": "_-"})
+        )
+        .unwrap(),
+        "--test"
+    );
+}
+
+#[test]
+fn test_substring() {
+    assert_eq!(
+        transform_string(
+            "hällo world",
+            json!({"type": "substring", "start": 1, "end": 5})
+        )
+        .unwrap(),
+        "ällo"
+    );
+    assert_eq!(
+        transform_string("hello world", json!({"type": "substring", "start": 6})).unwrap(),
+        "world"
+    );
+    assert_eq!(
+        transform_string("hello", json!({"type": "substring", "start": 10})).unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn test_truncate() {
+    assert_eq!(
+        transform_string(
+            "hello world",
+            json!({"type": "truncate", "length": 8, "ellipsis": "..."})
+        )
+        .unwrap(),
+        "hello..."
+    );
+    assert_eq!(
+        transform_string(
+            "hello",
+            json!({"type": "truncate", "length": 8, "ellipsis": "..."})
+        )
+        .unwrap(),
+        "hello"
+    );
+}
+
+#[test]
+fn test_pad() {
+    assert_eq!(
+        transform_string("42", json!({"type": "pad", "length": 5, "character": "0"})).unwrap(),
+        "00042"
+    );
+    assert_eq!(
+        transform_string("ab", json!({"type": "pad", "length": 5, "side": "end"})).unwrap(),
+        "ab   "
+    );
+    assert_eq!(
+        transform_string(
+            "ab",
+            json!({"type": "pad", "length": 5, "side": "both", "character": "*"})
+        )
+        .unwrap(),
+        "*ab**"
+    );
+}
+
+#[test]
+fn test_regex_replace() {
+    assert_eq!(
+        transform_string(
+            "2024-01-31",
+            json!({
+                "type": "regexReplace",
+                "pattern": "(?<year>\\d{4})-(\\d{2})-(\\d{2})",
+                "replacement": "$3.$2.${year}"
+            })
+        )
+        .unwrap(),
+        "31.01.2024"
+    );
+    assert_eq!(
+        transform_string(
+            "a a a",
+            json!({
+                "type": "regexReplace",
+                "pattern": "a",
+                "replacement": "b",
+                "limit": 2
+            })
+        )
+        .unwrap(),
+        "b b a"
+    );
+}
+
+#[test]
+fn test_slugify() {
+    assert_eq!(
+        transform_string("  Hällo, Wörld! ", json!({"type": "slugify"})).unwrap(),
+        "hallo-world"
+    );
+    assert_eq!(
+        transform_string("Hello World", json!({"type": "slugify", "separator": "_"})).unwrap(),
+        "hello_world"
+    );
+}
+
+#[test]
+fn test_encode() {
+    assert_eq!(
+        transform_string("hello?", json!({"type": "encode", "encoding": "base64"})).unwrap(),
+        "aGVsbG8/"
+    );
+    assert_eq!(
+        transform_string("hello?", json!({"type": "encode", "encoding": "base64Url"})).unwrap(),
+        "aGVsbG8_"
+    );
+    assert_eq!(
+        transform_string("hi", json!({"type": "encode", "encoding": "hex"})).unwrap(),
+        "6869"
+    );
+    assert_eq!(
+        transform_string("a b&c", json!({"type": "encode", "encoding": "url"})).unwrap(),
+        "a%20b%26c"
+    );
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(
+        transform_string("test", json!({"type": "hash", "algorithm": "md5"})).unwrap(),
+        "098f6bcd4621d373cade4e832627b4f6"
+    );
+    assert_eq!(
+        transform_string("test", json!({"type": "hash", "algorithm": "sha1"})).unwrap(),
+        "a94a8fe5ccb19ba61c4c0873d391e987982fbbd3"
+    );
+    assert_eq!(
+        transform_string("test", json!({"type": "hash", "algorithm": "sha256"})).unwrap(),
+        "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+    );
+}
+
+fn create_object(
+    serialize_non_strings: bool,
+    recursive: bool,
+) -> anyhow::Result<Arc<GeneratedSchema>> {
+    generate_schema!({
+        "type": "object",
+        "properties": {
+            "name": {
+                "type": "string",
+                "value": "  Test  "
+            },
+            "age": {
+                "type": "integer",
+                "value": 42
+            }
+        },
+        "transform": [
+            {
+                "type": "trim",
+                "serializeNonStrings": serialize_non_strings,
+                "recursive": recursive
+            }
+        ]
+    })
+}
+
+#[test]
+fn test_string_transform_recursive() {
+    let generated = create_object(false, true).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        r#"{"name":"Test","age":"42"}"#
+    );
+}
+
+#[test]
+fn test_string_transform_serialize_non_strings() {
+    let generated = create_object(true, false).unwrap();
+
+    assert_eq!(
+        generated.as_ref(),
+        &GeneratedSchema::String(r#"{"name":"  Test  ","age":42}"#.to_string())
+    );
+}
+
+#[test]
+fn test_string_transform_non_string() {
+    let generated = create_object(false, false);
+
+    assert!(generated.is_err());
+}
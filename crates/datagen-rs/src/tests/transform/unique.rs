@@ -0,0 +1,155 @@
+use crate::generate::generated_schema::{GeneratedSchema, IntoRandom};
+use crate::{assert_enum, generate_schema};
+use serde_json::json;
+use std::collections::HashSet;
+
+#[test]
+fn test_unique_values() {
+    let generated = generate_schema!({
+        "type": "array",
+        "values": ["a", "b", "a", "c", "b"],
+        "transform": [
+            {
+                "type": "unique"
+            }
+        ]
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&generated).unwrap(),
+        json!(["a", "b", "c"])
+    );
+}
+
+#[test]
+fn test_dedupe_keep_last_by_key() {
+    let generated = generate_schema!({
+        "type": "array",
+        "values": [
+            {
+                "type": "object",
+                "properties": {
+                    "name": "a",
+                    "address": {
+                        "type": "object",
+                        "properties": {
+                            "city": "Berlin"
+                        }
+                    }
+                }
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "name": "b",
+                    "address": {
+                        "type": "object",
+                        "properties": {
+                            "city": "Paris"
+                        }
+                    }
+                }
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "name": "c",
+                    "address": {
+                        "type": "object",
+                        "properties": {
+                            "city": "Berlin"
+                        }
+                    }
+                }
+            }
+        ],
+        "transform": [
+            {
+                "type": "dedupe",
+                "by": "address.city",
+                "keep": "last"
+            }
+        ]
+    })
+    .unwrap();
+
+    let names = assert_enum!(generated.as_ref(), GeneratedSchema::Array)
+        .iter()
+        .map(|v| {
+            let obj = assert_enum!(v.as_ref(), GeneratedSchema::Object);
+            assert_enum!(obj.get("name").unwrap().as_ref(), GeneratedSchema::String).clone()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["b", "c"]);
+}
+
+#[test]
+fn test_unique_refill() {
+    let generated = generate_schema!({
+        "type": "array",
+        "length": {
+            "value": 20
+        },
+        "items": {
+            "type": "integer",
+            "min": 0,
+            "max": 25
+        },
+        "transform": [
+            {
+                "type": "unique",
+                "refill": true
+            }
+        ]
+    })
+    .unwrap();
+
+    let arr = assert_enum!(generated.as_ref(), GeneratedSchema::Array);
+    assert_eq!(arr.len(), 20);
+    let values = arr
+        .iter()
+        .map(|v| *assert_enum!(v.as_ref(), GeneratedSchema::Integer))
+        .collect::<HashSet<_>>();
+    assert_eq!(values.len(), 20);
+}
+
+#[test]
+fn test_unique_refill_impossible() {
+    let generated = generate_schema!({
+        "type": "array",
+        "length": {
+            "value": 5
+        },
+        "items": {
+            "type": "bool"
+        },
+        "transform": [
+            {
+                "type": "unique",
+                "refill": true,
+                "maxAttempts": 10
+            }
+        ]
+    });
+
+    assert!(generated.is_err());
+    assert!(format!("{:?}", generated.unwrap_err())
+        .contains("Failed to generate 5 unique elements after 10 attempts"));
+}
+
+#[test]
+fn test_unique_refill_without_items() {
+    let generated = generate_schema!({
+        "type": "array",
+        "values": [1, 1],
+        "transform": [
+            {
+                "type": "unique",
+                "refill": true
+            }
+        ]
+    });
+
+    assert!(generated.is_err());
+}
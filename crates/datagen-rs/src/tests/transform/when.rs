@@ -0,0 +1,92 @@
+use crate::generate::generated_schema::IntoRandom;
+use crate::generate_schema;
+use serde_json::json;
+
+#[test]
+fn test_when_condition_on_value() {
+    for (value, expected) in [("short", "short"), ("a very long value", "a very")] {
+        let generated = generate_schema!({
+            "type": "string",
+            "value": value,
+            "transform": [
+                {
+                    "type": "when",
+                    "condition": {
+                        "operator": "contains",
+                        "other": "long"
+                    },
+                    "transform": [
+                        {
+                            "type": "truncate",
+                            "length": 6
+                        }
+                    ]
+                }
+            ]
+        })
+        .unwrap();
+
+        assert_eq!(serde_json::to_value(&generated).unwrap(), json!(expected));
+    }
+}
+
+#[test]
+fn test_when_condition_on_sibling() {
+    for (country, expected) in [("DE", "ANNA"), ("FR", "Anna")] {
+        let generated = generate_schema!({
+            "type": "object",
+            "properties": {
+                "country": country,
+                "name": {
+                    "type": "string",
+                    "value": "Anna",
+                    "transform": [
+                        {
+                            "type": "when",
+                            "condition": {
+                                "field": "ref:../country",
+                                "operator": "equals",
+                                "other": "DE"
+                            },
+                            "transform": [
+                                {
+                                    "type": "toUpperCase"
+                                }
+                            ]
+                        }
+                    ]
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&generated).unwrap(),
+            json!({ "country": country, "name": expected })
+        );
+    }
+}
+
+#[test]
+fn test_when_probability() {
+    for (probability, expected) in [(0.0, "test"), (1.0, "TEST")] {
+        let generated = generate_schema!({
+            "type": "string",
+            "value": "test",
+            "transform": [
+                {
+                    "type": "when",
+                    "probability": probability,
+                    "transform": [
+                        {
+                            "type": "toUpperCase"
+                        }
+                    ]
+                }
+            ]
+        })
+        .unwrap();
+
+        assert_eq!(serde_json::to_value(&generated).unwrap(), json!(expected));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::generate::generated_schema::IntoRandom;
+use crate::generate_schema;
+use crate::schema::serializer::Serializer;
+use crate::util::compression::Compression;
+use serde_json::{from_value, json};
+use std::io::{Read, Write};
+
+const DATA: &[u8] = br#"[{"name":"test","age":42},{"name":"test","age":42}]"#;
+
+fn decompress(compression: Compression, data: &[u8]) -> Vec<u8> {
+    let mut res = Vec::new();
+    match compression {
+        Compression::Gzip => flate2::read::GzDecoder::new(data)
+            .read_to_end(&mut res)
+            .unwrap(),
+        Compression::Zstd => zstd::Decoder::new(data)
+            .unwrap()
+            .read_to_end(&mut res)
+            .unwrap(),
+        Compression::Bzip2 => bzip2::read::BzDecoder::new(data)
+            .read_to_end(&mut res)
+            .unwrap(),
+    };
+
+    res
+}
+
+#[test]
+fn test_compress() {
+    for compression in [Compression::Gzip, Compression::Zstd, Compression::Bzip2] {
+        let compressed = compression.compress(DATA).unwrap();
+
+        assert_ne!(compressed, DATA);
+        assert_eq!(decompress(compression, &compressed), DATA);
+    }
+}
+
+#[test]
+fn test_compressed_writer() {
+    for compression in [Compression::Gzip, Compression::Zstd, Compression::Bzip2] {
+        let mut writer = compression.writer(Vec::new()).unwrap();
+        for chunk in DATA.chunks(7) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        let compressed = writer.finish().unwrap();
+        assert_eq!(decompress(compression, &compressed), DATA);
+    }
+}
+
+#[test]
+fn test_serialize_into_compressed_writer() {
+    let generated = generate_schema!({
+        "type": "array",
+        "length": 2,
+        "items": {
+            "type": "object",
+            "properties": {
+                "name": "test",
+                "age": {
+                    "type": "integer",
+                    "value": 42
+                }
+            }
+        }
+    })
+    .unwrap();
+
+    for serializer in [
+        json!({"type": "json"}),
+        json!({"type": "json", "pretty": true}),
+        json!({"type": "yaml"}),
+        json!({"type": "xml", "rootElement": "root"}),
+    ] {
+        let serializer: Serializer = from_value(serializer).unwrap();
+        let expected = serializer
+            .serialize_generated(generated.clone(), None)
+            .unwrap();
+
+        let mut writer = Compression::Gzip.writer(Vec::new()).unwrap();
+        serializer
+            .serialize_generated_to(generated.clone(), None, &mut writer)
+            .unwrap();
+
+        let compressed = writer.finish().unwrap();
+        assert_eq!(
+            decompress(Compression::Gzip, &compressed),
+            expected.as_bytes()
+        );
+    }
+}
+
+#[test]
+fn test_from_path() {
+    assert_eq!(
+        Compression::from_path("out/data.json.gz"),
+        Some(Compression::Gzip)
+    );
+    assert_eq!(
+        Compression::from_path("data.yaml.zst"),
+        Some(Compression::Zstd)
+    );
+    assert_eq!(
+        Compression::from_path("data.xml.bz2"),
+        Some(Compression::Bzip2)
+    );
+    assert_eq!(Compression::from_path("data.json"), None);
+    assert_eq!(Compression::from_path("data"), None);
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("gzip".parse::<Compression>().unwrap(), Compression::Gzip);
+    assert_eq!("ZSTD".parse::<Compression>().unwrap(), Compression::Zstd);
+    assert_eq!("bz2".parse::<Compression>().unwrap(), Compression::Bzip2);
+    assert!("lzma".parse::<Compression>().is_err());
+}
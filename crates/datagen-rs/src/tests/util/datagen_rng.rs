@@ -0,0 +1,40 @@
+use crate::generate::current_schema::CurrentSchema;
+use crate::generate::generated_schema::IntoRandom;
+use crate::plugins::plugin_list::PluginList;
+use crate::schema::any_value::AnyValue;
+use serde_json::json;
+
+fn generate(seed: u64) -> String {
+    let value: AnyValue = serde_json::from_value(json!({
+        "type": "array",
+        "length": {"min": 1, "max": 20},
+        "items": {
+            "type": "object",
+            "properties": {
+                "id": {"type": "string", "generator": {"type": "uuid"}},
+                "email": {"type": "string", "generator": {"type": "email"}},
+                "age": {"type": "integer", "min": 0, "max": 100},
+                "score": {"type": "number"},
+                "active": {"type": "bool"},
+                "role": {"type": "anyOf", "values": ["admin", "user", "guest"]},
+                "tags": {
+                    "type": "array",
+                    "length": {"min": 0, "max": 5},
+                    "items": {"type": "integer", "min": 0, "max": 10},
+                    "transform": [{"type": "shuffle"}]
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let root = CurrentSchema::seeded_root(Default::default(), PluginList::empty().into(), seed);
+    serde_json::to_string(&value.into_random(root.into()).unwrap()).unwrap()
+}
+
+#[test]
+fn test_seeded_generation() {
+    // Every generation uses a fresh root schema
+    assert_eq!(generate(1), generate(1));
+    assert_ne!(generate(1), generate(2));
+}
@@ -103,3 +103,30 @@ fn test_deserialize_with_default_value() {
     let generated = generate_random_data(result, None).unwrap();
     assert_eq!(generated, "\"default_value\"".to_string());
 }
+
+#[test]
+fn test_deserialize_with_variables() {
+    let json = json!({"id": "${ID}", "name": "${VARIABLE_NAME}", "status": "${STATUS:-active}"});
+    envmnt::set("VARIABLE_NAME", "env");
+    let variables = [("ID".to_string(), "42".to_string())].into_iter().collect();
+    let result: serde_json::Value =
+        crate::util::json_deserialize::from_value_with_variables(json, &variables).unwrap();
+
+    assert_eq!(
+        result,
+        json!({"id": "42", "name": "env", "status": "active"})
+    );
+}
+
+#[test]
+fn test_deserialize_variables_take_precedence() {
+    let json = json!({"name": "${PRECEDENCE_NAME}"});
+    envmnt::set("PRECEDENCE_NAME", "env");
+    let variables = [("PRECEDENCE_NAME".to_string(), "variable".to_string())]
+        .into_iter()
+        .collect();
+    let result: serde_json::Value =
+        crate::util::json_deserialize::from_value_with_variables(json, &variables).unwrap();
+
+    assert_eq!(result, json!({"name": "variable"}));
+}
@@ -3,6 +3,9 @@ use crate::generate::datagen_context::DatagenContextRef;
 use crate::plugins::plugin_list::PluginList;
 use crate::schema::schema_definition::SchemaOptions;
 
+#[cfg(feature = "compression")]
+mod compression;
+mod datagen_rng;
 #[cfg(feature = "env-schema")]
 mod json_deserialize;
 
@@ -379,3 +379,685 @@ fn test_validate_invalid_file_transform() {
         })
     );
 }
+
+#[test]
+fn test_validate_invalid_filter() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "filter",
+                "operator": "between",
+                "other": [1, 2, 3]
+            },
+            {
+                "type": "filter",
+                "operator": "matches",
+                "other": "("
+            },
+            {
+                "type": "filter",
+                "operator": "or",
+                "filters": [
+                    {
+                        "operator": "in",
+                        "other": 1
+                    }
+                ]
+            },
+            {
+                "type": "filter",
+                "operator": "and"
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 4);
+    assert_eq!(
+        error[0].message,
+        "The 'between' operator requires an array with two values"
+    );
+    assert_eq!(error[0].path, "transform.0.other");
+    assert_eq!(error[1].message, "invalid regex pattern");
+    assert_eq!(error[1].path, "transform.1.other");
+    assert_eq!(
+        error[2].message,
+        "The 'in' and 'notIn' operators require an array or a reference"
+    );
+    assert_eq!(error[2].path, "transform.2.filters.0.other");
+    assert_eq!(
+        error[3].message,
+        "At least one filter is required if the operator is 'and', 'or' or 'not'"
+    );
+    assert_eq!(error[3].path, "transform.3");
+}
+
+#[test]
+fn test_validate_invalid_reshape() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {},
+        "transform": [
+            {
+                "type": "reshape"
+            },
+            {
+                "type": "reshape",
+                "rename": {
+                    "a": "c",
+                    "b": "c"
+                },
+                "nest": {
+                    "first": ["d"],
+                    "second": ["d"],
+                    "third": []
+                }
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 4);
+    assert_eq!(
+        error[0].message,
+        "At least one of set, rename, hoist, nest or pick must be set"
+    );
+    assert_eq!(error[0].path, "transform.0");
+    assert_eq!(
+        error[1].message,
+        "Multiple keys must not be renamed to the same name"
+    );
+    assert_eq!(error[1].path, "transform.1.rename");
+    assert_eq!(
+        error[2].message,
+        "A key must not be moved into multiple nested objects"
+    );
+    assert_eq!(error[2].path, "transform.1.nest");
+    assert_eq!(
+        error[3].message,
+        "The keys of a nested object must not be empty"
+    );
+    assert_eq!(error[3].path, "transform.1.nest.third");
+}
+
+#[test]
+fn test_validate_invalid_numeric_transforms() {
+    let schema = schema!({
+        "type": "number",
+        "transform": [
+            {
+                "type": "round",
+                "decimals": 2,
+                "step": -1
+            },
+            {
+                "type": "clamp"
+            },
+            {
+                "type": "clamp",
+                "min": 10,
+                "max": 5
+            },
+            {
+                "type": "scale"
+            },
+            {
+                "type": "formatNumber",
+                "locale": "invalid"
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 6);
+    assert_eq!(error[0].message, "Only one of decimals and step can be set");
+    assert_eq!(error[0].path, "transform.0");
+    assert_eq!(error[1].message, "The step must be greater than zero");
+    assert_eq!(error[1].path, "transform.0.step");
+    assert_eq!(error[2].message, "At least one of min and max must be set");
+    assert_eq!(error[2].path, "transform.1");
+    assert_eq!(error[3].message, "min must be less than or equal to max");
+    assert_eq!(error[3].path, "transform.2");
+    assert_eq!(
+        error[4].message,
+        "At least one of factor and offset must be set"
+    );
+    assert_eq!(error[4].path, "transform.3");
+    assert_eq!(error[5].message, "Unknown locale");
+    assert_eq!(error[5].path, "transform.4.locale");
+}
+
+#[test]
+fn test_validate_round_too_many_decimals() {
+    let schema = schema!({
+        "type": "number",
+        "transform": [
+            {
+                "type": "round",
+                "decimals": 15
+            },
+            {
+                "type": "floor",
+                "decimals": 400
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0].message,
+        "The number of decimals must be at most 15"
+    );
+    assert_eq!(error[0].path, "transform.1.decimals");
+}
+
+#[test]
+fn test_validate_numeric_transform_on_non_numeric_value() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {
+            "name": {
+                "type": "string",
+                "value": "test",
+                "transform": [
+                    {
+                        "type": "round"
+                    }
+                ]
+            },
+            "price": {
+                "type": "number",
+                "transform": [
+                    {
+                        "type": "formatNumber"
+                    },
+                    {
+                        "type": "abs"
+                    }
+                ]
+            },
+            "valid": {
+                "type": "integer",
+                "transform": [
+                    {
+                        "type": "scale",
+                        "factor": 0.5
+                    },
+                    {
+                        "type": "round",
+                        "decimals": 1
+                    }
+                ]
+            }
+        }
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0].message,
+        "The transform can only be applied to Number or Integer values, but the value is of type String"
+    );
+    assert_eq!(error[0].path, "properties.name.transform.0");
+    assert_eq!(
+        error[1].message,
+        "The transform can only be applied to Number or Integer values, but the value is of type String"
+    );
+    assert_eq!(error[1].path, "properties.price.transform.1");
+}
+
+#[test]
+fn test_validate_invalid_string_transforms() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "substring",
+                "start": 5,
+                "end": 2
+            },
+            {
+                "type": "truncate",
+                "length": 2,
+                "ellipsis": "..."
+            },
+            {
+                "type": "regexReplace",
+                "pattern": "(",
+                "replacement": ""
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].message, "start must be less than or equal to end");
+    assert_eq!(error[0].path, "transform.0");
+    assert_eq!(
+        error[1].message,
+        "The ellipsis must not be longer than length"
+    );
+    assert_eq!(error[1].path, "transform.1.ellipsis");
+    assert_eq!(error[2].message, "invalid regex pattern");
+    assert_eq!(error[2].path, "transform.2.pattern");
+}
+
+#[test]
+fn test_validate_invalid_sort() {
+    let schema = schema!({
+        "type": "array",
+        "values": [],
+        "transform": [
+            {
+                "type": "sort",
+                "by": []
+            },
+            {
+                "type": "sort",
+                "by": ["name", {"key": ""}]
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(error[0].message, "At least one sort key is required");
+    assert_eq!(error[0].path, "transform.0.by");
+    assert_eq!(error[1].message, "The sort key must not be empty");
+    assert_eq!(error[1].path, "transform.1.by.1");
+}
+
+#[test]
+fn test_validate_invalid_unique() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "unique",
+                "by": "",
+                "maxAttempts": 10
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].message, "The key must not be empty");
+    assert_eq!(error[0].path, "transform.0.by");
+    assert_eq!(
+        error[1].message,
+        "maxAttempts can only be set if refill is enabled"
+    );
+    assert_eq!(error[1].path, "transform.0.maxAttempts");
+    assert_eq!(
+        error[2].message,
+        "The transform can only be applied to Array values, but the value is of type String"
+    );
+    assert_eq!(error[2].path, "transform.0");
+}
+
+#[test]
+fn test_validate_invalid_aggregate() {
+    let schema = schema!({
+        "type": "array",
+        "values": [],
+        "transform": [
+            {
+                "type": "aggregate",
+                "groupBy": [""],
+                "aggregations": {
+                    "total": {
+                        "function": "sum"
+                    }
+                }
+            },
+            {
+                "type": "aggregate",
+                "aggregations": {}
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].message, "The key must not be empty");
+    assert_eq!(error[0].path, "transform.0.groupBy.0");
+    assert_eq!(error[1].message, "A key is required for the 'sum' function");
+    assert_eq!(error[1].path, "transform.0.aggregations.total");
+    assert_eq!(error[2].message, "At least one aggregation is required");
+    assert_eq!(error[2].path, "transform.1.aggregations");
+}
+
+#[test]
+fn test_validate_invalid_array_transforms() {
+    let schema = schema!({
+        "type": "array",
+        "values": [],
+        "transform": [
+            {
+                "type": "sample",
+                "n": 2,
+                "fraction": 1.5
+            },
+            {
+                "type": "slice",
+                "start": 3,
+                "end": 1
+            },
+            {
+                "type": "chunk",
+                "size": 0
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 4);
+    assert_eq!(
+        error[0].message,
+        "Exactly one of n and fraction must be set"
+    );
+    assert_eq!(error[0].path, "transform.0");
+    assert_eq!(error[1].message, "fraction must be between 0 and 1");
+    assert_eq!(error[1].path, "transform.0.fraction");
+    assert_eq!(error[2].message, "start must be less than or equal to end");
+    assert_eq!(error[2].path, "transform.1");
+    assert_eq!(error[3].message, "The size must be greater than zero");
+    assert_eq!(error[3].path, "transform.2.size");
+}
+
+#[test]
+fn test_validate_invalid_when() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "when",
+                "probability": 2,
+                "transform": [
+                    {
+                        "type": "abs"
+                    }
+                ]
+            },
+            {
+                "type": "when",
+                "transform": []
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 4);
+    assert_eq!(error[0].message, "probability must be between 0 and 1");
+    assert_eq!(error[0].path, "transform.0.probability");
+    assert_eq!(
+        error[1].message,
+        "At least one of condition and probability must be set"
+    );
+    assert_eq!(error[1].path, "transform.1");
+    assert_eq!(error[2].message, "At least one transform is required");
+    assert_eq!(error[2].path, "transform.1.transform");
+    assert_eq!(
+        error[3].message,
+        "The transform can only be applied to Number or Integer values, but the value is of type String"
+    );
+    assert_eq!(error[3].path, "transform.0.transform.0");
+}
+
+#[test]
+fn test_validate_invalid_coerce() {
+    let schema = schema!({
+        "type": "array",
+        "values": [],
+        "transform": [
+            {
+                "type": "toInteger"
+            },
+            {
+                "type": "formatDate",
+                "format": "",
+                "inputFormat": "%Q"
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].message, "format must not be empty");
+    assert_eq!(error[0].path, "transform.1.format");
+    assert_eq!(error[1].message, "invalid date format");
+    assert_eq!(error[1].path, "transform.1.inputFormat");
+    assert_eq!(
+        error[2].message,
+        "The transform can only be applied to String or Number or Integer or Bool values, but the value is of type Array"
+    );
+    assert_eq!(error[2].path, "transform.0");
+}
+
+#[test]
+fn test_validate_invalid_corrupt() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "corrupt",
+                "rate": 1.5,
+                "kinds": [
+                    "typo",
+                    {
+                        "kind": "swapChars",
+                        "weight": 0
+                    }
+                ]
+            },
+            {
+                "type": "corrupt",
+                "kinds": []
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].message, "rate must be between 0 and 1");
+    assert_eq!(error[0].path, "transform.0.rate");
+    assert_eq!(error[1].message, "The weight must be greater than zero");
+    assert_eq!(error[1].path, "transform.0.kinds.1");
+    assert_eq!(error[2].message, "At least one corruption kind is required");
+    assert_eq!(error[2].path, "transform.1.kinds");
+}
+
+#[test]
+fn test_validate_invalid_pseudonymize() {
+    let schema = schema!({
+        "type": "string",
+        "value": "test",
+        "transform": [
+            {
+                "type": "pseudonymize",
+                "salt": "",
+                "generator": {
+                    "type": "format",
+                    "format": "{{a}}",
+                    "args": {}
+                },
+                "keepLast": 4
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].message, "The salt must not be empty");
+    assert_eq!(error[0].path, "transform.0.salt");
+    assert_eq!(
+        error[1].message,
+        "The format generator can't be used to pseudonymize values"
+    );
+    assert_eq!(error[1].path, "transform.0.generator");
+    assert_eq!(
+        error[2].message,
+        "keepLast and luhn can only be used without a generator"
+    );
+    assert_eq!(error[2].path, "transform.0");
+}
+
+#[test]
+fn test_validate_pseudonymize_date_time_without_to() {
+    let schema = schema!({
+        "type": "string",
+        "value": "1990-05-17",
+        "transform": [
+            {
+                "type": "pseudonymize",
+                "salt": "secret",
+                "generator": {
+                    "type": "dateTime",
+                    "from": "1970-01-01T00:00:00Z"
+                }
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0].message,
+        "The dateTime generator requires 'to' to pseudonymize values"
+    );
+    assert_eq!(error[0].path, "transform.0.generator");
+}
+
+#[test]
+fn test_validate_invalid_merge() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {},
+        "transform": [
+            {
+                "type": "merge",
+                "value": "test"
+            },
+            {
+                "type": "merge",
+                "value": 1
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0].message,
+        "The value must be a reference or a schema"
+    );
+    assert_eq!(error[0].path, "transform.0.value");
+    assert_eq!(error[1].path, "transform.1.value");
+}
+
+#[test]
+fn test_validate_invalid_json_patch() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {},
+        "transform": [
+            {
+                "type": "jsonPatch",
+                "operations": [
+                    {"op": "add", "path": "name", "value": 1},
+                    {"op": "remove", "path": ""},
+                    {"op": "test", "path": "/a~b", "value": 1},
+                    {"op": "move", "from": "/a", "path": "/a/b"}
+                ]
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 4);
+    assert_eq!(
+        error[0].message,
+        "The JSON pointer must be empty or start with '/'"
+    );
+    assert_eq!(error[0].path, "transform.0.operations.0.path");
+    assert_eq!(error[1].message, "The whole value can't be removed");
+    assert_eq!(error[1].path, "transform.0.operations.1.path");
+    assert_eq!(
+        error[2].message,
+        "'~' must be escaped as '~0' in JSON pointers"
+    );
+    assert_eq!(error[2].path, "transform.0.operations.2.path");
+    assert_eq!(
+        error[3].message,
+        "A value can't be moved into itself or one of its children"
+    );
+    assert_eq!(error[3].path, "transform.0.operations.3");
+}
+
+#[test]
+fn test_validate_invalid_flatten_keys() {
+    let schema = schema!({
+        "type": "object",
+        "properties": {},
+        "transform": [
+            {
+                "type": "flattenKeys",
+                "separator": ""
+            },
+            {
+                "type": "unflattenKeys",
+                "separator": ""
+            }
+        ]
+    });
+
+    let result = schema.validate_root();
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(error[0].message, "The separator must not be empty");
+    assert_eq!(error[0].path, "transform.0.separator");
+    assert_eq!(error[1].path, "transform.1.separator");
+}
@@ -0,0 +1,378 @@
+use indexmap::IndexMap;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Group an array of objects by one or more keys
+/// and compute aggregated values for each group.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct AggregateTransform {
+    /// The paths of the keys to group by.
+    /// Nested keys are separated by dots, e.g. `address.city`.
+    /// If empty, all elements are put into a single group.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub group_by: Vec<String>,
+    /// The values to compute for each group, keyed by the name of the output key.
+    pub aggregations: IndexMap<String, Aggregation>,
+    /// The format of the result.
+    /// Defaults to an array of objects.
+    pub output: Option<AggregateOutput>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct Aggregation {
+    /// The function to compute.
+    pub function: AggregateFunction,
+    /// The path of the key to aggregate.
+    /// Required for all functions except `count`.
+    /// If set for `count`, only non-null values are counted.
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum AggregateFunction {
+    /// The number of elements in the group.
+    Count,
+    /// The sum of all numbers.
+    Sum,
+    /// The average of all numbers.
+    Avg,
+    /// The smallest number or string.
+    Min,
+    /// The largest number or string.
+    Max,
+    /// All values as an array.
+    Collect,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum AggregateOutput {
+    /// An array of objects containing the grouped keys
+    /// and the aggregated values.
+    #[default]
+    Array,
+    /// An object keyed by the values of the grouped keys.
+    /// If grouped by multiple keys, the objects are nested.
+    Object,
+}
+
+impl AggregateFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AggregateFunction::Count => "count",
+            AggregateFunction::Sum => "sum",
+            AggregateFunction::Avg => "avg",
+            AggregateFunction::Min => "min",
+            AggregateFunction::Max => "max",
+            AggregateFunction::Collect => "collect",
+        }
+    }
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::aggregate::{
+        AggregateFunction, AggregateOutput, AggregateTransform, Aggregation,
+    };
+    use crate::util::key_path::get_key_path;
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::anyhow;
+    use indexmap::IndexMap;
+    use std::cmp::Ordering;
+    use std::sync::Arc;
+
+    type Group = Vec<Arc<GeneratedSchema>>;
+    /// The groups keyed by their serialized keys,
+    /// containing the values of the grouped keys and the elements of the group.
+    type Groups = IndexMap<String, (Vec<Arc<GeneratedSchema>>, Group)>;
+
+    fn get_path(value: &Arc<GeneratedSchema>, path: &str) -> Arc<GeneratedSchema> {
+        get_key_path(value, path)
+            .cloned()
+            .unwrap_or_else(|_| GeneratedSchema::None.into())
+    }
+
+    fn group_key(value: &Arc<GeneratedSchema>) -> anyhow::Result<String> {
+        match value.as_ref() {
+            GeneratedSchema::String(str) => Ok(str.clone()),
+            value => Ok(serde_json::to_string(value)?),
+        }
+    }
+
+    fn compare(a: &GeneratedSchema, b: &GeneratedSchema) -> anyhow::Result<Ordering> {
+        match (a, b) {
+            (GeneratedSchema::Integer(a), GeneratedSchema::Integer(b)) => Ok(a.cmp(b)),
+            (GeneratedSchema::String(a), GeneratedSchema::String(b)) => Ok(a.cmp(b)),
+            (a, b) => match (as_f64(a), as_f64(b)) {
+                (Some(a), Some(b)) => Ok(a.total_cmp(&b)),
+                _ => Err(anyhow!(
+                    "Cannot compare values of type {} and {}",
+                    a.name(),
+                    b.name()
+                )),
+            },
+        }
+    }
+
+    fn as_f64(value: &GeneratedSchema) -> Option<f64> {
+        match value {
+            GeneratedSchema::Integer(i) => Some(*i as f64),
+            GeneratedSchema::Number(n) => Some(n.0),
+            _ => None,
+        }
+    }
+
+    impl Aggregation {
+        fn compute(&self, group: &Group) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let values = match &self.key {
+                Some(key) => group.iter().map(|value| get_path(value, key)).collect(),
+                None if self.function == AggregateFunction::Count => group.clone(),
+                None => {
+                    return Err(anyhow!(
+                        "A key is required for the '{}' function",
+                        self.function.name()
+                    ))
+                }
+            };
+            let non_null = values
+                .iter()
+                .filter(|value| value.as_ref() != &GeneratedSchema::None);
+
+            Ok(match self.function {
+                AggregateFunction::Count => {
+                    GeneratedSchema::Integer(non_null.count() as i64).into()
+                }
+                AggregateFunction::Collect => GeneratedSchema::Array(values).into(),
+                AggregateFunction::Sum | AggregateFunction::Avg => {
+                    let mut integer_sum = Some(0i64);
+                    let mut sum = 0f64;
+                    let mut count = 0usize;
+                    for value in non_null {
+                        let number = as_f64(value).ok_or(anyhow!(
+                            "Only numbers can be aggregated using '{}'. Actual type was {}",
+                            self.function.name(),
+                            value.name()
+                        ))?;
+
+                        integer_sum = match value.as_ref() {
+                            GeneratedSchema::Integer(i) => {
+                                integer_sum.and_then(|sum| sum.checked_add(*i))
+                            }
+                            _ => None,
+                        };
+                        sum += number;
+                        count += 1;
+                    }
+
+                    match (self.function, integer_sum) {
+                        (AggregateFunction::Avg, _) if count == 0 => GeneratedSchema::None,
+                        (AggregateFunction::Avg, _) => {
+                            GeneratedSchema::Number((sum / count as f64).into())
+                        }
+                        (_, Some(sum)) => GeneratedSchema::Integer(sum),
+                        (_, None) => GeneratedSchema::Number(sum.into()),
+                    }
+                    .into()
+                }
+                AggregateFunction::Min | AggregateFunction::Max => {
+                    let mut res: Option<&Arc<GeneratedSchema>> = None;
+                    for value in non_null {
+                        res = match res {
+                            Some(current) => {
+                                let ord = compare(value, current)?;
+                                if (self.function == AggregateFunction::Min && ord.is_lt())
+                                    || (self.function == AggregateFunction::Max && ord.is_gt())
+                                {
+                                    Some(value)
+                                } else {
+                                    Some(current)
+                                }
+                            }
+                            None => Some(value),
+                        };
+                    }
+
+                    res.cloned().unwrap_or(GeneratedSchema::None.into())
+                }
+            })
+        }
+    }
+
+    impl AggregateTransform {
+        fn aggregate(
+            &self,
+            group: &Group,
+        ) -> anyhow::Result<IndexMap<String, Arc<GeneratedSchema>>> {
+            self.aggregations
+                .iter()
+                .map(|(name, aggregation)| Ok((name.clone(), aggregation.compute(group)?)))
+                .collect()
+        }
+
+        fn group(&self, array: &[Arc<GeneratedSchema>]) -> anyhow::Result<Groups> {
+            let mut groups: Groups = IndexMap::new();
+            for value in array {
+                let keys = self
+                    .group_by
+                    .iter()
+                    .map(|path| get_path(value, path))
+                    .collect::<Vec<_>>();
+                let key = serde_json::to_string(&keys)?;
+
+                groups
+                    .entry(key)
+                    .or_insert_with(|| (keys, Vec::new()))
+                    .1
+                    .push(value.clone());
+            }
+
+            Ok(groups)
+        }
+
+        fn to_array(&self, groups: Groups) -> anyhow::Result<GeneratedSchema> {
+            Ok(GeneratedSchema::Array(
+                groups
+                    .into_values()
+                    .map(|(keys, group)| {
+                        let mut res = self
+                            .group_by
+                            .iter()
+                            .cloned()
+                            .zip(keys)
+                            .collect::<IndexMap<_, _>>();
+                        res.extend(self.aggregate(&group)?);
+
+                        Ok(GeneratedSchema::Object(res).into())
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+            ))
+        }
+
+        fn to_object(&self, groups: Groups) -> anyhow::Result<GeneratedSchema> {
+            let mut res = IndexMap::new();
+            for (keys, group) in groups.into_values() {
+                insert_nested(&mut res, &keys, self.aggregate(&group)?)?;
+            }
+
+            Ok(GeneratedSchema::Object(res))
+        }
+    }
+
+    fn insert_nested(
+        object: &mut IndexMap<String, Arc<GeneratedSchema>>,
+        keys: &[Arc<GeneratedSchema>],
+        values: IndexMap<String, Arc<GeneratedSchema>>,
+    ) -> anyhow::Result<()> {
+        // Without any keys to group by, the result is the aggregated object itself
+        let Some((first, rest)) = keys.split_first() else {
+            *object = values;
+            return Ok(());
+        };
+
+        let key = group_key(first)?;
+        if rest.is_empty() {
+            object.insert(key, GeneratedSchema::Object(values).into());
+            return Ok(());
+        }
+
+        let nested = object
+            .entry(key)
+            .or_insert_with(|| GeneratedSchema::Object(IndexMap::new()).into());
+        let mut inner = match nested.as_ref() {
+            GeneratedSchema::Object(inner) => inner.clone(),
+            _ => IndexMap::new(),
+        };
+        insert_nested(&mut inner, rest, values)?;
+        *nested = GeneratedSchema::Object(inner).into();
+
+        Ok(())
+    }
+
+    impl TransformTrait for AggregateTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let array = match value.as_ref() {
+                GeneratedSchema::Array(array) => array,
+                GeneratedSchema::None => return Ok(value),
+                invalid => {
+                    return Err(anyhow!(
+                        "aggregate can only be applied to arrays. Actual type was {}",
+                        invalid.name(),
+                    )
+                    .context(anyhow!("Invalid schema at {}", schema.path()?)))
+                }
+            };
+
+            let res = self
+                .group(array)
+                .and_then(|groups| match self.output.unwrap_or_default() {
+                    AggregateOutput::Array => self.to_array(groups),
+                    AggregateOutput::Object => self.to_object(groups),
+                });
+
+            match res {
+                Ok(res) => Ok(res.into()),
+                Err(e) => Err(e.context(anyhow!("Invalid schema at {}", schema.path()?))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::aggregate::{AggregateFunction, AggregateTransform};
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    impl Validate for AggregateTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                !self.aggregations.is_empty(),
+                "At least one aggregation is required",
+                &path.append_single("aggregations"),
+            )
+            .with(self.group_by.iter(), |i, key| {
+                ValidationResult::ensure(
+                    !key.is_empty(),
+                    "The key must not be empty",
+                    &path.append("groupBy", i),
+                )
+            })
+            .with(self.aggregations.iter(), |_, (name, aggregation)| {
+                ValidationResult::ensure(
+                    aggregation.function == AggregateFunction::Count || aggregation.key.is_some(),
+                    format!(
+                        "A key is required for the '{}' function",
+                        aggregation.function.name()
+                    ),
+                    &path.append("aggregations", name),
+                )
+            })
+        }
+    }
+}
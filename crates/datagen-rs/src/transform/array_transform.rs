@@ -0,0 +1,272 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Select random elements from an array.
+/// The selected elements keep their original order.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SampleTransform {
+    /// The number of elements to select.
+    /// If the array has fewer elements, all elements are kept.
+    pub n: Option<usize>,
+    /// The fraction of elements to select, between 0 and 1.
+    /// The number of elements is rounded to the nearest integer.
+    pub fraction: Option<f64>,
+}
+
+/// Get a part of an array.
+/// Negative indices are counted from the end of the array.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SliceTransform {
+    /// The index of the first element to keep.
+    /// Defaults to 0.
+    pub start: Option<i64>,
+    /// The index after the last element to keep.
+    /// Defaults to the length of the array.
+    pub end: Option<i64>,
+}
+
+/// Keep the first `n` elements of an array.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct TakeTransform {
+    /// The number of elements to keep.
+    pub n: usize,
+}
+
+/// Remove the first `n` elements of an array.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SkipTransform {
+    /// The number of elements to remove.
+    pub n: usize,
+}
+
+/// Split an array into arrays of a fixed size.
+/// The last chunk may contain fewer elements.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ChunkTransform {
+    /// The number of elements in each chunk.
+    pub size: usize,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::array_transform::{
+        ChunkTransform, SampleTransform, SkipTransform, SliceTransform, TakeTransform,
+    };
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::anyhow;
+    use rand::seq::{index, SliceRandom};
+    use std::sync::Arc;
+
+    fn transform_array<F>(
+        name: &str,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+        func: F,
+    ) -> anyhow::Result<Arc<GeneratedSchema>>
+    where
+        F: FnOnce(&[Arc<GeneratedSchema>]) -> Vec<Arc<GeneratedSchema>>,
+    {
+        match value.as_ref() {
+            GeneratedSchema::Array(array) => Ok(GeneratedSchema::Array(func(array)).into()),
+            GeneratedSchema::None => Ok(value),
+            invalid => Err(anyhow!(
+                "{name} can only be applied to arrays. Actual type was {}",
+                invalid.name(),
+            )
+            .context(anyhow!("Invalid schema at {}", schema.path()?))),
+        }
+    }
+
+    /// Convert a possibly negative index into an index into an array of length `len`.
+    fn resolve_index(index: i64, len: usize) -> usize {
+        if index < 0 {
+            len.saturating_sub(index.unsigned_abs() as usize)
+        } else {
+            (index as usize).min(len)
+        }
+    }
+
+    pub(crate) fn shuffle(
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        let mut rng = schema.rng();
+        transform_array("shuffle", schema, value, |array| {
+            let mut array = array.to_vec();
+            array.shuffle(&mut rng);
+            array
+        })
+    }
+
+    pub(crate) fn reverse(
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        transform_array("reverse", schema, value, |array| {
+            array.iter().rev().cloned().collect()
+        })
+    }
+
+    impl TransformTrait for SampleTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let mut rng = schema.rng();
+            transform_array("sample", schema, value, |array| {
+                let amount = match (self.n, self.fraction) {
+                    (Some(n), _) => n,
+                    (None, Some(fraction)) => (array.len() as f64 * fraction).round() as usize,
+                    (None, None) => array.len(),
+                }
+                .min(array.len());
+
+                let mut indices = index::sample(&mut rng, array.len(), amount).into_vec();
+                indices.sort_unstable();
+                indices.into_iter().map(|i| array[i].clone()).collect()
+            })
+        }
+    }
+
+    impl TransformTrait for SliceTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_array("slice", schema, value, |array| {
+                let start = resolve_index(self.start.unwrap_or(0), array.len());
+                let end = self
+                    .end
+                    .map_or(array.len(), |end| resolve_index(end, array.len()));
+
+                array
+                    .get(start..end.max(start))
+                    .unwrap_or_default()
+                    .to_vec()
+            })
+        }
+    }
+
+    impl TransformTrait for TakeTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_array("take", schema, value, |array| {
+                array.iter().take(self.n).cloned().collect()
+            })
+        }
+    }
+
+    impl TransformTrait for SkipTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_array("skip", schema, value, |array| {
+                array.iter().skip(self.n).cloned().collect()
+            })
+        }
+    }
+
+    impl TransformTrait for ChunkTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            if self.size == 0 {
+                return Err(anyhow!("The chunk size must be greater than zero")
+                    .context(anyhow!("Invalid schema at {}", schema.path()?)));
+            }
+
+            transform_array("chunk", schema, value, |array| {
+                array
+                    .chunks(self.size)
+                    .map(|chunk| GeneratedSchema::Array(chunk.to_vec()).into())
+                    .collect()
+            })
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::array_transform::{ChunkTransform, SampleTransform, SliceTransform};
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    impl Validate for SampleTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.n.is_some() != self.fraction.is_some(),
+                "Exactly one of n and fraction must be set",
+                path,
+            )
+            .concat(ValidationResult::ensure(
+                !matches!(self.fraction, Some(fraction) if !(0.0..=1.0).contains(&fraction)),
+                "fraction must be between 0 and 1",
+                &path.append_single("fraction"),
+            ))
+        }
+    }
+
+    impl Validate for SliceTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                !matches!((self.start, self.end), (Some(start), Some(end)) if (start >= 0) == (end >= 0) && start > end),
+                "start must be less than or equal to end",
+                path,
+            )
+        }
+    }
+
+    impl Validate for ChunkTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.size > 0,
+                "The size must be greater than zero",
+                &path.append_single("size"),
+            )
+        }
+    }
+}
@@ -17,8 +17,6 @@ pub struct ChooseFromArray {
 pub mod generate {
     use std::sync::Arc;
 
-    use rand::thread_rng;
-
     use crate::{
         generate::{datagen_context::DatagenContextRef, generated_schema::GeneratedSchema},
         util::traits::generate::TransformTrait,
@@ -62,7 +60,7 @@ pub mod generate {
                         }
                     }
                     None => {
-                        let mut rng = thread_rng();
+                        let mut rng = schema.rng();
                         Ok(array
                             .choose(&mut rng)
                             .map(Clone::clone)
@@ -0,0 +1,348 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// What to do with values which can't be converted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum OnInvalid {
+    /// Replace the value with null.
+    Null,
+    /// Fail with an error containing the path of the schema.
+    #[default]
+    Error,
+}
+
+/// The unit of a unix timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum TimestampUnit {
+    #[default]
+    Seconds,
+    Milliseconds,
+}
+
+/// Convert a value to an integer, a number or a bool.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CoerceTransform {
+    /// What to do with values which can't be converted.
+    /// Defaults to an error.
+    pub on_invalid: Option<OnInvalid>,
+}
+
+/// Parse a date string into a unix timestamp.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ParseDateTransform {
+    /// The strftime format of the date string, e.g. `%d.%m.%Y`.
+    /// Dates without a timezone are treated as UTC.
+    /// If not set, the date must be in RFC 3339 format.
+    pub format: Option<String>,
+    /// The unit of the resulting timestamp.
+    /// Defaults to seconds.
+    pub unit: Option<TimestampUnit>,
+    /// What to do with values which can't be parsed.
+    /// Defaults to an error.
+    pub on_invalid: Option<OnInvalid>,
+}
+
+/// Format a unix timestamp or a date string.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct FormatDateTransform {
+    /// The strftime format of the resulting string, e.g. `%d.%m.%Y`.
+    /// If not set, the date is formatted in RFC 3339 format.
+    pub format: Option<String>,
+    /// The strftime format used to parse string values.
+    /// If not set, strings must be in RFC 3339 format.
+    pub input_format: Option<String>,
+    /// The unit of timestamp values.
+    /// Defaults to seconds.
+    pub unit: Option<TimestampUnit>,
+    /// What to do with values which can't be parsed.
+    /// Defaults to an error.
+    pub on_invalid: Option<OnInvalid>,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::coerce::{
+        CoerceTransform, FormatDateTransform, OnInvalid, ParseDateTransform, TimestampUnit,
+    };
+    use crate::util::generate_error::GenerateError;
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::anyhow;
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+    use std::fmt::Write;
+    use std::sync::Arc;
+
+    fn coerce<F>(
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+        on_invalid: Option<OnInvalid>,
+        target: &str,
+        func: F,
+    ) -> anyhow::Result<Arc<GeneratedSchema>>
+    where
+        F: FnOnce(&GeneratedSchema) -> Option<GeneratedSchema>,
+    {
+        try_coerce(schema, value, on_invalid, target, |value| Ok(func(value)))
+    }
+
+    /// Like [`coerce`], but the conversion may fail with an error
+    /// which isn't caused by the value, like an invalid format.
+    fn try_coerce<F>(
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+        on_invalid: Option<OnInvalid>,
+        target: &str,
+        func: F,
+    ) -> anyhow::Result<Arc<GeneratedSchema>>
+    where
+        F: FnOnce(&GeneratedSchema) -> anyhow::Result<Option<GeneratedSchema>>,
+    {
+        if value.as_ref() == &GeneratedSchema::None {
+            return Ok(value);
+        }
+
+        match func(value.as_ref())? {
+            Some(res) => Ok(res.into()),
+            None => match on_invalid.unwrap_or_default() {
+                OnInvalid::Null => Ok(GeneratedSchema::None.into()),
+                OnInvalid::Error => Err(GenerateError::new(
+                    &schema,
+                    &format!(
+                        "Cannot convert {} value {} to {target}",
+                        value.name(),
+                        serde_json::to_string(&value)?
+                    ),
+                )
+                .into()),
+            },
+        }
+    }
+
+    fn parse_date(value: &str, format: Option<&str>) -> Option<DateTime<Utc>> {
+        let Some(format) = format else {
+            return DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|date| date.to_utc());
+        };
+
+        DateTime::parse_from_str(value, format)
+            .map(|date| date.to_utc())
+            .or_else(|_| NaiveDateTime::parse_from_str(value, format).map(|date| date.and_utc()))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(value, format)
+                    .map(|date| date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+            })
+            .ok()
+    }
+
+    fn from_timestamp(value: i64, unit: Option<TimestampUnit>) -> Option<DateTime<Utc>> {
+        match unit.unwrap_or_default() {
+            TimestampUnit::Seconds => DateTime::from_timestamp(value, 0),
+            TimestampUnit::Milliseconds => DateTime::from_timestamp_millis(value),
+        }
+    }
+
+    pub(crate) fn to_integer(
+        transform: CoerceTransform,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        coerce(
+            schema,
+            value,
+            transform.on_invalid,
+            "Integer",
+            |value| match value {
+                GeneratedSchema::Integer(i) => Some(GeneratedSchema::Integer(*i)),
+                GeneratedSchema::Number(n) if n.fract() == 0.0 => {
+                    Some(GeneratedSchema::Integer(n.0 as i64))
+                }
+                GeneratedSchema::Bool(b) => Some(GeneratedSchema::Integer(*b as i64)),
+                GeneratedSchema::String(str) => {
+                    let str = str.trim();
+                    str.parse::<i64>()
+                        .ok()
+                        .or_else(|| {
+                            str.parse::<f64>()
+                                .ok()
+                                .filter(|n| n.fract() == 0.0)
+                                .map(|n| n as i64)
+                        })
+                        .map(GeneratedSchema::Integer)
+                }
+                _ => None,
+            },
+        )
+    }
+
+    pub(crate) fn to_number(
+        transform: CoerceTransform,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        coerce(
+            schema,
+            value,
+            transform.on_invalid,
+            "Number",
+            |value| match value {
+                GeneratedSchema::Integer(i) => Some(GeneratedSchema::Number((*i as f64).into())),
+                GeneratedSchema::Number(n) => Some(GeneratedSchema::Number(*n)),
+                GeneratedSchema::Bool(b) => Some(GeneratedSchema::Number((*b as u8 as f64).into())),
+                GeneratedSchema::String(str) => str
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|n| n.is_finite())
+                    .map(|n| GeneratedSchema::Number(n.into())),
+                _ => None,
+            },
+        )
+    }
+
+    pub(crate) fn to_bool(
+        transform: CoerceTransform,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        coerce(
+            schema,
+            value,
+            transform.on_invalid,
+            "Bool",
+            |value| match value {
+                GeneratedSchema::Bool(b) => Some(GeneratedSchema::Bool(*b)),
+                GeneratedSchema::Integer(0) => Some(GeneratedSchema::Bool(false)),
+                GeneratedSchema::Integer(1) => Some(GeneratedSchema::Bool(true)),
+                GeneratedSchema::String(str) => match str.trim().to_lowercase().as_str() {
+                    "true" | "1" | "yes" | "y" | "on" => Some(GeneratedSchema::Bool(true)),
+                    "false" | "0" | "no" | "n" | "off" => Some(GeneratedSchema::Bool(false)),
+                    _ => None,
+                },
+                _ => None,
+            },
+        )
+    }
+
+    impl TransformTrait for ParseDateTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            coerce(schema, value, self.on_invalid, "a date", |value| {
+                let GeneratedSchema::String(str) = value else {
+                    return None;
+                };
+
+                let date = parse_date(str.trim(), self.format.as_deref())?;
+                Some(GeneratedSchema::Integer(
+                    match self.unit.unwrap_or_default() {
+                        TimestampUnit::Seconds => date.timestamp(),
+                        TimestampUnit::Milliseconds => date.timestamp_millis(),
+                    },
+                ))
+            })
+        }
+    }
+
+    impl TransformTrait for FormatDateTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            try_coerce(schema, value, self.on_invalid, "a date", |value| {
+                let date = match value {
+                    GeneratedSchema::Integer(i) => from_timestamp(*i, self.unit),
+                    GeneratedSchema::Number(n) => from_timestamp(n.0 as i64, self.unit),
+                    GeneratedSchema::String(str) => {
+                        parse_date(str.trim(), self.input_format.as_deref())
+                    }
+                    _ => None,
+                };
+                let Some(date) = date else {
+                    return Ok(None);
+                };
+
+                let res = match &self.format {
+                    Some(format) => {
+                        let mut res = String::new();
+                        write!(res, "{}", date.format(format))
+                            .map_err(|_| anyhow!("Invalid date format '{format}'"))?;
+
+                        res
+                    }
+                    None => date.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                };
+
+                Ok(Some(GeneratedSchema::String(res)))
+            })
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::coerce::{FormatDateTransform, ParseDateTransform};
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+    use chrono::format::StrftimeItems;
+
+    fn validate_format(format: &Option<String>, path: &ValidationPath) -> ValidationResult {
+        let Some(format) = format else {
+            return Ok(());
+        };
+
+        ValidationResult::ensure(!format.is_empty(), "format must not be empty", path)?;
+        ValidationResult::ensure_ok(
+            StrftimeItems::new(format).parse(),
+            "invalid date format",
+            path,
+            Some(serde_json::Value::String(format.clone())),
+        )
+    }
+
+    impl Validate for ParseDateTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            validate_format(&self.format, &path.append_single("format"))
+        }
+    }
+
+    impl Validate for FormatDateTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            validate_format(&self.format, &path.append_single("format")).concat(validate_format(
+                &self.input_format,
+                &path.append_single("inputFormat"),
+            ))
+        }
+    }
+}
@@ -0,0 +1,334 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Randomly corrupt values to create data for data-quality testing.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CorruptTransform {
+    /// The probability that a value is corrupted, between 0 and 1.
+    /// Defaults to 0.1.
+    pub rate: Option<f64>,
+    /// The kinds of corruption to apply.
+    /// If a value is corrupted, one of the kinds which can be applied
+    /// to the value is chosen randomly, using the weights of the kinds.
+    /// Defaults to all kinds with equal weights.
+    pub kinds: Option<Vec<CorruptionKindOptions>>,
+    /// Whether to corrupt the values inside objects and arrays.
+    /// Defaults to true.
+    pub recursive: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum CorruptionKind {
+    /// Replace a random character of a string.
+    Typo,
+    /// Swap two adjacent characters of a string.
+    SwapChars,
+    /// Replace the value with a value of a different type.
+    WrongType,
+    /// Replace the value with an empty string.
+    EmptyString,
+    /// Replace a number with a number far outside of its original range.
+    OutOfRange,
+    /// Append whitespace to a string.
+    TrailingWhitespace,
+    /// Make an email address invalid.
+    /// Only applies to strings containing an `@`.
+    InvalidEmail,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(untagged, deny_unknown_fields))]
+pub enum CorruptionKindOptions {
+    Kind(CorruptionKind),
+    Weighted(WeightedCorruptionKind),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WeightedCorruptionKind {
+    /// The kind of corruption.
+    pub kind: CorruptionKind,
+    /// The relative weight of this kind.
+    /// Defaults to 1.
+    pub weight: Option<f64>,
+}
+
+impl CorruptionKind {
+    pub const ALL: [CorruptionKind; 7] = [
+        CorruptionKind::Typo,
+        CorruptionKind::SwapChars,
+        CorruptionKind::WrongType,
+        CorruptionKind::EmptyString,
+        CorruptionKind::OutOfRange,
+        CorruptionKind::TrailingWhitespace,
+        CorruptionKind::InvalidEmail,
+    ];
+}
+
+impl CorruptionKindOptions {
+    pub fn kind(&self) -> CorruptionKind {
+        match self {
+            CorruptionKindOptions::Kind(kind) => *kind,
+            CorruptionKindOptions::Weighted(weighted) => weighted.kind,
+        }
+    }
+
+    pub fn weight(&self) -> f64 {
+        match self {
+            CorruptionKindOptions::Kind(_) => 1.0,
+            CorruptionKindOptions::Weighted(weighted) => weighted.weight.unwrap_or(1.0),
+        }
+    }
+}
+
+impl CorruptTransform {
+    /// Get the kinds of corruption with their weights.
+    pub fn weighted_kinds(&self) -> Vec<(CorruptionKind, f64)> {
+        match &self.kinds {
+            Some(kinds) => kinds
+                .iter()
+                .map(|kind| (kind.kind(), kind.weight()))
+                .collect(),
+            None => CorruptionKind::ALL
+                .iter()
+                .map(|kind| (*kind, 1.0))
+                .collect(),
+        }
+    }
+
+    /// Whether this transform may change the type of a value.
+    pub fn changes_type(&self) -> bool {
+        self.weighted_kinds().iter().any(|(kind, _)| {
+            matches!(
+                kind,
+                CorruptionKind::WrongType | CorruptionKind::EmptyString
+            )
+        })
+    }
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::corrupt::{CorruptTransform, CorruptionKind};
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::anyhow;
+    use indexmap::IndexMap;
+    use rand::seq::SliceRandom;
+    use rand::Rng;
+    use std::sync::Arc;
+
+    impl CorruptionKind {
+        fn can_apply(&self, value: &GeneratedSchema) -> bool {
+            match (self, value) {
+                (CorruptionKind::Typo | CorruptionKind::TrailingWhitespace, value) => {
+                    matches!(value, GeneratedSchema::String(_))
+                }
+                (CorruptionKind::SwapChars, GeneratedSchema::String(str)) => {
+                    str.chars().count() >= 2
+                }
+                (CorruptionKind::InvalidEmail, GeneratedSchema::String(str)) => str.contains('@'),
+                (CorruptionKind::OutOfRange, value) => {
+                    matches!(
+                        value,
+                        GeneratedSchema::Integer(_) | GeneratedSchema::Number(_)
+                    )
+                }
+                (CorruptionKind::EmptyString, GeneratedSchema::String(str)) => !str.is_empty(),
+                (CorruptionKind::WrongType | CorruptionKind::EmptyString, value) => matches!(
+                    value,
+                    GeneratedSchema::String(_)
+                        | GeneratedSchema::Integer(_)
+                        | GeneratedSchema::Number(_)
+                        | GeneratedSchema::Bool(_)
+                ),
+                _ => false,
+            }
+        }
+
+        fn apply<R: Rng>(&self, value: &GeneratedSchema, rng: &mut R) -> GeneratedSchema {
+            match (self, value) {
+                (CorruptionKind::Typo, GeneratedSchema::String(str)) => {
+                    let mut chars = str.chars().collect::<Vec<_>>();
+                    let mut replacement = rng.gen_range(b'a'..=b'z') as char;
+                    if chars.is_empty() {
+                        chars.push(replacement);
+                    } else {
+                        let i = rng.gen_range(0..chars.len());
+                        // Make sure the string actually changes
+                        if chars[i] == replacement {
+                            replacement = if replacement == 'z' {
+                                'a'
+                            } else {
+                                (replacement as u8 + 1) as char
+                            };
+                        }
+                        chars[i] = replacement;
+                    }
+
+                    GeneratedSchema::String(chars.into_iter().collect())
+                }
+                (CorruptionKind::SwapChars, GeneratedSchema::String(str)) => {
+                    let mut chars = str.chars().collect::<Vec<_>>();
+                    let i = rng.gen_range(0..chars.len() - 1);
+                    chars.swap(i, i + 1);
+
+                    GeneratedSchema::String(chars.into_iter().collect())
+                }
+                (CorruptionKind::WrongType, GeneratedSchema::String(_)) => {
+                    GeneratedSchema::Integer(rng.gen_range(0..1000))
+                }
+                (CorruptionKind::WrongType, GeneratedSchema::Integer(i)) => {
+                    GeneratedSchema::String(i.to_string())
+                }
+                (CorruptionKind::WrongType, GeneratedSchema::Number(n)) => {
+                    GeneratedSchema::String(n.to_string())
+                }
+                (CorruptionKind::WrongType, GeneratedSchema::Bool(b)) => {
+                    GeneratedSchema::Integer(*b as i64)
+                }
+                (CorruptionKind::EmptyString, _) => GeneratedSchema::String(String::new()),
+                (CorruptionKind::OutOfRange, GeneratedSchema::Integer(i)) => {
+                    let offset = i.unsigned_abs().saturating_add(1).saturating_mul(1000);
+                    GeneratedSchema::Integer(if rng.gen_bool(0.5) {
+                        offset.min(i64::MAX as u64) as i64
+                    } else {
+                        -(offset.min(i64::MAX as u64) as i64)
+                    })
+                }
+                (CorruptionKind::OutOfRange, GeneratedSchema::Number(n)) => {
+                    let offset = (n.abs() + 1.0) * 1000.0;
+                    GeneratedSchema::Number(if rng.gen_bool(0.5) { offset } else { -offset }.into())
+                }
+                (CorruptionKind::TrailingWhitespace, GeneratedSchema::String(str)) => {
+                    let whitespace = [" ", "  ", "\t", "\n"].choose(rng).unwrap_or(&" ");
+                    GeneratedSchema::String(format!("{str}{whitespace}"))
+                }
+                (CorruptionKind::InvalidEmail, GeneratedSchema::String(str)) => {
+                    let (local, domain) = str.split_once('@').unwrap_or((str, ""));
+                    GeneratedSchema::String(match rng.gen_range(0..4) {
+                        0 => format!("{local}{domain}"),
+                        1 => format!("{local}@@{domain}"),
+                        2 => format!("{local}@"),
+                        _ => format!(
+                            "{local}@{}",
+                            domain.rsplit_once('.').map_or(domain, |(name, _)| name)
+                        ),
+                    })
+                }
+                (_, value) => value.clone(),
+            }
+        }
+    }
+
+    impl CorruptTransform {
+        fn corrupt<R: Rng>(
+            &self,
+            kinds: &[(CorruptionKind, f64)],
+            value: Arc<GeneratedSchema>,
+            rng: &mut R,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            match value.as_ref() {
+                GeneratedSchema::Object(obj) if self.recursive.unwrap_or(true) => {
+                    Ok(GeneratedSchema::Object(
+                        obj.iter()
+                            .map(|(key, value)| {
+                                Ok((key.clone(), self.corrupt(kinds, value.clone(), rng)?))
+                            })
+                            .collect::<anyhow::Result<IndexMap<_, _>>>()?,
+                    )
+                    .into())
+                }
+                GeneratedSchema::Array(arr) if self.recursive.unwrap_or(true) => {
+                    Ok(GeneratedSchema::Array(
+                        arr.iter()
+                            .map(|value| self.corrupt(kinds, value.clone(), rng))
+                            .collect::<anyhow::Result<Vec<_>>>()?,
+                    )
+                    .into())
+                }
+                inner => {
+                    if !rng.gen_bool(self.rate.unwrap_or(0.1).clamp(0.0, 1.0)) {
+                        return Ok(value);
+                    }
+
+                    let applicable = kinds
+                        .iter()
+                        .filter(|(kind, _)| kind.can_apply(inner))
+                        .collect::<Vec<_>>();
+                    if applicable.is_empty() {
+                        return Ok(value);
+                    }
+
+                    let (kind, _) = applicable
+                        .choose_weighted(rng, |(_, weight)| *weight)
+                        .map_err(|e| anyhow!(e).context("Invalid corruption weights"))?;
+                    Ok(kind.apply(inner, rng).into())
+                }
+            }
+        }
+    }
+
+    impl TransformTrait for CorruptTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let kinds = self.weighted_kinds();
+            match self.corrupt(&kinds, value, &mut schema.rng()) {
+                Ok(res) => Ok(res),
+                Err(e) => Err(e.context(anyhow!("Invalid schema at {}", schema.path()?))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::corrupt::CorruptTransform;
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    impl Validate for CorruptTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                !matches!(self.rate, Some(rate) if !(0.0..=1.0).contains(&rate)),
+                "rate must be between 0 and 1",
+                &path.append_single("rate"),
+            )
+            .concat(ValidationResult::ensure(
+                !matches!(&self.kinds, Some(kinds) if kinds.is_empty()),
+                "At least one corruption kind is required",
+                &path.append_single("kinds"),
+            ))
+            .with(self.kinds.iter().flatten(), |i, kind| {
+                ValidationResult::ensure(
+                    kind.weight() > 0.0 && kind.weight().is_finite(),
+                    "The weight must be greater than zero",
+                    &path.append("kinds", i),
+                )
+            })
+        }
+    }
+}
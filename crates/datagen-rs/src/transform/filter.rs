@@ -18,11 +18,17 @@ pub struct FilterTransform {
     pub field: Option<ReferenceOrString>,
     /// The operator which will be used to compare the value with the other value
     pub operator: FilterTransformOp,
-    /// The value which will be used to compare the value with
+    /// The value which will be used to compare the value with.
+    /// If this is a string starting with `ref:`, the referenced value will be used.
+    /// This is not used by the `and`, `or` and `not` operators.
+    #[cfg_attr(feature = "serialize", serde(default))]
     pub other: GeneratedSchema,
+    /// The filters which will be combined using the `and`, `or` or `not` operator.
+    /// Each filter may compare a different field.
+    pub filters: Option<Vec<FilterTransform>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
@@ -31,6 +37,35 @@ pub enum FilterTransformOp {
     Equals,
     /// The value must not be equal to the other value
     NotEquals,
+    /// The value must be greater than the other value
+    GreaterThan,
+    /// The value must be greater than or equal to the other value
+    GreaterThanOrEquals,
+    /// The value must be less than the other value
+    LessThan,
+    /// The value must be less than or equal to the other value
+    LessThanOrEquals,
+    /// The value must be between the two values in the other array (inclusive)
+    Between,
+    /// The value must be contained in the other array
+    In,
+    /// The value must not be contained in the other array
+    NotIn,
+    /// The string must contain the other string,
+    /// or the array must contain the other value
+    Contains,
+    /// The string must start with the other string
+    StartsWith,
+    /// The string must end with the other string
+    EndsWith,
+    /// The string must match the regex pattern in the other value
+    Matches,
+    /// All filters must match
+    And,
+    /// At least one filter must match
+    Or,
+    /// None of the filters must match
+    Not,
 }
 
 #[cfg(feature = "map-schema")]
@@ -39,27 +74,258 @@ pub mod generate {
     use crate::generate::generated_schema::GeneratedSchema;
     use crate::transform::filter::{FilterTransform, FilterTransformOp};
     use crate::util::traits::generate::{ResolveRef, TransformTrait};
+    use anyhow::{anyhow, bail};
     use indexmap::IndexMap;
+    use regex::Regex;
+    use std::cmp::Ordering;
+    use std::collections::hash_map::Entry;
+    use std::collections::HashMap;
     use std::sync::Arc;
 
+    /// The compiled patterns of the `matches` operator, keyed by their source.
+    /// Shared between all values filtered by a single transform call,
+    /// so every pattern is only compiled once.
+    type Regexes = HashMap<String, Regex>;
+
+    impl FilterTransform {
+        fn uses_fields(&self) -> bool {
+            self.field.is_some()
+                || self
+                    .filters
+                    .iter()
+                    .flatten()
+                    .any(FilterTransform::uses_fields)
+        }
+
+        fn resolve_other(
+            &self,
+            schema: &DatagenContextRef,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            match (&self.other, self.operator) {
+                // Compare with all referenced values instead of a random one
+                (
+                    GeneratedSchema::String(other),
+                    FilterTransformOp::In | FilterTransformOp::NotIn,
+                ) if other.starts_with("ref:") => Ok(GeneratedSchema::Array(
+                    schema
+                        .resolve_ref(other)?
+                        .into_vec()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .flat_map(|value| match value.as_ref() {
+                            GeneratedSchema::Array(values) => values.clone(),
+                            _ => vec![value],
+                        })
+                        .collect(),
+                )
+                .into()),
+                (GeneratedSchema::String(other), _) if other.starts_with("ref:") => {
+                    other.clone().resolve_ref(schema)
+                }
+                (other, _) => Ok(other.clone().into()),
+            }
+        }
+
+        pub(crate) fn matches(
+            &self,
+            schema: &DatagenContextRef,
+            value: &Arc<GeneratedSchema>,
+        ) -> anyhow::Result<bool> {
+            self.matches_with(schema, value, &mut Regexes::new())
+        }
+
+        fn matches_with(
+            &self,
+            schema: &DatagenContextRef,
+            value: &Arc<GeneratedSchema>,
+            regexes: &mut Regexes,
+        ) -> anyhow::Result<bool> {
+            let filters = self.filters.iter().flatten();
+
+            match self.operator {
+                FilterTransformOp::And => {
+                    for filter in filters {
+                        if !filter.matches_with(schema, value, regexes)? {
+                            return Ok(false);
+                        }
+                    }
+
+                    Ok(true)
+                }
+                FilterTransformOp::Or => {
+                    for filter in filters {
+                        if filter.matches_with(schema, value, regexes)? {
+                            return Ok(true);
+                        }
+                    }
+
+                    Ok(false)
+                }
+                FilterTransformOp::Not => {
+                    for filter in filters {
+                        if filter.matches_with(schema, value, regexes)? {
+                            return Ok(false);
+                        }
+                    }
+
+                    Ok(true)
+                }
+                operator => {
+                    let current = match self.field.clone() {
+                        Some(field) => field.resolve_ref(schema)?,
+                        None => value.clone(),
+                    };
+
+                    operator.compare(&current, &*self.resolve_other(schema)?, regexes)
+                }
+            }
+        }
+    }
+
+    impl FilterTransformOp {
+        fn compare(
+            &self,
+            value: &GeneratedSchema,
+            other: &GeneratedSchema,
+            regexes: &mut Regexes,
+        ) -> anyhow::Result<bool> {
+            Ok(match self {
+                // Equality is strict, so integers never equal numbers
+                FilterTransformOp::Equals => value == other,
+                FilterTransformOp::NotEquals => value != other,
+                FilterTransformOp::GreaterThan => {
+                    compare_values(value, other) == Some(Ordering::Greater)
+                }
+                FilterTransformOp::GreaterThanOrEquals => matches!(
+                    compare_values(value, other),
+                    Some(Ordering::Greater | Ordering::Equal)
+                ),
+                FilterTransformOp::LessThan => compare_values(value, other) == Some(Ordering::Less),
+                FilterTransformOp::LessThanOrEquals => matches!(
+                    compare_values(value, other),
+                    Some(Ordering::Less | Ordering::Equal)
+                ),
+                FilterTransformOp::Between => match other {
+                    GeneratedSchema::Array(range) if range.len() == 2 => {
+                        FilterTransformOp::GreaterThanOrEquals.compare(value, &range[0], regexes)?
+                            && FilterTransformOp::LessThanOrEquals
+                                .compare(value, &range[1], regexes)?
+                    }
+                    _ => bail!(
+                        "The 'between' operator requires an array with two values, got '{other}'"
+                    ),
+                },
+                FilterTransformOp::In | FilterTransformOp::NotIn => {
+                    // A reference may resolve to a single value
+                    let contained = match other {
+                        GeneratedSchema::Array(values) => {
+                            values.iter().any(|other| values_equal(value, other))
+                        }
+                        other => values_equal(value, other),
+                    };
+
+                    contained == matches!(self, FilterTransformOp::In)
+                }
+                FilterTransformOp::Contains => match (value, other) {
+                    (GeneratedSchema::String(value), GeneratedSchema::String(other)) => {
+                        value.contains(other)
+                    }
+                    (GeneratedSchema::Array(values), other) => {
+                        values.iter().any(|value| values_equal(value, other))
+                    }
+                    (GeneratedSchema::Object(obj), GeneratedSchema::String(key)) => {
+                        obj.contains_key(key)
+                    }
+                    _ => false,
+                },
+                FilterTransformOp::StartsWith => match (value, other) {
+                    (GeneratedSchema::String(value), GeneratedSchema::String(other)) => {
+                        value.starts_with(other)
+                    }
+                    _ => false,
+                },
+                FilterTransformOp::EndsWith => match (value, other) {
+                    (GeneratedSchema::String(value), GeneratedSchema::String(other)) => {
+                        value.ends_with(other)
+                    }
+                    _ => false,
+                },
+                FilterTransformOp::Matches => {
+                    let GeneratedSchema::String(pattern) = other else {
+                        bail!(
+                            "The 'matches' operator requires a regex pattern, got {}",
+                            other.name()
+                        );
+                    };
+
+                    let GeneratedSchema::String(value) = value else {
+                        return Ok(false);
+                    };
+
+                    let regex = match regexes.entry(pattern.clone()) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => entry.insert(
+                            Regex::new(pattern)
+                                .map_err(|e| anyhow!(e).context("Invalid regex pattern"))?,
+                        ),
+                    };
+
+                    regex.is_match(value)
+                }
+                FilterTransformOp::And | FilterTransformOp::Or | FilterTransformOp::Not => {
+                    bail!("The 'and', 'or' and 'not' operators can only be used with filters")
+                }
+            })
+        }
+    }
+
+    fn as_f64(value: &GeneratedSchema) -> Option<f64> {
+        match value {
+            GeneratedSchema::Number(number) => Some(number.0),
+            GeneratedSchema::Integer(integer) => Some(*integer as f64),
+            _ => None,
+        }
+    }
+
+    /// Compare two values, treating integers and numbers with the same value as equal.
+    fn values_equal(value: &GeneratedSchema, other: &GeneratedSchema) -> bool {
+        match (as_f64(value), as_f64(other)) {
+            (Some(value), Some(other)) => value == other,
+            _ => value == other,
+        }
+    }
+
+    fn compare_values(value: &GeneratedSchema, other: &GeneratedSchema) -> Option<Ordering> {
+        match (value, other) {
+            (GeneratedSchema::Integer(value), GeneratedSchema::Integer(other)) => {
+                Some(value.cmp(other))
+            }
+            (GeneratedSchema::String(value), GeneratedSchema::String(other)) => {
+                Some(value.cmp(other))
+            }
+            _ => as_f64(value)?.partial_cmp(&as_f64(other)?),
+        }
+    }
+
     impl TransformTrait for FilterTransform {
         fn transform(
             self,
             schema: DatagenContextRef,
             value: Arc<GeneratedSchema>,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            if let Some(current) = self
-                .field
-                .clone()
-                .map(|reference| reference.resolve_ref(&schema))
-                .map_or(Ok(None), |res| res.map(Some))?
-            {
-                let matches = match self.operator {
-                    FilterTransformOp::Equals => *current == self.other,
-                    FilterTransformOp::NotEquals => *current != self.other,
-                };
+            self.filter(&schema, value, &mut Regexes::new())
+        }
+    }
 
-                return if matches {
+    impl FilterTransform {
+        fn filter(
+            &self,
+            schema: &DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+            regexes: &mut Regexes,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            if self.uses_fields() {
+                return if self.matches_with(schema, &value, regexes)? {
                     Ok(value)
                 } else {
                     Ok(GeneratedSchema::None.into())
@@ -69,28 +335,20 @@ pub mod generate {
             match value.as_ref() {
                 GeneratedSchema::Array(arr) => Ok(GeneratedSchema::Array(
                     arr.iter()
-                        .map(|e| self.clone().transform(schema.clone(), e.clone()))
+                        .map(|e| self.filter(schema, e.clone(), regexes))
                         .collect::<anyhow::Result<Vec<_>>>()?,
                 )
                 .into()),
                 GeneratedSchema::Object(obj) => Ok(GeneratedSchema::Object(
                     obj.iter()
                         .map(|(key, val)| {
-                            Ok((
-                                key.clone(),
-                                self.clone().transform(schema.clone(), val.clone())?,
-                            ))
+                            Ok((key.clone(), self.filter(schema, val.clone(), regexes)?))
                         })
                         .collect::<anyhow::Result<IndexMap<_, _>>>()?,
                 )
                 .into()),
-                rest => {
-                    let matches = match self.operator {
-                        FilterTransformOp::Equals => *rest == self.other,
-                        FilterTransformOp::NotEquals => *rest != self.other,
-                    };
-
-                    if matches {
+                _ => {
+                    if self.matches_with(schema, &value, regexes)? {
                         Ok(value)
                     } else {
                         Ok(GeneratedSchema::None.into())
@@ -103,14 +361,90 @@ pub mod generate {
 
 #[cfg(feature = "validate-schema")]
 pub mod validate {
-    use crate::transform::filter::FilterTransform;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::filter::{FilterTransform, FilterTransformOp};
     use crate::validation::path::ValidationPath;
-    use crate::validation::result::ValidationResult;
+    use crate::validation::result::{IterValidate, ValidationResult};
     use crate::validation::validate::Validate;
+    use serde_json::Value;
+
+    impl FilterTransform {
+        fn validate_other(&self, path: &ValidationPath) -> ValidationResult {
+            let path = path.append_single("other");
+            let invalid_value = || serde_json::to_value(&self.other).ok();
+
+            match (self.operator, &self.other) {
+                (_, GeneratedSchema::String(other)) if other.starts_with("ref:") => Ok(()),
+                (FilterTransformOp::Between, GeneratedSchema::Array(range)) if range.len() == 2 => {
+                    Ok(())
+                }
+                (FilterTransformOp::Between, _) => ValidationResult::single(
+                    "The 'between' operator requires an array with two values",
+                    &path,
+                    None,
+                    invalid_value(),
+                ),
+                (FilterTransformOp::In | FilterTransformOp::NotIn, GeneratedSchema::Array(_)) => {
+                    Ok(())
+                }
+                (FilterTransformOp::In | FilterTransformOp::NotIn, _) => ValidationResult::single(
+                    "The 'in' and 'notIn' operators require an array or a reference",
+                    &path,
+                    None,
+                    invalid_value(),
+                ),
+                (FilterTransformOp::Matches, GeneratedSchema::String(pattern)) => {
+                    ValidationResult::ensure_ok(
+                        regex::Regex::new(pattern),
+                        "invalid regex pattern",
+                        &path,
+                        Some(Value::String(pattern.clone())),
+                    )
+                }
+                (FilterTransformOp::Matches, _) => ValidationResult::single(
+                    "The 'matches' operator requires a regex pattern",
+                    &path,
+                    None,
+                    invalid_value(),
+                ),
+                _ => Ok(()),
+            }
+        }
+    }
 
     impl Validate for FilterTransform {
         fn validate(&self, path: &ValidationPath) -> ValidationResult {
-            self.field.validate(path)
+            match self.operator {
+                FilterTransformOp::And | FilterTransformOp::Or | FilterTransformOp::Not => {
+                    ValidationResult::ensure(
+                        self.field.is_none(),
+                        "The field must not be set if the operator is 'and', 'or' or 'not'",
+                        path,
+                    )
+                    .concat(match &self.filters {
+                        Some(filters) if !filters.is_empty() => {
+                            ValidationResult::validate(filters.iter(), |i, filter| {
+                                filter.validate(&path.append("filters", i))
+                            })
+                        }
+                        _ => ValidationResult::single(
+                            "At least one filter is required if the operator is 'and', 'or' or 'not'",
+                            path,
+                            None,
+                            None,
+                        ),
+                    })
+                }
+                _ => self
+                    .field
+                    .validate(path)
+                    .concat(ValidationResult::ensure(
+                        self.filters.is_none(),
+                        "Filters can only be set if the operator is 'and', 'or' or 'not'",
+                        path,
+                    ))
+                    .concat(self.validate_other(path)),
+            }
         }
     }
 }
@@ -1,9 +1,20 @@
+pub mod aggregate;
+pub mod array_transform;
 pub mod choose_from_array;
+pub mod coerce;
+pub mod corrupt;
 pub mod filter;
+pub mod numeric_transform;
+pub mod object_transform;
 pub mod plugin_transform;
+pub mod pseudonymize;
 pub mod random_remove;
 pub mod regex_filter;
 pub mod remove_all;
+pub mod reshape;
 pub mod sort;
 pub mod string_case_transform;
+pub mod string_transform;
 pub mod to_string;
+pub mod unique;
+pub mod when;
@@ -0,0 +1,440 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of decimals a number can be rounded to.
+/// Larger values exceed the precision of a 64 bit float.
+pub const MAX_DECIMALS: u32 = 15;
+
+/// Round a number to a number of decimals or to a step.
+/// If neither `decimals` nor `step` is set, the number is rounded to an integer.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct RoundTransform {
+    /// The number of decimals to round to.
+    /// Must be at most `15`.
+    pub decimals: Option<u32>,
+    /// The step to round to, e.g. `0.05` or `10`.
+    pub step: Option<f64>,
+}
+
+/// Restrict a number to a range.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ClampTransform {
+    /// The minimum value (inclusive).
+    pub min: Option<f64>,
+    /// The maximum value (inclusive).
+    pub max: Option<f64>,
+}
+
+/// Multiply a number with a factor and add an offset.
+/// Integers stay integers if both the factor and the offset are integers.
+/// In that case, the value is calculated using integer arithmetic.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ScaleTransform {
+    /// The factor to multiply the number with.
+    /// Defaults to `1`.
+    pub factor: Option<f64>,
+    /// The offset to add to the number after multiplying it.
+    /// Defaults to `0`.
+    pub offset: Option<f64>,
+}
+
+/// Format a number as a string using the number format of a locale.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct FormatNumberTransform {
+    /// The name of the locale to use, e.g. `en` or `de`.
+    /// Defaults to `en`.
+    pub locale: Option<String>,
+    /// The number of decimals to write.
+    /// If not set, all decimals of the number are written.
+    pub decimals: Option<u32>,
+    /// Whether to group the digits using the separator of the locale.
+    /// Defaults to `true`.
+    pub grouping: Option<bool>,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::numeric_transform::{
+        ClampTransform, FormatNumberTransform, RoundTransform, ScaleTransform, MAX_DECIMALS,
+    };
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::{anyhow, bail};
+    use num_format::{Locale, ToFormattedString};
+    use std::sync::Arc;
+
+    #[derive(Clone, Copy)]
+    pub(crate) enum RoundMode {
+        Round,
+        Floor,
+        Ceil,
+    }
+
+    impl RoundMode {
+        fn name(&self) -> &'static str {
+            match self {
+                RoundMode::Round => "round",
+                RoundMode::Floor => "floor",
+                RoundMode::Ceil => "ceil",
+            }
+        }
+
+        fn apply(&self, value: f64) -> f64 {
+            match self {
+                RoundMode::Round => value.round(),
+                RoundMode::Floor => value.floor(),
+                RoundMode::Ceil => value.ceil(),
+            }
+        }
+    }
+
+    fn transform_numeric<I, N>(
+        name: &str,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+        integer: I,
+        number: N,
+    ) -> anyhow::Result<Arc<GeneratedSchema>>
+    where
+        I: FnOnce(i64) -> anyhow::Result<GeneratedSchema>,
+        N: FnOnce(f64) -> anyhow::Result<GeneratedSchema>,
+    {
+        match value.as_ref() {
+            GeneratedSchema::Integer(value) => Ok(integer(*value)?.into()),
+            GeneratedSchema::Number(value) => Ok(number(value.0)?.into()),
+            GeneratedSchema::None => Ok(value),
+            invalid => Err(anyhow!(
+                "{name} can only be applied to numbers. Actual type was {}",
+                invalid.name(),
+            )
+            .context(anyhow!("Invalid schema at {}", schema.path()?))),
+        }
+    }
+
+    fn number_or_integer(value: f64, integer: bool) -> GeneratedSchema {
+        if integer {
+            GeneratedSchema::Integer(value as i64)
+        } else {
+            GeneratedSchema::Number(value.into())
+        }
+    }
+
+    /// Get the number of decimals of the shortest representation of a number.
+    fn count_decimals(value: f64) -> i32 {
+        value
+            .to_string()
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len() as i32)
+    }
+
+    fn round_to_decimals(value: f64, decimals: i32, mode: RoundMode) -> anyhow::Result<f64> {
+        let factor = 10f64.powi(decimals);
+        let res = mode.apply(value * factor) / factor;
+        if !res.is_finite() {
+            bail!("Unable to round {value} to {decimals} decimals");
+        }
+
+        Ok(res)
+    }
+
+    impl RoundTransform {
+        pub(crate) fn transform_with_mode(
+            self,
+            mode: RoundMode,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let step = self.step;
+            let decimals = self.decimals.unwrap_or(0);
+            if decimals > MAX_DECIMALS {
+                return Err(anyhow!(
+                    "{} can't round to more than {MAX_DECIMALS} decimals",
+                    mode.name()
+                )
+                .context(anyhow!("Invalid schema at {}", schema.path()?)));
+            }
+
+            transform_numeric(
+                mode.name(),
+                schema,
+                value,
+                |value| {
+                    Ok(match step {
+                        Some(step) => number_or_integer(
+                            mode.apply(value as f64 / step) * step,
+                            step.fract() == 0.0,
+                        ),
+                        None => GeneratedSchema::Integer(value),
+                    })
+                },
+                |value| {
+                    Ok(GeneratedSchema::Number(
+                        match step {
+                            // Remove floating point errors introduced by the multiplication
+                            Some(step) => round_to_decimals(
+                                mode.apply(value / step) * step,
+                                count_decimals(step),
+                                RoundMode::Round,
+                            )?,
+                            None => round_to_decimals(value, decimals as i32, mode)?,
+                        }
+                        .into(),
+                    ))
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for ClampTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let min = self.min.unwrap_or(f64::NEG_INFINITY);
+            let max = self.max.unwrap_or(f64::INFINITY);
+
+            transform_numeric(
+                "clamp",
+                schema,
+                value,
+                |value| {
+                    let (min, max) = (min.ceil(), max.floor());
+                    if min > max {
+                        bail!("clamp can't be applied to integers, there is no integer between min and max");
+                    }
+
+                    Ok(GeneratedSchema::Integer(
+                        (value as f64).clamp(min, max) as i64
+                    ))
+                },
+                |value| {
+                    if min > max {
+                        bail!("clamp requires min to be less than or equal to max");
+                    }
+
+                    Ok(GeneratedSchema::Number(value.clamp(min, max).into()))
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for ScaleTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let factor = self.factor.unwrap_or(1.0);
+            let offset = self.offset.unwrap_or(0.0);
+
+            transform_numeric(
+                "scale",
+                schema,
+                value,
+                |value| {
+                    if factor.fract() != 0.0 || offset.fract() != 0.0 {
+                        return Ok(GeneratedSchema::Number(
+                            (value as f64 * factor + offset).into(),
+                        ));
+                    }
+
+                    // Use integer arithmetic to keep the precision of large integers
+                    let integer = |value: f64| {
+                        (value >= i64::MIN as f64 && value < i64::MAX as f64)
+                            .then_some(value as i64)
+                    };
+                    integer(factor)
+                        .zip(integer(offset))
+                        .and_then(|(factor, offset)| value.checked_mul(factor)?.checked_add(offset))
+                        .map(GeneratedSchema::Integer)
+                        .ok_or(anyhow!(
+                            "The result of scaling {value} by {factor} with offset {offset} does not fit into an integer"
+                        ))
+                },
+                |value| Ok(GeneratedSchema::Number((value * factor + offset).into())),
+            )
+        }
+    }
+
+    pub(crate) fn abs(
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        transform_numeric(
+            "abs",
+            schema,
+            value,
+            |value| {
+                Ok(GeneratedSchema::Integer(value.checked_abs().ok_or(
+                    anyhow!("The absolute value of {value} does not fit into an integer"),
+                )?))
+            },
+            |value| Ok(GeneratedSchema::Number(value.abs().into())),
+        )
+    }
+
+    impl FormatNumberTransform {
+        fn format(&self, locale: &Locale, value: f64, decimals: Option<usize>) -> String {
+            let formatted = match decimals {
+                Some(decimals) => format!("{:.*}", decimals, value.abs()),
+                None => value.abs().to_string(),
+            };
+            let (integer, fraction) = formatted
+                .split_once('.')
+                .map_or((formatted.as_str(), None), |(i, f)| (i, Some(f)));
+
+            let mut res = String::new();
+            if value.is_sign_negative() && formatted.chars().any(|c| c.is_ascii_digit() && c != '0')
+            {
+                res.push_str(locale.minus_sign());
+            }
+
+            match integer.parse::<u128>() {
+                Ok(integer) if self.grouping.unwrap_or(true) => {
+                    res.push_str(&integer.to_formatted_string(locale))
+                }
+                _ => res.push_str(integer),
+            }
+
+            if let Some(fraction) = fraction {
+                res.push_str(locale.decimal());
+                res.push_str(fraction);
+            }
+
+            res
+        }
+    }
+
+    impl TransformTrait for FormatNumberTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let locale = Locale::from_name(self.locale.as_deref().unwrap_or("en"))
+                .map_err(|e| anyhow!(e.to_string()))?;
+            let decimals = self.decimals.map(|decimals| decimals as usize);
+
+            transform_numeric(
+                "formatNumber",
+                schema,
+                value,
+                |value| {
+                    Ok(GeneratedSchema::String(self.format(
+                        &locale,
+                        value as f64,
+                        Some(decimals.unwrap_or(0)),
+                    )))
+                },
+                |value| {
+                    Ok(GeneratedSchema::String(
+                        self.format(&locale, value, decimals),
+                    ))
+                },
+            )
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::numeric_transform::{
+        ClampTransform, FormatNumberTransform, RoundTransform, ScaleTransform, MAX_DECIMALS,
+    };
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+    use num_format::Locale;
+    use serde_json::Value;
+
+    impl Validate for RoundTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.decimals.is_none() || self.step.is_none(),
+                "Only one of decimals and step can be set",
+                path,
+            )
+            .concat(ValidationResult::ensure(
+                !matches!(self.step, Some(step) if step <= 0.0),
+                "The step must be greater than zero",
+                &path.append_single("step"),
+            ))
+            .concat(ValidationResult::ensure(
+                !matches!(self.decimals, Some(decimals) if decimals > MAX_DECIMALS),
+                format!("The number of decimals must be at most {MAX_DECIMALS}"),
+                &path.append_single("decimals"),
+            ))
+        }
+    }
+
+    impl Validate for ClampTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            match (self.min, self.max) {
+                (None, None) => ValidationResult::single(
+                    "At least one of min and max must be set",
+                    path,
+                    None,
+                    None,
+                ),
+                (Some(min), Some(max)) => ValidationResult::ensure(
+                    min <= max,
+                    "min must be less than or equal to max",
+                    path,
+                ),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    impl Validate for ScaleTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.factor.is_some() || self.offset.is_some(),
+                "At least one of factor and offset must be set",
+                path,
+            )
+        }
+    }
+
+    impl Validate for FormatNumberTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            match &self.locale {
+                Some(locale) => ValidationResult::ensure_ok(
+                    Locale::from_name(locale),
+                    "Unknown locale",
+                    &path.append_single("locale"),
+                    Some(Value::String(locale.clone())),
+                ),
+                None => Ok(()),
+            }
+        }
+    }
+}
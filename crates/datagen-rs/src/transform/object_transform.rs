@@ -0,0 +1,781 @@
+use crate::schema::any_value::AnyValue;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// How arrays are merged if both values contain an array.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum ArrayMergeStrategy {
+    /// Replace the array with the merged array.
+    #[default]
+    Replace,
+    /// Append the elements of the merged array.
+    Concat,
+    /// Append the elements of the merged array
+    /// which are not already part of the array.
+    Union,
+    /// Merge the elements with the same index.
+    /// Additional elements of the merged array are appended.
+    ByIndex,
+}
+
+/// Merge another value into an object or an array.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MergeTransform {
+    /// The value to merge into the transformed value.
+    /// This is either a reference or a schema which is generated
+    /// every time the transform is applied.
+    pub value: AnyValue,
+    /// Whether nested objects and arrays are merged recursively.
+    /// If false, nested values are replaced.
+    /// Defaults to true.
+    pub deep: Option<bool>,
+    /// How arrays are merged.
+    /// Defaults to replacing the array.
+    pub arrays: Option<ArrayMergeStrategy>,
+}
+
+/// A single JSON Patch operation as defined in RFC 6902.
+/// Paths are JSON pointers, e.g. `/address/street` or `/tags/0`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(tag = "op", rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum JsonPatchOperation {
+    /// Add a value to an object or insert it into an array.
+    /// Use `-` as the last segment of the path to append to an array.
+    Add { path: String, value: Value },
+    /// Remove a value.
+    Remove { path: String },
+    /// Replace an existing value.
+    Replace { path: String, value: Value },
+    /// Move a value to another path.
+    Move { from: String, path: String },
+    /// Copy a value to another path.
+    Copy { from: String, path: String },
+    /// Fail if the value at the path is not equal to the given value.
+    Test { path: String, value: Value },
+}
+
+/// Apply JSON Patch (RFC 6902) operations to a value.
+/// Values which are strings starting with `ref:` are resolved as references.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct JsonPatchTransform {
+    /// The operations to apply, in order.
+    /// If one of the operations fails, the transform fails.
+    pub operations: Vec<JsonPatchOperation>,
+}
+
+/// How array elements are keyed in flattened objects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum ArrayKeyMode {
+    /// Use the index as a key segment, e.g. `tags.0`.
+    #[default]
+    Index,
+    /// Append the index in brackets, e.g. `tags[0]`.
+    Brackets,
+    /// Keep arrays as values.
+    Keep,
+}
+
+/// Flatten nested objects into a single object,
+/// e.g. `{"address": {"city": "X"}}` into `{"address.city": "X"}`.
+/// If applied to an array, every object in the array is flattened.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct FlattenKeysTransform {
+    /// The separator between the key segments.
+    /// Defaults to `.`.
+    pub separator: Option<String>,
+    /// How array elements are keyed.
+    /// Defaults to using the index as a key segment.
+    pub arrays: Option<ArrayKeyMode>,
+}
+
+/// Turn an object with flattened keys into nested objects,
+/// e.g. `{"address.city": "X"}` into `{"address": {"city": "X"}}`.
+/// If applied to an array, every object in the array is unflattened.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct UnflattenKeysTransform {
+    /// The separator between the key segments.
+    /// Defaults to `.`.
+    pub separator: Option<String>,
+    /// How array elements are keyed.
+    /// Objects whose keys are the indices `0` to `n - 1` are converted to arrays,
+    /// unless arrays are kept.
+    /// Defaults to using the index as a key segment.
+    pub arrays: Option<ArrayKeyMode>,
+}
+
+impl JsonPatchOperation {
+    /// Whether the operation replaces the whole value.
+    pub fn targets_root(&self) -> bool {
+        match self {
+            JsonPatchOperation::Add { path, .. }
+            | JsonPatchOperation::Replace { path, .. }
+            | JsonPatchOperation::Move { path, .. }
+            | JsonPatchOperation::Copy { path, .. } => path.is_empty(),
+            JsonPatchOperation::Remove { .. } | JsonPatchOperation::Test { .. } => false,
+        }
+    }
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::schema::any_value::AnyValue;
+    use crate::transform::object_transform::{
+        ArrayKeyMode, ArrayMergeStrategy, FlattenKeysTransform, JsonPatchOperation,
+        JsonPatchTransform, MergeTransform, UnflattenKeysTransform,
+    };
+    use crate::util::traits::generate::{ResolveRef, TransformTrait};
+    use anyhow::anyhow;
+    use indexmap::IndexMap;
+    use serde_json::Value;
+    use std::sync::Arc;
+
+    type ObjectMap = IndexMap<String, Arc<GeneratedSchema>>;
+
+    /// Convert a JSON value into a generated schema,
+    /// so it can be handled like any other generated value.
+    fn from_json(value: Value) -> GeneratedSchema {
+        match value {
+            Value::Null => GeneratedSchema::None,
+            Value::Bool(b) => GeneratedSchema::Bool(b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => GeneratedSchema::Integer(i),
+                None => GeneratedSchema::Number(n.as_f64().unwrap_or_default().into()),
+            },
+            Value::String(str) => GeneratedSchema::String(str),
+            Value::Array(array) => GeneratedSchema::Array(
+                array
+                    .into_iter()
+                    .map(|value| from_json(value).into())
+                    .collect(),
+            ),
+            Value::Object(object) => GeneratedSchema::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, from_json(value).into()))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn normalize(value: Arc<GeneratedSchema>) -> Arc<GeneratedSchema> {
+        match value.as_ref() {
+            GeneratedSchema::Value(json) => from_json(json.clone()).into(),
+            _ => value,
+        }
+    }
+
+    /// Wrap the result in a [`GeneratedSchema::Value`] if the input was one.
+    fn restore_value(
+        input: &GeneratedSchema,
+        res: Arc<GeneratedSchema>,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        Ok(match input {
+            GeneratedSchema::Value(_) => GeneratedSchema::Value(serde_json::to_value(res)?).into(),
+            _ => res,
+        })
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate_value(
+        value: AnyValue,
+        schema: &DatagenContextRef,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        use crate::generate::generated_schema::IntoRandom;
+
+        match value {
+            AnyValue::String(reference) => reference.resolve_ref(schema),
+            value => value.into_random(schema.child(None, "merge")?),
+        }
+    }
+
+    #[cfg(not(feature = "generate"))]
+    fn generate_value(
+        value: AnyValue,
+        schema: &DatagenContextRef,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        match value {
+            AnyValue::String(reference) => reference.resolve_ref(schema),
+            _ => crate::bail_unsupported!("generate"),
+        }
+    }
+
+    impl MergeTransform {
+        fn merge(
+            &self,
+            target: Arc<GeneratedSchema>,
+            source: Arc<GeneratedSchema>,
+        ) -> Arc<GeneratedSchema> {
+            let deep = self.deep.unwrap_or(true);
+            let merge_nested = |target: &Arc<GeneratedSchema>, source: &Arc<GeneratedSchema>| {
+                if deep {
+                    self.merge(target.clone(), source.clone())
+                } else {
+                    source.clone()
+                }
+            };
+
+            match (normalize(target).as_ref(), normalize(source).as_ref()) {
+                (GeneratedSchema::Object(target), GeneratedSchema::Object(source)) => {
+                    let mut res = target.clone();
+                    for (key, value) in source {
+                        let value = match res.get(key) {
+                            Some(existing) => merge_nested(existing, value),
+                            None => value.clone(),
+                        };
+
+                        res.insert(key.clone(), value);
+                    }
+
+                    GeneratedSchema::Object(res).into()
+                }
+                (GeneratedSchema::Array(target), GeneratedSchema::Array(source)) => {
+                    GeneratedSchema::Array(match self.arrays.unwrap_or_default() {
+                        ArrayMergeStrategy::Replace => source.clone(),
+                        ArrayMergeStrategy::Concat => {
+                            target.iter().chain(source.iter()).cloned().collect()
+                        }
+                        ArrayMergeStrategy::Union => {
+                            let mut res = target.clone();
+                            for value in source {
+                                if !res.contains(value) {
+                                    res.push(value.clone());
+                                }
+                            }
+
+                            res
+                        }
+                        ArrayMergeStrategy::ByIndex => (0..target.len().max(source.len()))
+                            .filter_map(|i| match (target.get(i), source.get(i)) {
+                                (Some(target), Some(source)) => Some(merge_nested(target, source)),
+                                (target, source) => target.or(source).cloned(),
+                            })
+                            .collect(),
+                    })
+                    .into()
+                }
+                (_, source) => source.clone().into(),
+            }
+        }
+    }
+
+    impl TransformTrait for MergeTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let target = normalize(value.clone());
+            if !matches!(
+                target.as_ref(),
+                GeneratedSchema::Object(_) | GeneratedSchema::Array(_)
+            ) {
+                return Err(anyhow!(
+                    "merge can only be applied to objects and arrays. Actual type was {}",
+                    value.name()
+                )
+                .context(anyhow!("Invalid schema at {}", schema.path()?)));
+            }
+
+            let source = normalize(generate_value(self.value.clone(), &schema)?);
+            if source.as_ref() == &GeneratedSchema::None {
+                return Ok(value);
+            } else if source.name() != target.name() {
+                return Err(anyhow!(
+                    "Cannot merge a value of type {} into a value of type {}",
+                    source.name(),
+                    target.name()
+                )
+                .context(anyhow!("Invalid schema at {}", schema.path()?)));
+            }
+
+            restore_value(value.as_ref(), self.merge(target, source))
+        }
+    }
+
+    /// Split a JSON pointer into the pointer to the parent and the unescaped last segment.
+    fn split_pointer(path: &str) -> anyhow::Result<(&str, String)> {
+        let (parent, key) = path
+            .rsplit_once('/')
+            .ok_or(anyhow!("Invalid JSON pointer '{path}'"))?;
+
+        Ok((parent, key.replace("~1", "/").replace("~0", "~")))
+    }
+
+    fn array_index(key: &str, len: usize, path: &str) -> anyhow::Result<usize> {
+        key.parse::<usize>()
+            .ok()
+            .filter(|&index| index <= len)
+            .ok_or(anyhow!("The array index of '{path}' is out of bounds"))
+    }
+
+    fn add(doc: &mut Value, path: &str, value: Value) -> anyhow::Result<()> {
+        if path.is_empty() {
+            *doc = value;
+            return Ok(());
+        }
+
+        let (parent, key) = split_pointer(path)?;
+        match doc.pointer_mut(parent) {
+            Some(Value::Object(object)) => {
+                object.insert(key, value);
+            }
+            Some(Value::Array(array)) if key == "-" => array.push(value),
+            Some(Value::Array(array)) => {
+                let index = array_index(&key, array.len(), path)?;
+                array.insert(index, value);
+            }
+            Some(_) => return Err(anyhow!("The parent of '{path}' is not an object or array")),
+            None => return Err(anyhow!("The parent of '{path}' does not exist")),
+        }
+
+        Ok(())
+    }
+
+    fn remove(doc: &mut Value, path: &str) -> anyhow::Result<Value> {
+        let (parent, key) = split_pointer(path)?;
+        match doc.pointer_mut(parent) {
+            Some(Value::Object(object)) => object.shift_remove(&key),
+            Some(Value::Array(array)) => {
+                let index = array_index(&key, array.len(), path)?;
+                (index < array.len()).then(|| array.remove(index))
+            }
+            _ => None,
+        }
+        .ok_or(anyhow!("The path '{path}' does not exist"))
+    }
+
+    fn get<'a>(doc: &'a Value, path: &str) -> anyhow::Result<&'a Value> {
+        doc.pointer(path)
+            .ok_or(anyhow!("The path '{path}' does not exist"))
+    }
+
+    impl JsonPatchTransform {
+        /// Resolve values which are references.
+        fn resolve(value: Value, schema: &DatagenContextRef) -> anyhow::Result<Value> {
+            match value {
+                Value::String(str) if str.starts_with("ref:") => {
+                    Ok(serde_json::to_value(str.resolve_ref(schema)?)?)
+                }
+                value => Ok(value),
+            }
+        }
+
+        fn apply(self, doc: &mut Value, schema: &DatagenContextRef) -> anyhow::Result<()> {
+            for operation in self.operations {
+                match operation {
+                    JsonPatchOperation::Add { path, value } => {
+                        add(doc, &path, Self::resolve(value, schema)?)?
+                    }
+                    JsonPatchOperation::Remove { path } => {
+                        remove(doc, &path)?;
+                    }
+                    JsonPatchOperation::Replace { path, value } => {
+                        let value = Self::resolve(value, schema)?;
+                        *doc.pointer_mut(&path)
+                            .ok_or(anyhow!("The path '{path}' does not exist"))? = value;
+                    }
+                    JsonPatchOperation::Move { from, path } => {
+                        let value = remove(doc, &from)?;
+                        add(doc, &path, value)?;
+                    }
+                    JsonPatchOperation::Copy { from, path } => {
+                        let value = get(doc, &from)?.clone();
+                        add(doc, &path, value)?;
+                    }
+                    JsonPatchOperation::Test { path, value } => {
+                        let value = Self::resolve(value, schema)?;
+                        let actual = get(doc, &path)?;
+                        if actual != &value {
+                            return Err(anyhow!(
+                                "The test of '{path}' failed: expected {value}, but the value was {actual}"
+                            ));
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl TransformTrait for JsonPatchTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let mut doc = match value.as_ref() {
+                GeneratedSchema::Value(json) => json.clone(),
+                GeneratedSchema::Object(_) | GeneratedSchema::Array(_) => {
+                    serde_json::to_value(&value)?
+                }
+                invalid => {
+                    return Err(anyhow!(
+                        "jsonPatch can only be applied to objects and arrays. Actual type was {}",
+                        invalid.name()
+                    )
+                    .context(anyhow!("Invalid schema at {}", schema.path()?)))
+                }
+            };
+
+            if let Err(e) = self.apply(&mut doc, &schema) {
+                return Err(e.context(anyhow!("Invalid schema at {}", schema.path()?)));
+            }
+
+            Ok(match value.as_ref() {
+                GeneratedSchema::Value(_) => GeneratedSchema::Value(doc),
+                _ => from_json(doc),
+            }
+            .into())
+        }
+    }
+
+    /// Apply `func` to an object or to every object in an array.
+    fn transform_objects<F>(
+        name: &str,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+        func: F,
+    ) -> anyhow::Result<Arc<GeneratedSchema>>
+    where
+        F: Fn(&ObjectMap) -> anyhow::Result<ObjectMap>,
+    {
+        let res = match normalize(value.clone()).as_ref() {
+            GeneratedSchema::Object(object) => func(object).map(GeneratedSchema::Object),
+            GeneratedSchema::Array(array) => array
+                .iter()
+                .map(|item| match normalize(item.clone()).as_ref() {
+                    GeneratedSchema::Object(object) => {
+                        Ok(GeneratedSchema::Object(func(object)?).into())
+                    }
+                    _ => Ok(item.clone()),
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map(GeneratedSchema::Array),
+            GeneratedSchema::None => return Ok(value),
+            invalid => Err(anyhow!(
+                "{name} can only be applied to objects and arrays. Actual type was {}",
+                invalid.name()
+            )),
+        };
+
+        match res {
+            Ok(res) => restore_value(value.as_ref(), res.into()),
+            Err(e) => Err(e.context(anyhow!("Invalid schema at {}", schema.path()?))),
+        }
+    }
+
+    impl FlattenKeysTransform {
+        fn flatten(
+            &self,
+            key: String,
+            value: &Arc<GeneratedSchema>,
+            res: &mut ObjectMap,
+        ) -> anyhow::Result<()> {
+            let separator = self.separator.as_deref().unwrap_or(".");
+            match normalize(value.clone()).as_ref() {
+                GeneratedSchema::Object(object) if !object.is_empty() => {
+                    for (nested, value) in object {
+                        self.flatten(format!("{key}{separator}{nested}"), value, res)?;
+                    }
+                }
+                GeneratedSchema::Array(array)
+                    if !array.is_empty() && self.arrays != Some(ArrayKeyMode::Keep) =>
+                {
+                    for (i, value) in array.iter().enumerate() {
+                        let key = match self.arrays.unwrap_or_default() {
+                            ArrayKeyMode::Brackets => format!("{key}[{i}]"),
+                            _ => format!("{key}{separator}{i}"),
+                        };
+
+                        self.flatten(key, value, res)?;
+                    }
+                }
+                _ => {
+                    if res.contains_key(&key) {
+                        return Err(anyhow!("The key '{key}' conflicts with another key"));
+                    }
+
+                    res.insert(key, value.clone());
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl TransformTrait for FlattenKeysTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_objects("flattenKeys", schema, value, |object| {
+                let mut res = ObjectMap::new();
+                for (key, value) in object {
+                    self.flatten(key.clone(), value, &mut res)?;
+                }
+
+                Ok(res)
+            })
+        }
+    }
+
+    /// An object which is built from flattened keys.
+    enum Node {
+        Leaf(Arc<GeneratedSchema>),
+        Branch(IndexMap<String, Node>),
+    }
+
+    impl UnflattenKeysTransform {
+        /// Split a flattened key into its segments.
+        fn segments(&self, key: &str) -> Vec<String> {
+            let separator = self.separator.as_deref().unwrap_or(".");
+            key.split(separator)
+                .flat_map(|segment| match self.arrays.unwrap_or_default() {
+                    ArrayKeyMode::Brackets => split_brackets(segment),
+                    _ => vec![segment.to_string()],
+                })
+                .collect()
+        }
+
+        fn build(&self, node: Node) -> Arc<GeneratedSchema> {
+            let branch = match node {
+                Node::Leaf(value) => return value,
+                Node::Branch(branch) => branch,
+            };
+
+            let is_array = self.arrays.unwrap_or_default() != ArrayKeyMode::Keep
+                && !branch.is_empty()
+                && (0..branch.len()).all(|i| branch.contains_key(&i.to_string()));
+            if is_array {
+                let mut branch = branch;
+                GeneratedSchema::Array(
+                    (0..branch.len())
+                        .filter_map(|i| branch.shift_remove(&i.to_string()))
+                        .map(|node| self.build(node))
+                        .collect(),
+                )
+                .into()
+            } else {
+                GeneratedSchema::Object(
+                    branch
+                        .into_iter()
+                        .map(|(key, node)| (key, self.build(node)))
+                        .collect(),
+                )
+                .into()
+            }
+        }
+
+        fn unflatten(&self, object: &ObjectMap) -> anyhow::Result<ObjectMap> {
+            let mut root = IndexMap::new();
+            for (key, value) in object {
+                let segments = self.segments(key);
+                let (last, parents) = segments.split_last().unwrap();
+
+                let mut current = &mut root;
+                for segment in parents {
+                    let node = current
+                        .entry(segment.clone())
+                        .or_insert_with(|| Node::Branch(IndexMap::new()));
+                    current = match node {
+                        Node::Branch(branch) => branch,
+                        Node::Leaf(_) => {
+                            return Err(anyhow!("The key '{key}' conflicts with another key"))
+                        }
+                    };
+                }
+
+                if current.contains_key(last) {
+                    return Err(anyhow!("The key '{key}' conflicts with another key"));
+                }
+
+                current.insert(last.clone(), Node::Leaf(value.clone()));
+            }
+
+            Ok(root
+                .into_iter()
+                .map(|(key, node)| (key, self.build(node)))
+                .collect())
+        }
+    }
+
+    /// Split a key segment like `tags[0][1]` into `tags`, `0` and `1`.
+    fn split_brackets(segment: &str) -> Vec<String> {
+        let Some((name, indices)) = segment.split_once('[') else {
+            return vec![segment.to_string()];
+        };
+
+        let indices = indices
+            .strip_suffix(']')
+            .map(|indices| indices.split("][").collect::<Vec<_>>())
+            .filter(|indices| indices.iter().all(|i| i.parse::<usize>().is_ok()));
+        match indices {
+            Some(indices) => std::iter::once(name)
+                .chain(indices)
+                .map(ToString::to_string)
+                .collect(),
+            None => vec![segment.to_string()],
+        }
+    }
+
+    impl TransformTrait for UnflattenKeysTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_objects("unflattenKeys", schema, value, |object| {
+                self.unflatten(object)
+            })
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::schema::any_value::AnyValue;
+    use crate::transform::object_transform::{
+        FlattenKeysTransform, JsonPatchOperation, JsonPatchTransform, MergeTransform,
+        UnflattenKeysTransform,
+    };
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    fn validate_pointer(pointer: &str, path: &ValidationPath) -> ValidationResult {
+        ValidationResult::ensure(
+            pointer.is_empty() || pointer.starts_with('/'),
+            "The JSON pointer must be empty or start with '/'",
+            path,
+        )
+        .concat(ValidationResult::ensure(
+            pointer
+                .match_indices('~')
+                .all(|(i, _)| matches!(pointer.as_bytes().get(i + 1), Some(b'0' | b'1'))),
+            "'~' must be escaped as '~0' in JSON pointers",
+            path,
+        ))
+    }
+
+    impl Validate for MergeTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            let path = path.append_single("value");
+            ValidationResult::ensure(
+                match &self.value {
+                    AnyValue::String(reference) => reference.starts_with("ref:"),
+                    AnyValue::Any(_) => true,
+                    _ => false,
+                },
+                "The value must be a reference or a schema",
+                &path,
+            )
+            .concat(self.value.validate(&path))
+        }
+    }
+
+    impl Validate for JsonPatchOperation {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            match self {
+                JsonPatchOperation::Add { path: pointer, .. }
+                | JsonPatchOperation::Replace { path: pointer, .. }
+                | JsonPatchOperation::Test { path: pointer, .. } => {
+                    validate_pointer(pointer, &path.append_single("path"))
+                }
+                JsonPatchOperation::Remove { path: pointer } => {
+                    validate_pointer(pointer, &path.append_single("path")).concat(
+                        ValidationResult::ensure(
+                            !pointer.is_empty(),
+                            "The whole value can't be removed",
+                            &path.append_single("path"),
+                        ),
+                    )
+                }
+                JsonPatchOperation::Copy {
+                    from,
+                    path: pointer,
+                } => validate_pointer(from, &path.append_single("from"))
+                    .concat(validate_pointer(pointer, &path.append_single("path"))),
+                JsonPatchOperation::Move {
+                    from,
+                    path: pointer,
+                } => validate_pointer(from, &path.append_single("from"))
+                    .concat(validate_pointer(pointer, &path.append_single("path")))
+                    .concat(ValidationResult::ensure(
+                        pointer != from && !pointer.starts_with(&format!("{from}/")),
+                        "A value can't be moved into itself or one of its children",
+                        path,
+                    )),
+            }
+        }
+    }
+
+    impl Validate for JsonPatchTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::validate(self.operations.iter(), |i, operation| {
+                operation.validate(&path.append("operations", i))
+            })
+        }
+    }
+
+    fn validate_separator(separator: &Option<String>, path: &ValidationPath) -> ValidationResult {
+        ValidationResult::ensure(
+            !matches!(separator, Some(separator) if separator.is_empty()),
+            "The separator must not be empty",
+            &path.append_single("separator"),
+        )
+    }
+
+    impl Validate for FlattenKeysTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            validate_separator(&self.separator, path)
+        }
+    }
+
+    impl Validate for UnflattenKeysTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            validate_separator(&self.separator, path)
+        }
+    }
+}
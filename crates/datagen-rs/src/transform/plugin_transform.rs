@@ -19,6 +19,7 @@ pub struct PluginTransform {
 pub mod generate {
     use crate::generate::datagen_context::DatagenContextRef;
     use crate::generate::generated_schema::GeneratedSchema;
+    use crate::generate::profiler;
     use crate::transform::plugin_transform::PluginTransform;
     use crate::util::traits::generate::TransformTrait;
     use std::sync::Arc;
@@ -29,9 +30,10 @@ pub mod generate {
             schema: DatagenContextRef,
             value: Arc<GeneratedSchema>,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            schema
-                .get_plugin(&self.name)?
-                .transform(schema, value, self.args.unwrap_or_default())
+            let plugin = schema.get_plugin(&self.name)?;
+            let _scope = profiler::plugin_scope(&self.name, "transform");
+
+            plugin.transform(schema, value, self.args.unwrap_or_default())
         }
     }
 }
@@ -0,0 +1,234 @@
+use crate::schema::string::StringGenerator;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Deterministically replace values with fake values.
+/// The same input and salt always result in the same output.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct PseudonymizeTransform {
+    /// The secret salt used to derive the output from the input.
+    pub salt: String,
+    /// The generator used to create the replacement value.
+    /// A `dateTime` generator must set `to`, so the output doesn't depend on the current date.
+    /// If not set, the value is masked while preserving its format:
+    /// digits are replaced with digits and letters with letters.
+    pub generator: Option<StringGenerator>,
+    /// The number of trailing digits and letters to keep when masking.
+    /// Defaults to 0.
+    pub keep_last: Option<usize>,
+    /// Whether the masked value should pass the Luhn check,
+    /// like a valid credit card number.
+    /// Defaults to false.
+    pub luhn: Option<bool>,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::schema::string::StringGenerator;
+    use crate::transform::pseudonymize::PseudonymizeTransform;
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::{anyhow, bail};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+    use sha2::{Digest, Sha256};
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    /// The minimum date generated by a `dateTime` generator without `from`.
+    const DATE_TIME_FROM: &str = "1970-01-01T00:00:00Z";
+
+    impl PseudonymizeTransform {
+        /// Create a random number generator which is seeded
+        /// using the salt and the input value.
+        fn rng(&self, value: &GeneratedSchema) -> anyhow::Result<ChaCha20Rng> {
+            let mut hasher = Sha256::new();
+            hasher.update(self.salt.as_bytes());
+            hasher.update([0]);
+            hasher.update(serde_json::to_string(value)?.as_bytes());
+
+            Ok(ChaCha20Rng::from_seed(hasher.finalize().into()))
+        }
+
+        /// Replace all digits and ASCII letters, keeping the last `keep_last` of them.
+        /// If `nonzero_first` is set, the first digit is never replaced with a zero.
+        fn mask(&self, value: &str, rng: &mut ChaCha20Rng, nonzero_first: bool) -> String {
+            let mut chars = value.chars().collect::<Vec<_>>();
+            let masked = chars
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.is_ascii_alphanumeric())
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            let masked = &masked[..masked.len().saturating_sub(self.keep_last.unwrap_or(0))];
+
+            for (n, &i) in masked.iter().enumerate() {
+                let c = chars[i];
+                chars[i] = if c.is_ascii_digit() {
+                    let min = if n == 0 && nonzero_first && c != '0' {
+                        1
+                    } else {
+                        0
+                    };
+                    char::from(b'0' + rng.gen_range(min..10))
+                } else if c.is_ascii_uppercase() {
+                    char::from(rng.gen_range(b'A'..=b'Z'))
+                } else {
+                    char::from(rng.gen_range(b'a'..=b'z'))
+                };
+            }
+
+            if self.luhn.unwrap_or(false) {
+                // Fix the last masked digit so that the whole number passes the Luhn check
+                let digits = chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| c.is_ascii_digit())
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+                if let Some(&check) = digits.iter().rev().find(|i| masked.contains(i)) {
+                    for digit in b'0'..=b'9' {
+                        chars[check] = char::from(digit);
+                        if luhn_valid(&chars) {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            chars.into_iter().collect()
+        }
+
+        fn pseudonymize(&self, value: &GeneratedSchema) -> anyhow::Result<GeneratedSchema> {
+            let mut rng = self.rng(value)?;
+            if let Some(generator) = &self.generator {
+                let generator = match generator {
+                    StringGenerator::DateTime { to: None, .. } => {
+                        bail!("The dateTime generator requires 'to' to pseudonymize values")
+                    }
+                    StringGenerator::DateTime {
+                        format,
+                        from: None,
+                        to,
+                    } => Cow::Owned(StringGenerator::DateTime {
+                        format: format.clone(),
+                        from: Some(DATE_TIME_FROM.into()),
+                        to: to.clone(),
+                    }),
+                    generator => Cow::Borrowed(generator),
+                };
+
+                return generator.generate_with_rng(&mut rng);
+            }
+
+            Ok(match value {
+                GeneratedSchema::String(str) => {
+                    GeneratedSchema::String(self.mask(str, &mut rng, false))
+                }
+                GeneratedSchema::Integer(i) => {
+                    let masked = self.mask(&i.to_string(), &mut rng, true);
+                    // The masked value may not fit into an integer if it has as many digits
+                    // as the largest integer, in which case it is returned as a string
+                    match masked.parse() {
+                        Ok(masked) => GeneratedSchema::Integer(masked),
+                        Err(_) => GeneratedSchema::String(masked),
+                    }
+                }
+                GeneratedSchema::Number(n) => {
+                    let masked = self.mask(&n.to_string(), &mut rng, true);
+                    GeneratedSchema::Number(
+                        masked
+                            .parse::<f64>()
+                            .map_err(|_| anyhow!("The masked value {masked} is not a number"))?
+                            .into(),
+                    )
+                }
+                invalid => {
+                    return Err(anyhow!(
+                    "pseudonymize can only be applied to strings and numbers. Actual type was {}",
+                    invalid.name()
+                ))
+                }
+            })
+        }
+    }
+
+    fn luhn_valid(chars: &[char]) -> bool {
+        let sum = chars
+            .iter()
+            .rev()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(i, digit)| match (i % 2, digit * 2) {
+                (1, doubled) if doubled > 9 => doubled - 9,
+                (1, doubled) => doubled,
+                _ => digit,
+            })
+            .sum::<u32>();
+
+        sum.is_multiple_of(10)
+    }
+
+    impl TransformTrait for PseudonymizeTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            if value.as_ref() == &GeneratedSchema::None {
+                return Ok(value);
+            }
+
+            match self.pseudonymize(value.as_ref()) {
+                Ok(res) => Ok(res.into()),
+                Err(e) => Err(e.context(anyhow!("Invalid schema at {}", schema.path()?))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::schema::string::StringGenerator;
+    use crate::transform::pseudonymize::PseudonymizeTransform;
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    impl Validate for PseudonymizeTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                !self.salt.is_empty(),
+                "The salt must not be empty",
+                &path.append_single("salt"),
+            )
+            .concat(ValidationResult::ensure(
+                !matches!(self.generator, Some(StringGenerator::Format { .. })),
+                "The format generator can't be used to pseudonymize values",
+                &path.append_single("generator"),
+            ))
+            .concat(ValidationResult::ensure(
+                !matches!(
+                    self.generator,
+                    Some(StringGenerator::DateTime { to: None, .. })
+                ),
+                "The dateTime generator requires 'to' to pseudonymize values",
+                &path.append_single("generator"),
+            ))
+            .concat(ValidationResult::ensure(
+                self.generator.is_none() || (self.keep_last.is_none() && self.luhn.is_none()),
+                "keepLast and luhn can only be used without a generator",
+                path,
+            ))
+        }
+    }
+}
@@ -31,16 +31,16 @@ pub mod generate {
     use crate::transform::random_remove::RandomRemoveTransform;
     use crate::util::traits::generate::TransformTrait;
     use rand::seq::SliceRandom;
-    use rand::{thread_rng, Rng};
+    use rand::Rng;
     use std::sync::Arc;
 
     impl TransformTrait for RandomRemoveTransform {
         fn transform(
             self,
-            _schema: DatagenContextRef,
+            schema: DatagenContextRef,
             value: Arc<GeneratedSchema>,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            let mut rng = thread_rng();
+            let mut rng = schema.rng();
 
             match value.as_ref() {
                 GeneratedSchema::Array(arr) => {
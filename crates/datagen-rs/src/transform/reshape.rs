@@ -0,0 +1,246 @@
+use crate::generate::generated_schema::GeneratedSchema;
+use indexmap::IndexMap;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Reshape an object.
+/// The operations are applied in the order
+/// `set`, `rename`, `hoist`, `nest` and `pick`.
+/// Use multiple `reshape` transforms if another order is required.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct ReshapeTransform {
+    /// The values to set, keyed by the name of the key to set.
+    /// Strings starting with `ref:` are resolved as references.
+    /// Existing keys are overwritten, new keys are appended.
+    pub set: Option<IndexMap<String, GeneratedSchema>>,
+    /// The keys to rename, mapping the old name to the new name.
+    /// Renamed keys keep their position.
+    /// Renaming a key to the name of another key is an error.
+    pub rename: Option<IndexMap<String, String>>,
+    /// The nested objects whose keys will be moved into the object.
+    /// The keys are inserted at the position of the nested object.
+    /// Hoisting a key which already exists in the object is an error.
+    pub hoist: Option<Vec<String>>,
+    /// The keys to move into nested objects, keyed by the name of the nested object.
+    /// The nested object is inserted at the position of the first moved key.
+    /// The name of the nested object must not be the name of a key which isn't moved.
+    pub nest: Option<IndexMap<String, Vec<String>>>,
+    /// The keys to keep, in the order they will be written in.
+    /// All other keys are removed.
+    pub pick: Option<Vec<String>>,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::reshape::ReshapeTransform;
+    use crate::util::traits::generate::{ResolveRef, TransformTrait};
+    use anyhow::{anyhow, bail};
+    use indexmap::IndexMap;
+    use std::sync::Arc;
+
+    type ObjectMap = IndexMap<String, Arc<GeneratedSchema>>;
+
+    fn set(
+        mut object: ObjectMap,
+        values: IndexMap<String, GeneratedSchema>,
+        schema: &DatagenContextRef,
+    ) -> anyhow::Result<ObjectMap> {
+        for (key, value) in values {
+            let value = match value {
+                GeneratedSchema::String(value) if value.starts_with("ref:") => {
+                    value.resolve_ref(schema)?
+                }
+                value => value.into(),
+            };
+
+            object.insert(key, value);
+        }
+
+        Ok(object)
+    }
+
+    fn rename(object: ObjectMap, names: &IndexMap<String, String>) -> anyhow::Result<ObjectMap> {
+        let mut res = ObjectMap::with_capacity(object.len());
+        for (key, value) in object {
+            let name = names.get(&key).cloned().unwrap_or(key);
+            if res.contains_key(&name) {
+                bail!("Can't rename a key to '{name}', the key already exists");
+            }
+
+            res.insert(name, value);
+        }
+
+        Ok(res)
+    }
+
+    fn hoist(object: ObjectMap, keys: &[String]) -> anyhow::Result<ObjectMap> {
+        let mut res = ObjectMap::new();
+        for (key, value) in object {
+            let values = if !keys.contains(&key) {
+                vec![(key, value)]
+            } else {
+                match value.as_ref() {
+                    GeneratedSchema::Object(nested) => nested.clone().into_iter().collect(),
+                    GeneratedSchema::None => vec![],
+                    invalid => {
+                        return Err(anyhow!(
+                            "Only objects can be hoisted, but '{key}' is of type {}",
+                            invalid.name()
+                        ))
+                    }
+                }
+            };
+
+            for (key, value) in values {
+                if res.contains_key(&key) {
+                    bail!("Can't hoist the key '{key}', the key already exists");
+                }
+
+                res.insert(key, value);
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn nest(
+        mut object: ObjectMap,
+        nested: &IndexMap<String, Vec<String>>,
+    ) -> anyhow::Result<ObjectMap> {
+        for (name, keys) in nested {
+            let Some(index) = keys.iter().filter_map(|key| object.get_index_of(key)).min() else {
+                continue;
+            };
+
+            let values = keys
+                .iter()
+                .filter_map(|key| object.shift_remove_entry(key))
+                .collect::<ObjectMap>();
+            if object.contains_key(name) {
+                bail!("Can't nest keys into '{name}', the key already exists");
+            }
+
+            let mut res = ObjectMap::with_capacity(object.len() + 1);
+            let mut values = Some(GeneratedSchema::Object(values).into());
+            for (i, (key, value)) in object.into_iter().enumerate() {
+                if i == index {
+                    res.insert(name.clone(), values.take().unwrap());
+                }
+
+                res.insert(key, value);
+            }
+
+            if let Some(values) = values {
+                res.insert(name.clone(), values);
+            }
+
+            object = res;
+        }
+
+        Ok(object)
+    }
+
+    fn pick(mut object: ObjectMap, keys: &[String]) -> ObjectMap {
+        keys.iter()
+            .filter_map(|key| object.swap_remove_entry(key))
+            .collect()
+    }
+
+    impl ReshapeTransform {
+        fn reshape(&self, mut object: ObjectMap) -> anyhow::Result<ObjectMap> {
+            if let Some(names) = &self.rename {
+                object = rename(object, names)?;
+            }
+            if let Some(keys) = &self.hoist {
+                object = hoist(object, keys)?;
+            }
+            if let Some(nested) = &self.nest {
+                object = nest(object, nested)?;
+            }
+            if let Some(keys) = &self.pick {
+                object = pick(object, keys);
+            }
+
+            Ok(object)
+        }
+    }
+
+    impl TransformTrait for ReshapeTransform {
+        fn transform(
+            mut self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let mut object = match value.as_ref() {
+                GeneratedSchema::Object(object) => object.clone(),
+                GeneratedSchema::None => return Ok(value),
+                invalid => {
+                    return Err(anyhow!(
+                        "reshape can only be applied to objects. Actual type was {}",
+                        invalid.name(),
+                    )
+                    .context(anyhow!("Invalid schema at {}", schema.path()?)))
+                }
+            };
+
+            if let Some(values) = self.set.take() {
+                object = set(object, values, &schema)?;
+            }
+
+            match self.reshape(object) {
+                Ok(object) => Ok(GeneratedSchema::Object(object).into()),
+                Err(e) => Err(e.context(anyhow!("Invalid schema at {}", schema.path()?))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::reshape::ReshapeTransform;
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+    use std::collections::HashSet;
+
+    impl Validate for ReshapeTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            let renamed = self.rename.iter().flatten().map(|(_, to)| to);
+            let nested_keys = self.nest.iter().flatten().flat_map(|(_, keys)| keys);
+
+            ValidationResult::ensure(
+                self.set.is_some()
+                    || self.rename.is_some()
+                    || self.hoist.is_some()
+                    || self.nest.is_some()
+                    || self.pick.is_some(),
+                "At least one of set, rename, hoist, nest or pick must be set",
+                path,
+            )
+            .concat(ValidationResult::ensure(
+                renamed.clone().count() == renamed.collect::<HashSet<_>>().len(),
+                "Multiple keys must not be renamed to the same name",
+                &path.append_single("rename"),
+            ))
+            .concat(ValidationResult::ensure(
+                nested_keys.clone().count() == nested_keys.collect::<HashSet<_>>().len(),
+                "A key must not be moved into multiple nested objects",
+                &path.append_single("nest"),
+            ))
+            .with(self.nest.iter().flatten(), |_, (name, keys)| {
+                ValidationResult::ensure(
+                    !keys.is_empty(),
+                    "The keys of a nested object must not be empty",
+                    &path.append("nest", name),
+                )
+            })
+        }
+    }
+}
@@ -8,20 +8,117 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct SortTransform {
-    pub by: Option<String>,
+    /// The key or keys to sort by.
+    /// If not set, the array is sorted by its values.
+    pub by: Option<SortBy>,
+    /// Whether to reverse the sorted array.
     pub reverse: Option<bool>,
+    /// Whether to compare strings using natural ordering,
+    /// comparing numbers in strings by their value.
+    /// Used for all keys which don't set `natural` themselves.
+    /// Defaults to false.
+    pub natural: Option<bool>,
+    /// Where to put null values.
+    /// Used for all keys which don't set `nulls` themselves.
+    /// Defaults to last.
+    pub nulls: Option<NullsOrder>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum SortBy {
+    /// A single key to sort by.
+    Single(SortKey),
+    /// Multiple keys to sort by.
+    /// Later keys are used if the values of all previous keys are equal.
+    Multiple(Vec<SortKey>),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum SortKey {
+    /// The path of the key to sort by in ascending order.
+    /// Nested keys are separated by dots, e.g. `address.city`.
+    Path(String),
+    Options(SortKeyOptions),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SortKeyOptions {
+    /// The path of the key to sort by.
+    /// Nested keys are separated by dots, e.g. `address.city`.
+    pub key: String,
+    /// The direction to sort in.
+    /// Defaults to ascending.
+    pub order: Option<SortOrder>,
+    /// Whether to compare strings using natural ordering.
+    pub natural: Option<bool>,
+    /// Where to put null values.
+    pub nulls: Option<NullsOrder>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum NullsOrder {
+    /// Put null values before all other values.
+    First,
+    /// Put null values after all other values.
+    #[default]
+    Last,
+}
+
+impl SortKey {
+    pub fn key(&self) -> &str {
+        match self {
+            SortKey::Path(key) => key,
+            SortKey::Options(options) => &options.key,
+        }
+    }
+}
+
+impl SortBy {
+    pub fn keys(&self) -> Vec<&SortKey> {
+        match self {
+            SortBy::Single(key) => vec![key],
+            SortBy::Multiple(keys) => keys.iter().collect(),
+        }
+    }
 }
 
 #[cfg(feature = "map-schema")]
 pub mod generate {
     use crate::generate::datagen_context::DatagenContextRef;
     use crate::generate::generated_schema::GeneratedSchema;
-    use crate::transform::sort::SortTransform;
+    use crate::transform::sort::{NullsOrder, SortKey, SortOrder, SortTransform};
+    use crate::util::key_path::get_key_path;
     use crate::util::traits::generate::TransformTrait;
     use anyhow::anyhow;
+    use std::cmp::Ordering;
     use std::sync::Arc;
 
-    #[derive(Debug, Clone, PartialEq, PartialOrd)]
+    #[derive(Debug, Clone, PartialEq)]
     enum ComparableSchema {
         Number(f64),
         Integer(i64),
@@ -45,17 +142,127 @@ pub mod generate {
         }
     }
 
-    fn find_by_key(value: &Arc<GeneratedSchema>, key: &str) -> anyhow::Result<ComparableSchema> {
-        match value.as_ref() {
-            GeneratedSchema::Object(obj) => {
-                if let Some(value) = obj.get(key) {
-                    value.as_ref().try_into()
-                } else {
-                    Err(anyhow!("Key '{}' not found in object", key))
+    impl ComparableSchema {
+        fn rank(&self) -> u8 {
+            match self {
+                ComparableSchema::Number(_) | ComparableSchema::Integer(_) => 0,
+                ComparableSchema::String(_) => 1,
+                ComparableSchema::Bool(_) => 2,
+                ComparableSchema::None => 3,
+            }
+        }
+
+        /// Compare two non-null values.
+        /// Numbers and integers are compared by their value,
+        /// values of different types are ordered by their type.
+        fn compare(&self, other: &Self, natural: bool) -> Ordering {
+            match (self, other) {
+                (ComparableSchema::Integer(a), ComparableSchema::Integer(b)) => a.cmp(b),
+                (ComparableSchema::Integer(a), ComparableSchema::Number(b)) => {
+                    (*a as f64).total_cmp(b)
+                }
+                (ComparableSchema::Number(a), ComparableSchema::Integer(b)) => {
+                    a.total_cmp(&(*b as f64))
                 }
+                (ComparableSchema::Number(a), ComparableSchema::Number(b)) => a.total_cmp(b),
+                (ComparableSchema::String(a), ComparableSchema::String(b)) if natural => {
+                    natural_cmp(a, b)
+                }
+                (ComparableSchema::String(a), ComparableSchema::String(b)) => a.cmp(b),
+                (ComparableSchema::Bool(a), ComparableSchema::Bool(b)) => a.cmp(b),
+                (a, b) => a.rank().cmp(&b.rank()),
             }
-            GeneratedSchema::None => Ok(ComparableSchema::None),
-            _ => Err(anyhow!("Sort can only be applied to objects")),
+        }
+    }
+
+    /// Compare two strings, comparing runs of digits by their numeric value.
+    fn natural_cmp(a: &str, b: &str) -> Ordering {
+        let mut a = a.chars().peekable();
+        let mut b = b.chars().peekable();
+
+        loop {
+            match (a.peek().copied(), b.peek().copied()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                    let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                        let mut number = String::new();
+                        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                            number.push(c);
+                        }
+                        number
+                    };
+
+                    let x = take_number(&mut a);
+                    let y = take_number(&mut b);
+                    let (x_trimmed, y_trimmed) =
+                        (x.trim_start_matches('0'), y.trim_start_matches('0'));
+
+                    let ord = x_trimmed
+                        .len()
+                        .cmp(&y_trimmed.len())
+                        .then_with(|| x_trimmed.cmp(y_trimmed))
+                        .then_with(|| x.len().cmp(&y.len()));
+                    if ord != Ordering::Equal {
+                        return ord;
+                    }
+                }
+                (Some(x), Some(y)) => {
+                    if x != y {
+                        return x.cmp(&y);
+                    }
+
+                    a.next();
+                    b.next();
+                }
+            }
+        }
+    }
+
+    struct ResolvedKey<'a> {
+        path: &'a str,
+        order: SortOrder,
+        natural: bool,
+        nulls: NullsOrder,
+    }
+
+    impl ResolvedKey<'_> {
+        fn compare(&self, a: &ComparableSchema, b: &ComparableSchema) -> Ordering {
+            match (a, b) {
+                (ComparableSchema::None, ComparableSchema::None) => Ordering::Equal,
+                (ComparableSchema::None, _) => match self.nulls {
+                    NullsOrder::First => Ordering::Less,
+                    NullsOrder::Last => Ordering::Greater,
+                },
+                (_, ComparableSchema::None) => match self.nulls {
+                    NullsOrder::First => Ordering::Greater,
+                    NullsOrder::Last => Ordering::Less,
+                },
+                (a, b) => {
+                    let ord = a.compare(b, self.natural);
+                    match self.order {
+                        SortOrder::Asc => ord,
+                        SortOrder::Desc => ord.reverse(),
+                    }
+                }
+            }
+        }
+    }
+
+    fn find_by_path(value: &Arc<GeneratedSchema>, path: &str) -> anyhow::Result<ComparableSchema> {
+        match value.as_ref() {
+            GeneratedSchema::Object(_) => {}
+            GeneratedSchema::None => return Ok(ComparableSchema::None),
+            _ => return Err(anyhow!("Sort can only be applied to objects")),
+        }
+
+        match get_key_path(value, path) {
+            Ok(value) => value.as_ref().try_into(),
+            Err(last) if matches!(last.as_ref(), GeneratedSchema::None) => {
+                Ok(ComparableSchema::None)
+            }
+            Err(_) => Err(anyhow!("Key '{}' not found in object", path)),
         }
     }
 
@@ -65,61 +272,104 @@ pub mod generate {
             _schema: DatagenContextRef,
             value: Arc<GeneratedSchema>,
         ) -> anyhow::Result<Arc<GeneratedSchema>> {
-            if let GeneratedSchema::Array(arr) = value.as_ref() {
-                let array = arr.clone();
+            let GeneratedSchema::Array(arr) = value.as_ref() else {
+                return Err(anyhow!("Sort can only be applied to arrays"));
+            };
 
-                if let Some(by) = self.by {
-                    let mut array = array
+            let keys = self
+                .by
+                .as_ref()
+                .map(|by| {
+                    by.keys()
                         .into_iter()
-                        .map(|e| Ok((find_by_key(&e, &by)?, e)))
-                        .collect::<anyhow::Result<Vec<_>>>()?;
+                        .map(|key| match key {
+                            SortKey::Path(path) => ResolvedKey {
+                                path,
+                                order: SortOrder::Asc,
+                                natural: self.natural.unwrap_or(false),
+                                nulls: self.nulls.unwrap_or_default(),
+                            },
+                            SortKey::Options(options) => ResolvedKey {
+                                path: &options.key,
+                                order: options.order.unwrap_or_default(),
+                                natural: options.natural.or(self.natural).unwrap_or(false),
+                                nulls: options.nulls.or(self.nulls).unwrap_or_default(),
+                            },
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
 
-                    array.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            let mut array = arr
+                .iter()
+                .map(|e| {
+                    let values = if keys.is_empty() {
+                        vec![e.as_ref().try_into()?]
+                    } else {
+                        keys.iter()
+                            .map(|key| find_by_path(e, key.path))
+                            .collect::<anyhow::Result<Vec<_>>>()?
+                    };
 
-                    if let Some(reverse) = self.reverse {
-                        if reverse {
-                            array.reverse();
-                        }
-                    }
+                    Ok((values, e.clone()))
+                })
+                .collect::<anyhow::Result<Vec<(Vec<ComparableSchema>, Arc<GeneratedSchema>)>>>()?;
 
-                    Ok(Arc::new(GeneratedSchema::Array(
-                        array.into_iter().map(|(_, e)| e).collect(),
-                    )))
-                } else {
-                    let mut array = array
-                        .into_iter()
-                        .map(|e| Ok((e.as_ref().try_into()?, e)))
-                        .collect::<anyhow::Result<Vec<(ComparableSchema, Arc<GeneratedSchema>)>>>(
-                    )?;
-                    array.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
-                    if let Some(reverse) = self.reverse {
-                        if reverse {
-                            array.reverse();
-                        }
-                    }
+            let default_key = ResolvedKey {
+                path: "",
+                order: SortOrder::Asc,
+                natural: self.natural.unwrap_or(false),
+                nulls: self.nulls.unwrap_or_default(),
+            };
+            array.sort_by(|(a, _), (b, _)| {
+                a.iter()
+                    .zip(b)
+                    .enumerate()
+                    .map(|(i, (a, b))| keys.get(i).unwrap_or(&default_key).compare(a, b))
+                    .find(|ord| ord.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
 
-                    Ok(Arc::new(GeneratedSchema::Array(
-                        array.into_iter().map(|(_, e)| e).collect(),
-                    )))
-                }
-            } else {
-                Err(anyhow!("Sort can only be applied to arrays"))
+            if self.reverse.unwrap_or(false) {
+                array.reverse();
             }
+
+            Ok(Arc::new(GeneratedSchema::Array(
+                array.into_iter().map(|(_, e)| e).collect(),
+            )))
         }
     }
 }
 
 #[cfg(feature = "validate-schema")]
 pub mod validate {
-    use crate::transform::sort::SortTransform;
+    use crate::transform::sort::{SortBy, SortTransform};
     use crate::validation::path::ValidationPath;
-    use crate::validation::result::ValidationResult;
+    use crate::validation::result::{IterValidate, ValidationResult};
     use crate::validation::validate::Validate;
 
     impl Validate for SortTransform {
-        fn validate(&self, _path: &ValidationPath) -> ValidationResult {
-            Ok(())
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            match &self.by {
+                Some(SortBy::Single(key)) => ValidationResult::ensure(
+                    !key.key().is_empty(),
+                    "The sort key must not be empty",
+                    &path.append_single("by"),
+                ),
+                Some(SortBy::Multiple(keys)) => ValidationResult::ensure(
+                    !keys.is_empty(),
+                    "At least one sort key is required",
+                    &path.append_single("by"),
+                )
+                .with(keys.iter(), |i, key| {
+                    ValidationResult::ensure(
+                        !key.key().is_empty(),
+                        "The sort key must not be empty",
+                        &path.append("by", i),
+                    )
+                }),
+                None => Ok(()),
+            }
         }
     }
 }
@@ -0,0 +1,573 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum StringSide {
+    /// The start of the string.
+    Start,
+    /// The end of the string.
+    End,
+    /// Both the start and the end of the string.
+    #[default]
+    Both,
+}
+
+/// Remove whitespace or other characters from the start and/or end of a string.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct TrimTransform {
+    /// The side to trim.
+    /// Defaults to both.
+    pub side: Option<StringSide>,
+    /// The characters to remove.
+    /// If not set, whitespace is removed.
+    pub characters: Option<String>,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+/// Get a part of a string.
+/// The indices are counted in characters.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SubstringTransform {
+    /// The index of the first character to keep.
+    /// Defaults to 0.
+    pub start: Option<usize>,
+    /// The index after the last character to keep.
+    /// Defaults to the length of the string.
+    pub end: Option<usize>,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+/// Shorten strings which are longer than a maximum length.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct TruncateTransform {
+    /// The maximum number of characters, including the ellipsis.
+    pub length: usize,
+    /// The string to append to truncated strings, e.g. `...`.
+    /// Defaults to an empty string.
+    pub ellipsis: Option<String>,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+/// Pad strings which are shorter than a minimum length.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct PadTransform {
+    /// The minimum number of characters.
+    pub length: usize,
+    /// The character to pad with.
+    /// Defaults to a space.
+    pub character: Option<char>,
+    /// The side to pad. If both sides are padded,
+    /// the end receives the extra character if the padding is uneven.
+    /// Defaults to the start.
+    pub side: Option<StringSide>,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+/// Replace all matches of a regex pattern.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct RegexReplaceTransform {
+    /// The regex pattern to match.
+    pub pattern: String,
+    /// The replacement. Capture groups can be referenced
+    /// using `$1` or `${name}`.
+    pub replacement: String,
+    /// The maximum number of matches to replace.
+    /// Replaces all matches if not set.
+    pub limit: Option<usize>,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+/// Convert a string into a lower case, url-friendly slug.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SlugifyTransform {
+    /// The separator to put between words.
+    /// Defaults to `-`.
+    pub separator: Option<String>,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum StringEncoding {
+    /// Standard base64 with padding.
+    Base64,
+    /// Url-safe base64 without padding.
+    Base64Url,
+    /// Lower case hex.
+    Hex,
+    /// Percent-encoding for use in urls.
+    Url,
+}
+
+/// Encode a string.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct EncodeTransform {
+    /// The encoding to use.
+    pub encoding: StringEncoding,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+/// Replace a string with its hash, written as lower case hex.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct HashTransform {
+    /// The hash algorithm to use.
+    pub algorithm: HashAlgorithm,
+    /// Whether to convert non-string values to strings
+    /// using serde_json.
+    pub serialize_non_strings: Option<bool>,
+    /// Whether to apply the transform recursively.
+    /// Defaults to false.
+    pub recursive: Option<bool>,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::string_transform::{
+        EncodeTransform, HashAlgorithm, HashTransform, PadTransform, RegexReplaceTransform,
+        SlugifyTransform, StringEncoding, StringSide, SubstringTransform, TrimTransform,
+        TruncateTransform,
+    };
+    use crate::util::traits::generate::TransformTrait;
+    use anyhow::anyhow;
+    use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+    use base64::Engine;
+    use indexmap::IndexMap;
+    use md5::Md5;
+    use regex::Regex;
+    use sha1::Sha1;
+    use sha2::{Digest, Sha256};
+    use std::sync::Arc;
+
+    /// Apply `func` to a string value, converting
+    /// non-string values the same way the string case transforms do.
+    fn transform_string<F>(
+        name: &str,
+        serialize_non_strings: bool,
+        recursive: bool,
+        value: Arc<GeneratedSchema>,
+        func: &F,
+    ) -> anyhow::Result<Arc<GeneratedSchema>>
+    where
+        F: Fn(&str) -> String,
+    {
+        let str = match value.as_ref() {
+            GeneratedSchema::String(str) => str.clone(),
+            GeneratedSchema::Integer(i) => i.to_string(),
+            GeneratedSchema::Number(n) => n.to_string(),
+            GeneratedSchema::Bool(b) => b.to_string(),
+            GeneratedSchema::Object(obj) if recursive => {
+                return Ok(GeneratedSchema::Object(
+                    obj.iter()
+                        .map(|(key, value)| {
+                            Ok((
+                                key.clone(),
+                                transform_string(
+                                    name,
+                                    serialize_non_strings,
+                                    recursive,
+                                    value.clone(),
+                                    func,
+                                )?,
+                            ))
+                        })
+                        .collect::<anyhow::Result<IndexMap<_, _>>>()?,
+                )
+                .into())
+            }
+            GeneratedSchema::Array(arr) if recursive => {
+                return Ok(GeneratedSchema::Array(
+                    arr.iter()
+                        .map(|value| {
+                            transform_string(
+                                name,
+                                serialize_non_strings,
+                                recursive,
+                                value.clone(),
+                                func,
+                            )
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?,
+                )
+                .into())
+            }
+            rest if serialize_non_strings && !recursive => serde_json::to_string(rest)?,
+            rest => {
+                return Err(anyhow!(
+                    "Cannot apply {name} to non-string value '{}'",
+                    rest.name()
+                ))
+            }
+        };
+
+        Ok(GeneratedSchema::String(func(&str)).into())
+    }
+
+    fn serialize_non_strings(
+        serialize_non_strings: Option<bool>,
+        schema: &DatagenContextRef,
+    ) -> anyhow::Result<bool> {
+        Ok(serialize_non_strings
+            .or(schema.options()?.serialize_non_strings)
+            .unwrap_or(false))
+    }
+
+    fn pad(str: &str, length: usize, character: char, side: StringSide) -> String {
+        let missing = length.saturating_sub(str.chars().count());
+        let (start, end) = match side {
+            StringSide::Start => (missing, 0),
+            StringSide::End => (0, missing),
+            StringSide::Both => (missing / 2, missing - missing / 2),
+        };
+
+        let mut res = String::with_capacity(str.len() + missing * character.len_utf8());
+        res.extend(std::iter::repeat_n(character, start));
+        res.push_str(str);
+        res.extend(std::iter::repeat_n(character, end));
+        res
+    }
+
+    fn slugify(str: &str, separator: &str) -> String {
+        deunicode::deunicode(str)
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    fn hash<D: Digest>(str: &str) -> String {
+        hex::encode(D::digest(str.as_bytes()))
+    }
+
+    impl TransformTrait for TrimTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let side = self.side.unwrap_or_default();
+            let is_trimmed = |c: char| match &self.characters {
+                Some(characters) => characters.contains(c),
+                None => c.is_whitespace(),
+            };
+
+            transform_string(
+                "trim",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| {
+                    match side {
+                        StringSide::Start => str.trim_start_matches(is_trimmed),
+                        StringSide::End => str.trim_end_matches(is_trimmed),
+                        StringSide::Both => str.trim_matches(is_trimmed),
+                    }
+                    .to_string()
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for SubstringTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let start = self.start.unwrap_or(0);
+
+            transform_string(
+                "substring",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| {
+                    let end = self.end.unwrap_or(usize::MAX);
+                    str.chars()
+                        .skip(start)
+                        .take(end.saturating_sub(start))
+                        .collect()
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for TruncateTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let ellipsis = self.ellipsis.unwrap_or_default();
+            let length = self.length;
+
+            transform_string(
+                "truncate",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| {
+                    if str.chars().count() <= length {
+                        return str.to_string();
+                    }
+
+                    let mut res = str
+                        .chars()
+                        .take(length.saturating_sub(ellipsis.chars().count()))
+                        .collect::<String>();
+                    res.push_str(&ellipsis);
+                    res
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for PadTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let character = self.character.unwrap_or(' ');
+            let side = self.side.unwrap_or(StringSide::Start);
+
+            transform_string(
+                "pad",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| pad(str, self.length, character, side),
+            )
+        }
+    }
+
+    impl TransformTrait for RegexReplaceTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let regex = Regex::new(&self.pattern)?;
+            let limit = self.limit.unwrap_or(0);
+
+            transform_string(
+                "regexReplace",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| {
+                    regex
+                        .replacen(str, limit, self.replacement.as_str())
+                        .into_owned()
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for SlugifyTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let separator = self.separator.as_deref().unwrap_or("-");
+
+            transform_string(
+                "slugify",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| slugify(str, separator),
+            )
+        }
+    }
+
+    impl TransformTrait for EncodeTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_string(
+                "encode",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| match self.encoding {
+                    StringEncoding::Base64 => STANDARD.encode(str),
+                    StringEncoding::Base64Url => URL_SAFE_NO_PAD.encode(str),
+                    StringEncoding::Hex => hex::encode(str),
+                    StringEncoding::Url => urlencoding::encode(str).into_owned(),
+                },
+            )
+        }
+    }
+
+    impl TransformTrait for HashTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            transform_string(
+                "hash",
+                serialize_non_strings(self.serialize_non_strings, &schema)?,
+                self.recursive.unwrap_or(false),
+                value,
+                &|str| match self.algorithm {
+                    HashAlgorithm::Md5 => hash::<Md5>(str),
+                    HashAlgorithm::Sha1 => hash::<Sha1>(str),
+                    HashAlgorithm::Sha256 => hash::<Sha256>(str),
+                },
+            )
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::string_transform::{
+        RegexReplaceTransform, SubstringTransform, TruncateTransform,
+    };
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+    use serde_json::Value;
+
+    impl Validate for SubstringTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.start.unwrap_or(0) <= self.end.unwrap_or(usize::MAX),
+                "start must be less than or equal to end",
+                path,
+            )
+        }
+    }
+
+    impl Validate for TruncateTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.ellipsis.as_ref().map_or(0, |e| e.chars().count()) <= self.length,
+                "The ellipsis must not be longer than length",
+                &path.append_single("ellipsis"),
+            )
+        }
+    }
+
+    impl Validate for RegexReplaceTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            if self.pattern.is_empty() {
+                return ValidationResult::single("pattern must not be empty", path, None, None);
+            }
+
+            ValidationResult::ensure_ok(
+                regex::Regex::new(&self.pattern),
+                "invalid regex pattern",
+                &path.append_single("pattern"),
+                Some(Value::String(self.pattern.clone())),
+            )
+        }
+    }
+}
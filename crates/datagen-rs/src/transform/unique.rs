@@ -0,0 +1,184 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Remove duplicate elements from an array.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct UniqueTransform {
+    /// The path of the key to compare the elements by.
+    /// Nested keys are separated by dots, e.g. `address.city`.
+    /// If not set, the elements are compared by their whole value.
+    pub by: Option<String>,
+    /// Which occurrence of a duplicate element to keep.
+    /// Defaults to the first occurrence.
+    pub keep: Option<KeepOccurrence>,
+    /// Whether to generate new elements using the item schema
+    /// of the array until the array has its original length.
+    /// This can only be used on arrays with an `items` schema.
+    /// Defaults to false.
+    pub refill: Option<bool>,
+    /// The maximum number of elements to generate while refilling the array.
+    /// Defaults to 100 times the number of removed elements.
+    pub max_attempts: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum KeepOccurrence {
+    #[default]
+    First,
+    Last,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::unique::{KeepOccurrence, UniqueTransform};
+    use crate::util::key_path::get_key_path;
+    use anyhow::anyhow;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    impl UniqueTransform {
+        fn key(&self, value: &Arc<GeneratedSchema>) -> anyhow::Result<String> {
+            let Some(path) = &self.by else {
+                return Ok(serde_json::to_string(value)?);
+            };
+
+            match get_key_path(value, path) {
+                Ok(value) => Ok(serde_json::to_string(value)?),
+                Err(last) => match last.as_ref() {
+                    GeneratedSchema::Object(_) => {
+                        Err(anyhow!("Key '{}' not found in object", path))
+                    }
+                    invalid => Err(anyhow!(
+                        "unique can only compare objects by key. Actual type was {}",
+                        invalid.name()
+                    )),
+                },
+            }
+        }
+
+        fn dedupe(
+            &self,
+            array: &[Arc<GeneratedSchema>],
+            seen: &mut HashSet<String>,
+        ) -> anyhow::Result<Vec<Arc<GeneratedSchema>>> {
+            let mut res = Vec::with_capacity(array.len());
+            match self.keep.unwrap_or_default() {
+                KeepOccurrence::First => {
+                    for value in array {
+                        if seen.insert(self.key(value)?) {
+                            res.push(value.clone());
+                        }
+                    }
+                }
+                KeepOccurrence::Last => {
+                    for value in array.iter().rev() {
+                        if seen.insert(self.key(value)?) {
+                            res.push(value.clone());
+                        }
+                    }
+                    res.reverse();
+                }
+            }
+
+            Ok(res)
+        }
+
+        /// Remove duplicate elements from an array.
+        /// If `refill` is set, `regenerate` is called with the index
+        /// of the element to generate until the array has its original length.
+        pub(crate) fn transform_with_refill(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+            regenerate: Option<&dyn Fn(usize) -> anyhow::Result<Arc<GeneratedSchema>>>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            let array = match value.as_ref() {
+                GeneratedSchema::Array(array) => array,
+                GeneratedSchema::None => return Ok(value),
+                invalid => {
+                    return Err(anyhow!(
+                        "unique can only be applied to arrays. Actual type was {}",
+                        invalid.name(),
+                    )
+                    .context(anyhow!("Invalid schema at {}", schema.path()?)))
+                }
+            };
+
+            let mut seen = HashSet::with_capacity(array.len());
+            let mut res = match self.dedupe(array, &mut seen) {
+                Ok(res) => res,
+                Err(e) => return Err(e.context(anyhow!("Invalid schema at {}", schema.path()?))),
+            };
+
+            if !self.refill.unwrap_or(false) || res.len() == array.len() {
+                return Ok(GeneratedSchema::Array(res).into());
+            }
+
+            let Some(regenerate) = regenerate else {
+                return Err(anyhow!(
+                    "unique can only refill arrays which are generated from an items schema"
+                )
+                .context(anyhow!("Invalid schema at {}", schema.path()?)));
+            };
+
+            let missing = array.len() - res.len();
+            let max_attempts = self.max_attempts.unwrap_or(missing * 100);
+            for _ in 0..max_attempts {
+                if res.len() == array.len() {
+                    break;
+                }
+
+                let value = regenerate(res.len())?;
+                if seen.insert(self.key(&value)?) {
+                    res.push(value);
+                }
+            }
+
+            if res.len() < array.len() {
+                return Err(anyhow!(
+                    "Failed to generate {} unique elements after {max_attempts} attempts",
+                    array.len()
+                )
+                .context(anyhow!("Invalid schema at {}", schema.path()?)));
+            }
+
+            Ok(GeneratedSchema::Array(res).into())
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::unique::UniqueTransform;
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    impl Validate for UniqueTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                !matches!(&self.by, Some(by) if by.is_empty()),
+                "The key must not be empty",
+                &path.append_single("by"),
+            )
+            .concat(ValidationResult::ensure(
+                self.max_attempts.is_none() || self.refill.unwrap_or(false),
+                "maxAttempts can only be set if refill is enabled",
+                &path.append_single("maxAttempts"),
+            ))
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::schema::transform::MaybeValidTransform;
+use crate::transform::filter::FilterTransform;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Apply a list of transforms only if a condition holds
+/// and/or only to a percentage of values.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WhenTransform {
+    /// The condition which must hold for the transforms to be applied.
+    /// This uses the same format as the `filter` transform,
+    /// so it can compare the value itself or a referenced field.
+    pub condition: Option<Box<FilterTransform>>,
+    /// The probability that the transforms are applied, between 0 and 1.
+    /// If a condition is set, the condition must hold as well.
+    pub probability: Option<f64>,
+    /// The transforms to apply.
+    pub transform: Vec<MaybeValidTransform>,
+}
+
+#[cfg(feature = "map-schema")]
+pub mod generate {
+    use crate::generate::datagen_context::DatagenContextRef;
+    use crate::generate::generated_schema::GeneratedSchema;
+    use crate::transform::when::WhenTransform;
+    use crate::util::traits::generate::TransformTrait;
+    use rand::Rng;
+    use std::sync::Arc;
+
+    impl TransformTrait for WhenTransform {
+        fn transform(
+            self,
+            schema: DatagenContextRef,
+            value: Arc<GeneratedSchema>,
+        ) -> anyhow::Result<Arc<GeneratedSchema>> {
+            if let Some(condition) = &self.condition {
+                if !condition.matches(&schema, &value)? {
+                    return Ok(value);
+                }
+            }
+
+            if let Some(probability) = self.probability {
+                if !schema.rng().gen_bool(probability.clamp(0.0, 1.0)) {
+                    return Ok(value);
+                }
+            }
+
+            self.transform.transform(schema, value)
+        }
+    }
+}
+
+#[cfg(feature = "validate-schema")]
+pub mod validate {
+    use crate::transform::when::WhenTransform;
+    use crate::validation::path::ValidationPath;
+    use crate::validation::result::{IterValidate, ValidationResult};
+    use crate::validation::validate::Validate;
+
+    impl Validate for WhenTransform {
+        fn validate(&self, path: &ValidationPath) -> ValidationResult {
+            ValidationResult::ensure(
+                self.condition.is_some() || self.probability.is_some(),
+                "At least one of condition and probability must be set",
+                path,
+            )
+            .concat(ValidationResult::ensure(
+                !matches!(self.probability, Some(probability) if !(0.0..=1.0).contains(&probability)),
+                "probability must be between 0 and 1",
+                &path.append_single("probability"),
+            ))
+            .concat(ValidationResult::ensure(
+                !self.transform.is_empty(),
+                "At least one transform is required",
+                &path.append_single("transform"),
+            ))
+            .concat(
+                self.condition
+                    .as_ref()
+                    .map(|condition| condition.validate(&path.append_single("condition")))
+                    .unwrap_or(Ok(())),
+            )
+            .with(self.transform.iter(), |i, transform| {
+                transform.validate(&path.append("transform", i))
+            })
+        }
+    }
+}
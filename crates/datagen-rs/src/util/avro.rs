@@ -0,0 +1,793 @@
+use anyhow::{anyhow, bail, Context};
+use indexmap::IndexMap;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+
+const MAGIC: &[u8] = b"Obj\x01";
+
+/// An Avro schema.
+/// Logical types are parsed into their underlying type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Record {
+        name: String,
+        namespace: Option<String>,
+        fields: Vec<AvroField>,
+    },
+    Enum {
+        name: String,
+        namespace: Option<String>,
+        symbols: Vec<String>,
+    },
+    Fixed {
+        name: String,
+        namespace: Option<String>,
+        size: usize,
+    },
+    /// A reference to a previously defined named type.
+    Ref(String),
+}
+
+/// A field of an Avro record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvroField {
+    pub name: String,
+    pub schema: AvroSchema,
+    pub default: Option<Value>,
+}
+
+impl AvroSchema {
+    /// Read an Avro schema from an `.avsc` file.
+    /// Returns the parsed schema and its JSON representation.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<(Self, Value)> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open avro schema at '{}'", path.display()))?;
+        let value: Value = serde_json::from_reader(file)
+            .with_context(|| format!("Failed to parse avro schema at '{}'", path.display()))?;
+
+        Ok((Self::parse(&value)?, value))
+    }
+
+    /// Parse an Avro schema from its JSON representation.
+    pub fn parse(value: &Value) -> anyhow::Result<Self> {
+        Self::parse_inner(value, None)
+    }
+
+    fn parse_inner(value: &Value, namespace: Option<&str>) -> anyhow::Result<Self> {
+        match value {
+            Value::String(name) => Ok(Self::primitive(name)
+                .unwrap_or_else(|| AvroSchema::Ref(Self::full_name(name, namespace)))),
+            Value::Array(types) => types
+                .iter()
+                .map(|t| Self::parse_inner(t, namespace))
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map(AvroSchema::Union),
+            Value::Object(obj) => {
+                let ty = obj
+                    .get("type")
+                    .ok_or(anyhow!("Avro schema object is missing the 'type' key"))?;
+                let Value::String(ty) = ty else {
+                    return Self::parse_inner(ty, namespace);
+                };
+
+                let name = || -> anyhow::Result<(String, Option<String>)> {
+                    let name = obj
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .ok_or(anyhow!("Avro type '{ty}' requires a name"))?;
+                    let namespace = obj
+                        .get("namespace")
+                        .and_then(Value::as_str)
+                        .or(namespace)
+                        .map(ToString::to_string);
+
+                    Ok((name.to_string(), namespace))
+                };
+
+                match ty.as_str() {
+                    "record" | "error" => {
+                        let (name, namespace) = name()?;
+                        let fields = obj
+                            .get("fields")
+                            .and_then(Value::as_array)
+                            .ok_or(anyhow!("Avro record '{name}' requires a 'fields' array"))?
+                            .iter()
+                            .map(|field| {
+                                let field_name = field
+                                    .get("name")
+                                    .and_then(Value::as_str)
+                                    .ok_or(anyhow!("A field of record '{name}' has no name"))?;
+
+                                Ok(AvroField {
+                                    name: field_name.to_string(),
+                                    schema: Self::parse_inner(
+                                        field.get("type").ok_or(anyhow!(
+                                            "Field '{field_name}' of record '{name}' has no type"
+                                        ))?,
+                                        namespace.as_deref(),
+                                    )?,
+                                    default: field.get("default").cloned(),
+                                })
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?;
+
+                        Ok(AvroSchema::Record {
+                            name,
+                            namespace,
+                            fields,
+                        })
+                    }
+                    "enum" => {
+                        let (name, namespace) = name()?;
+                        let symbols = obj
+                            .get("symbols")
+                            .and_then(Value::as_array)
+                            .ok_or(anyhow!("Avro enum '{name}' requires a 'symbols' array"))?
+                            .iter()
+                            .map(|s| {
+                                s.as_str()
+                                    .map(ToString::to_string)
+                                    .ok_or(anyhow!("Symbols of enum '{name}' must be strings"))
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?;
+
+                        Ok(AvroSchema::Enum {
+                            name,
+                            namespace,
+                            symbols,
+                        })
+                    }
+                    "fixed" => {
+                        let (name, namespace) = name()?;
+                        let size = obj
+                            .get("size")
+                            .and_then(Value::as_u64)
+                            .ok_or(anyhow!("Avro fixed '{name}' requires a 'size'"))?;
+
+                        Ok(AvroSchema::Fixed {
+                            name,
+                            namespace,
+                            size: size as usize,
+                        })
+                    }
+                    "array" => Ok(AvroSchema::Array(Box::new(Self::parse_inner(
+                        obj.get("items")
+                            .ok_or(anyhow!("Avro array requires an 'items' schema"))?,
+                        namespace,
+                    )?))),
+                    "map" => Ok(AvroSchema::Map(Box::new(Self::parse_inner(
+                        obj.get("values")
+                            .ok_or(anyhow!("Avro map requires a 'values' schema"))?,
+                        namespace,
+                    )?))),
+                    other => Self::parse_inner(&Value::String(other.to_string()), namespace),
+                }
+            }
+            other => Err(anyhow!("Invalid avro schema: {other}")),
+        }
+    }
+
+    fn primitive(name: &str) -> Option<Self> {
+        match name {
+            "null" => Some(AvroSchema::Null),
+            "boolean" => Some(AvroSchema::Boolean),
+            "int" => Some(AvroSchema::Int),
+            "long" => Some(AvroSchema::Long),
+            "float" => Some(AvroSchema::Float),
+            "double" => Some(AvroSchema::Double),
+            "bytes" => Some(AvroSchema::Bytes),
+            "string" => Some(AvroSchema::String),
+            _ => None,
+        }
+    }
+
+    fn full_name(name: &str, namespace: Option<&str>) -> String {
+        match namespace {
+            Some(namespace) if !name.contains('.') && !namespace.is_empty() => {
+                format!("{namespace}.{name}")
+            }
+            _ => name.to_string(),
+        }
+    }
+
+    fn type_name(&self) -> String {
+        match self {
+            AvroSchema::Null => "null".into(),
+            AvroSchema::Boolean => "boolean".into(),
+            AvroSchema::Int => "int".into(),
+            AvroSchema::Long => "long".into(),
+            AvroSchema::Float => "float".into(),
+            AvroSchema::Double => "double".into(),
+            AvroSchema::Bytes => "bytes".into(),
+            AvroSchema::String => "string".into(),
+            AvroSchema::Array(_) => "array".into(),
+            AvroSchema::Map(_) => "map".into(),
+            AvroSchema::Union(_) => "union".into(),
+            AvroSchema::Record { name, .. }
+            | AvroSchema::Enum { name, .. }
+            | AvroSchema::Fixed { name, .. }
+            | AvroSchema::Ref(name) => name.clone(),
+        }
+    }
+
+    /// Infer an Avro schema from a JSON value.
+    /// If the value is an array, the schema is inferred from all of its items,
+    /// as every item will be written as a separate datum.
+    /// Object keys which aren't valid Avro names are sanitized,
+    /// returns an error if two keys of an object map to the same field name.
+    pub fn infer(value: &Value, name: &str, namespace: Option<String>) -> anyhow::Result<Self> {
+        match value {
+            Value::Array(items) => Self::infer_items(items, name, &namespace),
+            other => Self::infer_value(other, name, &namespace),
+        }
+    }
+
+    fn infer_items(
+        items: &[Value],
+        name: &str,
+        namespace: &Option<String>,
+    ) -> anyhow::Result<Self> {
+        Ok(items
+            .iter()
+            .map(|item| Self::infer_value(item, name, namespace))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .reduce(Self::merge)
+            .unwrap_or(AvroSchema::Null))
+    }
+
+    fn infer_value(value: &Value, name: &str, namespace: &Option<String>) -> anyhow::Result<Self> {
+        Ok(match value {
+            Value::Null => AvroSchema::Null,
+            Value::Bool(_) => AvroSchema::Boolean,
+            Value::Number(number) if number.is_f64() => AvroSchema::Double,
+            Value::Number(_) => AvroSchema::Long,
+            Value::String(_) => AvroSchema::String,
+            Value::Array(items) => {
+                AvroSchema::Array(Box::new(Self::infer_items(items, name, namespace)?))
+            }
+            Value::Object(obj) => {
+                let mut fields = IndexMap::<String, (&String, AvroField)>::new();
+                for (key, value) in obj {
+                    let field_name = Self::sanitize_name(key);
+                    if let Some((other, _)) = fields.get(&field_name) {
+                        bail!(
+                            "The keys '{other}' and '{key}' of record '{name}' both map to the avro field '{field_name}'"
+                        );
+                    }
+
+                    let field = AvroField {
+                        schema: Self::infer_value(
+                            value,
+                            &format!("{name}_{field_name}"),
+                            namespace,
+                        )?,
+                        name: field_name.clone(),
+                        default: None,
+                    };
+                    fields.insert(field_name, (key, field));
+                }
+
+                AvroSchema::Record {
+                    name: name.to_string(),
+                    namespace: namespace.clone(),
+                    fields: fields.into_values().map(|(_, field)| field).collect(),
+                }
+            }
+        })
+    }
+
+    /// Convert a key into a valid Avro name, matching `[A-Za-z_][A-Za-z0-9_]*`.
+    fn sanitize_name(name: &str) -> String {
+        let res = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+
+        match res.chars().next() {
+            Some(c) if !c.is_ascii_digit() => res,
+            _ => format!("_{res}"),
+        }
+    }
+
+    fn nullable(self) -> Self {
+        match self {
+            AvroSchema::Null => AvroSchema::Null,
+            AvroSchema::Union(mut types) => {
+                if !types.contains(&AvroSchema::Null) {
+                    types.insert(0, AvroSchema::Null);
+                }
+
+                AvroSchema::Union(types)
+            }
+            other => AvroSchema::Union(vec![AvroSchema::Null, other]),
+        }
+    }
+
+    fn mergeable(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (
+                AvroSchema::Long | AvroSchema::Double,
+                AvroSchema::Long | AvroSchema::Double
+            )
+        ) || std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (AvroSchema::Null, other) | (other, AvroSchema::Null) => other.nullable(),
+            (AvroSchema::Long, AvroSchema::Double) | (AvroSchema::Double, AvroSchema::Long) => {
+                AvroSchema::Double
+            }
+            (AvroSchema::Array(a), AvroSchema::Array(b)) => {
+                AvroSchema::Array(Box::new(a.merge(*b)))
+            }
+            (
+                AvroSchema::Record {
+                    name,
+                    namespace,
+                    fields: a,
+                },
+                AvroSchema::Record { fields: b, .. },
+            ) => {
+                let mut merged = a
+                    .into_iter()
+                    .map(|f| (f.name.clone(), (f, false)))
+                    .collect::<IndexMap<_, _>>();
+                for field in b {
+                    match merged.get_mut(&field.name) {
+                        Some((existing, seen)) => {
+                            existing.schema = existing.schema.clone().merge(field.schema);
+                            *seen = true;
+                        }
+                        None => {
+                            merged.insert(
+                                field.name.clone(),
+                                (
+                                    AvroField {
+                                        schema: field.schema.nullable(),
+                                        ..field
+                                    },
+                                    true,
+                                ),
+                            );
+                        }
+                    }
+                }
+
+                AvroSchema::Record {
+                    name,
+                    namespace,
+                    fields: merged
+                        .into_values()
+                        .map(|(field, seen)| {
+                            if seen {
+                                field
+                            } else {
+                                AvroField {
+                                    schema: field.schema.nullable(),
+                                    ..field
+                                }
+                            }
+                        })
+                        .collect(),
+                }
+            }
+            (AvroSchema::Union(mut types), other) | (other, AvroSchema::Union(mut types)) => {
+                let other = match other {
+                    AvroSchema::Union(other) => other,
+                    other => vec![other],
+                };
+
+                for ty in other {
+                    if let Some(pos) = types.iter().position(|t| t.mergeable(&ty)) {
+                        types[pos] = types[pos].clone().merge(ty);
+                    } else {
+                        types.push(ty);
+                    }
+                }
+
+                AvroSchema::Union(types)
+            }
+            (a, b) => AvroSchema::Union(vec![a, b]),
+        }
+    }
+
+    /// Convert this schema into its JSON representation.
+    pub fn to_json(&self) -> Value {
+        match self {
+            AvroSchema::Array(items) => json!({ "type": "array", "items": items.to_json() }),
+            AvroSchema::Map(values) => json!({ "type": "map", "values": values.to_json() }),
+            AvroSchema::Union(types) => Value::Array(types.iter().map(Self::to_json).collect()),
+            AvroSchema::Record {
+                name,
+                namespace,
+                fields,
+            } => {
+                let mut res = Map::new();
+                res.insert("type".into(), "record".into());
+                res.insert("name".into(), name.clone().into());
+                if let Some(namespace) = namespace {
+                    res.insert("namespace".into(), namespace.clone().into());
+                }
+                res.insert(
+                    "fields".into(),
+                    fields
+                        .iter()
+                        .map(|field| {
+                            let mut res = Map::new();
+                            res.insert("name".into(), field.name.clone().into());
+                            res.insert("type".into(), field.schema.to_json());
+                            if let Some(default) = &field.default {
+                                res.insert("default".into(), default.clone());
+                            }
+
+                            Value::Object(res)
+                        })
+                        .collect(),
+                );
+
+                Value::Object(res)
+            }
+            AvroSchema::Enum {
+                name,
+                namespace,
+                symbols,
+            } => {
+                let mut res = json!({ "type": "enum", "name": name, "symbols": symbols });
+                if let Some(namespace) = namespace {
+                    res["namespace"] = namespace.clone().into();
+                }
+
+                res
+            }
+            AvroSchema::Fixed {
+                name,
+                namespace,
+                size,
+            } => {
+                let mut res = json!({ "type": "fixed", "name": name, "size": size });
+                if let Some(namespace) = namespace {
+                    res["namespace"] = namespace.clone().into();
+                }
+
+                res
+            }
+            other => Value::String(other.type_name()),
+        }
+    }
+}
+
+/// Encodes JSON values using the Avro binary encoding.
+pub struct AvroEncoder<'a> {
+    schema: &'a AvroSchema,
+    named: HashMap<String, &'a AvroSchema>,
+}
+
+impl<'a> AvroEncoder<'a> {
+    pub fn new(schema: &'a AvroSchema) -> Self {
+        let mut named = HashMap::new();
+        Self::collect_named(schema, None, &mut named);
+
+        Self { schema, named }
+    }
+
+    fn collect_named(
+        schema: &'a AvroSchema,
+        parent_namespace: Option<&str>,
+        named: &mut HashMap<String, &'a AvroSchema>,
+    ) {
+        match schema {
+            AvroSchema::Record {
+                name,
+                namespace,
+                fields,
+            } => {
+                let namespace = namespace.as_deref().or(parent_namespace);
+                named.insert(name.clone(), schema);
+                named.insert(AvroSchema::full_name(name, namespace), schema);
+                for field in fields {
+                    Self::collect_named(&field.schema, namespace, named);
+                }
+            }
+            AvroSchema::Enum {
+                name, namespace, ..
+            }
+            | AvroSchema::Fixed {
+                name, namespace, ..
+            } => {
+                named.insert(name.clone(), schema);
+                named.insert(
+                    AvroSchema::full_name(name, namespace.as_deref().or(parent_namespace)),
+                    schema,
+                );
+            }
+            AvroSchema::Array(inner) | AvroSchema::Map(inner) => {
+                Self::collect_named(inner, parent_namespace, named)
+            }
+            AvroSchema::Union(types) => {
+                for ty in types {
+                    Self::collect_named(ty, parent_namespace, named);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn resolve(&self, schema: &'a AvroSchema) -> anyhow::Result<&'a AvroSchema> {
+        match schema {
+            AvroSchema::Ref(name) => self
+                .named
+                .get(name)
+                .or_else(|| {
+                    name.rsplit_once('.')
+                        .and_then(|(_, short)| self.named.get(short))
+                })
+                .copied()
+                .ok_or(anyhow!("Unknown avro type '{name}'")),
+            other => Ok(other),
+        }
+    }
+
+    /// Encode a single datum, validating that it matches the schema.
+    pub fn encode(&self, value: &Value, buf: &mut Vec<u8>) -> anyhow::Result<()> {
+        self.encode_inner(self.schema, value, buf, "")
+    }
+
+    fn matches(&self, schema: &'a AvroSchema, value: &Value) -> bool {
+        let Ok(schema) = self.resolve(schema) else {
+            return false;
+        };
+
+        match (schema, value) {
+            (AvroSchema::Null, Value::Null) => true,
+            (AvroSchema::Boolean, Value::Bool(_)) => true,
+            (AvroSchema::Int, Value::Number(_)) => {
+                as_long(value).is_some_and(|n| i32::try_from(n).is_ok())
+            }
+            (AvroSchema::Long, Value::Number(_)) => as_long(value).is_some(),
+            (AvroSchema::Float | AvroSchema::Double, Value::Number(_)) => true,
+            (AvroSchema::Bytes | AvroSchema::String, Value::String(_)) => true,
+            (AvroSchema::Enum { symbols, .. }, Value::String(s)) => symbols.contains(s),
+            (AvroSchema::Fixed { size, .. }, Value::String(s)) => s.len() == *size,
+            (AvroSchema::Array(items), Value::Array(arr)) => {
+                arr.iter().all(|item| self.matches(items, item))
+            }
+            (AvroSchema::Map(values), Value::Object(obj)) => {
+                obj.values().all(|value| self.matches(values, value))
+            }
+            // Records only match objects with known keys and matching fields,
+            // so the right branch of a union of records is selected
+            (AvroSchema::Record { fields, .. }, Value::Object(obj)) => {
+                obj.keys()
+                    .all(|key| fields.iter().any(|field| Self::is_field(field, key)))
+                    && fields.iter().all(|field| {
+                        let value = Self::field_value(field, obj).or(field.default.as_ref());
+                        self.matches(&field.schema, value.unwrap_or(&Value::Null))
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether an object key belongs to a record field.
+    /// Inferred schemas use sanitized field names, the keys may not be valid avro names.
+    fn is_field(field: &AvroField, key: &str) -> bool {
+        field.name == key || field.name == AvroSchema::sanitize_name(key)
+    }
+
+    fn field_value<'v>(field: &AvroField, obj: &'v Map<String, Value>) -> Option<&'v Value> {
+        obj.get(&field.name).or_else(|| {
+            obj.iter()
+                .find(|(key, _)| Self::is_field(field, key))
+                .map(|(_, value)| value)
+        })
+    }
+
+    fn mismatch(schema: &AvroSchema, value: &Value, path: &str) -> anyhow::Error {
+        anyhow!(
+            "Value {value} at path '{path}' does not match the avro type '{}'",
+            schema.type_name()
+        )
+    }
+
+    fn encode_inner(
+        &self,
+        schema: &'a AvroSchema,
+        value: &Value,
+        buf: &mut Vec<u8>,
+        path: &str,
+    ) -> anyhow::Result<()> {
+        let schema = self.resolve(schema)?;
+        let child_path = |key: &dyn std::fmt::Display| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            }
+        };
+
+        match (schema, value) {
+            (AvroSchema::Null, Value::Null) => {}
+            (AvroSchema::Boolean, Value::Bool(b)) => buf.push(*b as u8),
+            (AvroSchema::Int, Value::Number(_)) | (AvroSchema::Long, Value::Number(_)) => {
+                if !self.matches(schema, value) {
+                    return Err(Self::mismatch(schema, value, path));
+                }
+
+                write_long(as_long(value).unwrap(), buf);
+            }
+            (AvroSchema::Float, Value::Number(n)) => {
+                buf.extend_from_slice(&(n.as_f64().unwrap() as f32).to_le_bytes())
+            }
+            (AvroSchema::Double, Value::Number(n)) => {
+                buf.extend_from_slice(&n.as_f64().unwrap().to_le_bytes())
+            }
+            (AvroSchema::Bytes | AvroSchema::String, Value::String(s)) => {
+                write_bytes(s.as_bytes(), buf)
+            }
+            (AvroSchema::Enum { symbols, .. }, Value::String(s)) => {
+                let index = symbols
+                    .iter()
+                    .position(|symbol| symbol == s)
+                    .ok_or_else(|| Self::mismatch(schema, value, path))?;
+
+                write_long(index as i64, buf);
+            }
+            (AvroSchema::Fixed { size, .. }, Value::String(s)) => {
+                if s.len() != *size {
+                    return Err(Self::mismatch(schema, value, path)
+                        .context(format!("Expected exactly {size} bytes, got {}", s.len())));
+                }
+
+                buf.extend_from_slice(s.as_bytes());
+            }
+            (AvroSchema::Array(items), Value::Array(arr)) => {
+                if !arr.is_empty() {
+                    write_long(arr.len() as i64, buf);
+                    for (i, item) in arr.iter().enumerate() {
+                        self.encode_inner(items, item, buf, &child_path(&i))?;
+                    }
+                }
+
+                write_long(0, buf);
+            }
+            (AvroSchema::Map(values), Value::Object(obj)) => {
+                if !obj.is_empty() {
+                    write_long(obj.len() as i64, buf);
+                    for (key, item) in obj {
+                        write_bytes(key.as_bytes(), buf);
+                        self.encode_inner(values, item, buf, &child_path(key))?;
+                    }
+                }
+
+                write_long(0, buf);
+            }
+            (AvroSchema::Record { fields, .. }, Value::Object(obj)) => {
+                for field in fields {
+                    let field_path = child_path(&field.name);
+                    match Self::field_value(field, obj).or(field.default.as_ref()) {
+                        Some(value) => self.encode_inner(&field.schema, value, buf, &field_path)?,
+                        None => self
+                            .encode_inner(&field.schema, &Value::Null, buf, &field_path)
+                            .with_context(|| {
+                                format!("Missing field '{}' at path '{path}'", field.name)
+                            })?,
+                    }
+                }
+
+                if let Some(key) = obj
+                    .keys()
+                    .find(|key| !fields.iter().any(|field| Self::is_field(field, key)))
+                {
+                    bail!(
+                        "Field '{}' is not part of the avro record '{}'",
+                        child_path(key),
+                        schema.type_name()
+                    );
+                }
+            }
+            (AvroSchema::Union(types), value) => {
+                let index = types
+                    .iter()
+                    .position(|ty| self.matches(ty, value))
+                    .ok_or_else(|| Self::mismatch(schema, value, path))?;
+
+                write_long(index as i64, buf);
+                self.encode_inner(&types[index], value, buf, path)?;
+            }
+            (schema, value) => return Err(Self::mismatch(schema, value, path)),
+        }
+
+        Ok(())
+    }
+}
+
+/// Get a number as an `i64`, also accepting floats without a fractional part.
+fn as_long(value: &Value) -> Option<i64> {
+    value.as_i64().or_else(|| {
+        value
+            .as_f64()
+            .filter(|n| n.fract() == 0.0 && *n >= i64::MIN as f64 && *n <= i64::MAX as f64)
+            .map(|n| n as i64)
+    })
+}
+
+fn write_long(value: i64, buf: &mut Vec<u8>) {
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n & !0x7F != 0 {
+        buf.push(((n & 0x7F) | 0x80) as u8);
+        n >>= 7;
+    }
+
+    buf.push(n as u8);
+}
+
+fn write_bytes(bytes: &[u8], buf: &mut Vec<u8>) {
+    write_long(bytes.len() as i64, buf);
+    buf.extend_from_slice(bytes);
+}
+
+/// Write an Avro Object Container File containing the given data.
+/// If the data is an array, every item is written as a separate datum.
+///
+/// # Arguments
+/// * `schema` - The schema of a single datum.
+/// * `schema_json` - The JSON representation of the schema to store in the file header.
+/// * `data` - The data to write.
+pub fn write_container_file(
+    schema: &AvroSchema,
+    schema_json: &Value,
+    data: &Value,
+) -> anyhow::Result<Vec<u8>> {
+    let encoder = AvroEncoder::new(schema);
+    let items = match data {
+        Value::Array(items) => items.iter().collect::<Vec<_>>(),
+        other => vec![other],
+    };
+
+    let mut block = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        encoder
+            .encode(item, &mut block)
+            .with_context(|| format!("Failed to encode record #{i} using the avro schema"))?;
+    }
+
+    // Derive the sync marker from the data, so the same data is always encoded the same way
+    let mut sync = [0u8; 16];
+    sync.copy_from_slice(&Sha256::digest(&block)[..16]);
+
+    let mut res = Vec::with_capacity(block.len() + 128);
+    res.extend_from_slice(MAGIC);
+    write_long(2, &mut res);
+    write_bytes(b"avro.schema", &mut res);
+    write_bytes(serde_json::to_string(schema_json)?.as_bytes(), &mut res);
+    write_bytes(b"avro.codec", &mut res);
+    write_bytes(b"null", &mut res);
+    write_long(0, &mut res);
+    res.extend_from_slice(&sync);
+
+    if !items.is_empty() {
+        write_long(items.len() as i64, &mut res);
+        write_bytes(&block, &mut res);
+        res.extend_from_slice(&sync);
+    }
+
+    Ok(res)
+}
@@ -0,0 +1,127 @@
+use anyhow::anyhow;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A compression format for serialized data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum Compression {
+    Gzip,
+    Zstd,
+    Bzip2,
+}
+
+impl Compression {
+    /// Get the compression format matching the extension of a path.
+    /// Returns `None` if the extension is not a known compression extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            "gz" | "gzip" => Some(Compression::Gzip),
+            "zst" | "zstd" => Some(Compression::Zstd),
+            "bz2" | "bzip2" => Some(Compression::Bzip2),
+            _ => None,
+        }
+    }
+
+    /// The value of the `Content-Encoding` header for this format.
+    pub fn content_encoding(&self) -> &'static str {
+        match self {
+            Compression::Gzip => "gzip",
+            Compression::Zstd => "zstd",
+            Compression::Bzip2 => "bzip2",
+        }
+    }
+
+    /// Wrap a writer into a writer compressing everything written to it.
+    /// [`CompressedWriter::finish`] must be called once all data has been written.
+    pub fn writer<W: Write>(&self, inner: W) -> anyhow::Result<CompressedWriter<W>> {
+        Ok(match self {
+            Compression::Gzip => CompressedWriter::Gzip(flate2::write::GzEncoder::new(
+                inner,
+                flate2::Compression::default(),
+            )),
+            Compression::Zstd => CompressedWriter::Zstd(zstd::Encoder::new(inner, 0)?),
+            Compression::Bzip2 => CompressedWriter::Bzip2(bzip2::write::BzEncoder::new(
+                inner,
+                bzip2::Compression::default(),
+            )),
+        })
+    }
+
+    /// Compress a buffer.
+    pub fn compress(&self, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let mut writer = self.writer(Vec::new())?;
+        writer.write_all(data)?;
+
+        writer.finish()
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.content_encoding())
+    }
+}
+
+impl FromStr for Compression {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "gzip" | "gz" => Ok(Compression::Gzip),
+            "zstd" | "zst" => Ok(Compression::Zstd),
+            "bzip2" | "bz2" => Ok(Compression::Bzip2),
+            _ => Err(anyhow!(
+                "Unknown compression '{s}', expected one of 'gzip', 'zstd' or 'bzip2'"
+            )),
+        }
+    }
+}
+
+/// A writer compressing everything written to it
+/// before passing it on to the inner writer.
+pub enum CompressedWriter<W: Write> {
+    Gzip(flate2::write::GzEncoder<W>),
+    Zstd(zstd::Encoder<'static, W>),
+    Bzip2(bzip2::write::BzEncoder<W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    /// Write the remaining compressed data and return the inner writer.
+    pub fn finish(self) -> anyhow::Result<W> {
+        let mut inner = match self {
+            CompressedWriter::Gzip(encoder) => encoder.finish()?,
+            CompressedWriter::Zstd(encoder) => encoder.finish()?,
+            CompressedWriter::Bzip2(encoder) => encoder.finish()?,
+        };
+        inner.flush()?;
+
+        Ok(inner)
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            CompressedWriter::Gzip(encoder) => encoder.write(buf),
+            CompressedWriter::Zstd(encoder) => encoder.write(buf),
+            CompressedWriter::Bzip2(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            CompressedWriter::Gzip(encoder) => encoder.flush(),
+            CompressedWriter::Zstd(encoder) => encoder.flush(),
+            CompressedWriter::Bzip2(encoder) => encoder.flush(),
+        }
+    }
+}
@@ -11,6 +11,23 @@ where
     serde_json::from_value(value)
 }
 
+/// Deserialize a value like [`from_value`], but also replace
+/// the given variables in strings. The variables take precedence
+/// over environment variables with the same name.
+pub fn from_value_with_variables<T>(
+    mut value: Value,
+    variables: &HashMap<String, String>,
+) -> serde_json::Result<T>
+where
+    T: DeserializeOwned,
+{
+    let mut env: HashMap<String, String> = std::env::vars().collect();
+    env.extend(variables.clone());
+
+    map_value(&mut value, &env)?;
+    serde_json::from_value(value)
+}
+
 pub fn from_reader<R, T>(rdr: R) -> serde_json::Result<T>
 where
     R: std::io::Read,
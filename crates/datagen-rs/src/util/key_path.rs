@@ -0,0 +1,29 @@
+use crate::generate::generated_schema::GeneratedSchema;
+use std::sync::Arc;
+
+/// Get the value at a dot-separated key path, like `address.city`.
+/// Keys containing dots are preferred over nested paths,
+/// so `{"address.city": 1}` is found before `{"address": {"city": 1}}`.
+///
+/// If the path doesn't exist, the value the lookup stopped at is returned as the error.
+/// This is either an object missing the next key or a value which isn't an object.
+pub(crate) fn get_key_path<'a>(
+    value: &'a Arc<GeneratedSchema>,
+    path: &str,
+) -> Result<&'a Arc<GeneratedSchema>, &'a Arc<GeneratedSchema>> {
+    if let GeneratedSchema::Object(obj) = value.as_ref() {
+        if let Some(value) = obj.get(path) {
+            return Ok(value);
+        }
+    }
+
+    let mut current = value;
+    for key in path.split('.') {
+        current = match current.as_ref() {
+            GeneratedSchema::Object(obj) => obj.get(key).ok_or(current)?,
+            _ => return Err(current),
+        };
+    }
+
+    Ok(current)
+}
@@ -1,9 +1,17 @@
+#[cfg(feature = "generate")]
+pub mod avro;
+#[cfg(feature = "compression")]
+pub mod compression;
 #[cfg(feature = "map-schema")]
 pub mod generate_error;
 pub mod helpers;
 #[cfg(feature = "env-schema")]
 pub mod json_deserialize;
+#[cfg(feature = "map-schema")]
+pub(crate) mod key_path;
 pub(crate) mod macros;
 #[cfg(feature = "generate")]
 pub mod sequential_vec;
 pub mod traits;
+#[cfg(feature = "generate")]
+pub mod xml;
@@ -1,5 +1,6 @@
 use anyhow::anyhow;
 use rand::seq::SliceRandom;
+use rand::Rng;
 
 pub struct SequentialVec<T> {
     data: Vec<T>,
@@ -15,8 +16,8 @@ impl<T> SequentialVec<T> {
         }
     }
 
-    pub fn random(&self) -> &T {
-        self.data.choose(&mut rand::thread_rng()).unwrap()
+    pub fn random<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        self.data.choose(rng).unwrap()
     }
 
     pub fn next_value(&mut self) -> &T {
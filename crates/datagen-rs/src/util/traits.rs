@@ -41,7 +41,9 @@ pub mod generate {
 
     impl ResolveRef for String {
         fn resolve_ref(self, schema: &DatagenContextRef) -> anyhow::Result<Arc<GeneratedSchema>> {
-            schema.resolve_ref(self.as_str())?.into_random()
+            schema
+                .resolve_ref(self.as_str())?
+                .into_random(&mut schema.rng())
         }
     }
 }
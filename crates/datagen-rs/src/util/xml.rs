@@ -0,0 +1,226 @@
+use anyhow::{anyhow, bail};
+use indexmap::IndexMap;
+use serde_json::Value;
+
+/// Options for writing XML.
+pub struct XmlOptions<'a> {
+    /// The prefix of keys which are written as attributes.
+    pub attribute_prefix: &'a str,
+    /// The key whose value is written as the text content of an element.
+    pub text_key: &'a str,
+    /// The key whose value is written as a CDATA section.
+    pub cdata_key: &'a str,
+    /// The element names of array items, keyed by the name of the array.
+    pub item_elements: Option<&'a IndexMap<String, String>>,
+    /// The namespaces to declare on the root element, keyed by their prefix.
+    /// An empty prefix declares the default namespace.
+    pub namespaces: Option<&'a IndexMap<String, String>>,
+}
+
+/// Write a JSON value as XML.
+/// Top-level arrays are written as repeated root elements.
+///
+/// # Arguments
+/// * `value` - The value to write.
+/// * `root_element` - The name of the root element.
+/// * `options` - The options to use.
+pub fn to_xml_string(
+    value: &Value,
+    root_element: &str,
+    options: &XmlOptions,
+) -> anyhow::Result<String> {
+    let mut res = String::new();
+    XmlWriter {
+        options,
+        out: &mut res,
+    }
+    .write_element(root_element, value, true)?;
+
+    Ok(res)
+}
+
+struct XmlWriter<'a, 'b> {
+    options: &'a XmlOptions<'a>,
+    out: &'b mut String,
+}
+
+impl XmlWriter<'_, '_> {
+    fn write_element(&mut self, name: &str, value: &Value, root: bool) -> anyhow::Result<()> {
+        if let Value::Array(items) = value {
+            for item in items {
+                self.write_element(name, item, root)?;
+            }
+
+            return Ok(());
+        }
+
+        check_name(name)?;
+        self.out.push('<');
+        self.out.push_str(name);
+        if root {
+            self.write_namespaces()?;
+        }
+
+        match value {
+            Value::Object(obj) => {
+                let mut children = Vec::new();
+                for (key, value) in obj {
+                    if let Some(attribute) = key.strip_prefix(self.options.attribute_prefix) {
+                        let value = scalar_to_string(value).ok_or(anyhow!(
+                            "The attribute '{key}' of element '{name}' must not be an array or object"
+                        ))?;
+
+                        check_name(attribute)?;
+                        self.write_attribute(attribute, &value);
+                    } else {
+                        children.push((key, value));
+                    }
+                }
+
+                if children.is_empty() {
+                    self.out.push_str("/>");
+                    return Ok(());
+                }
+
+                self.out.push('>');
+                for (key, value) in children {
+                    self.write_child(key, value)?;
+                }
+            }
+            Value::Null => {
+                self.out.push_str("/>");
+                return Ok(());
+            }
+            scalar => {
+                self.out.push('>');
+                self.write_text(&scalar_to_string(scalar).unwrap_or_default());
+            }
+        }
+
+        self.out.push_str("</");
+        self.out.push_str(name);
+        self.out.push('>');
+
+        Ok(())
+    }
+
+    fn write_child(&mut self, key: &str, value: &Value) -> anyhow::Result<()> {
+        if key == self.options.text_key || key == self.options.cdata_key {
+            let text = scalar_to_string(value).ok_or(anyhow!(
+                "The value of '{key}' must not be an array or object"
+            ))?;
+
+            if key == self.options.text_key {
+                self.write_text(&text);
+            } else {
+                self.write_cdata(&text);
+            }
+
+            return Ok(());
+        }
+
+        match (value, self.options.item_elements.and_then(|e| e.get(key))) {
+            (Value::Array(items), Some(item_element)) => {
+                check_name(key)?;
+                if items.is_empty() {
+                    self.out.push_str(&format!("<{key}/>"));
+                    return Ok(());
+                }
+
+                self.out.push_str(&format!("<{key}>"));
+                for item in items {
+                    if let Value::Array(_) = item {
+                        bail!("Nested arrays in '{key}' can not be written using item elements");
+                    }
+
+                    self.write_element(item_element, item, false)?;
+                }
+                self.out.push_str(&format!("</{key}>"));
+
+                Ok(())
+            }
+            _ => self.write_element(key, value, false),
+        }
+    }
+
+    fn write_namespaces(&mut self) -> anyhow::Result<()> {
+        for (prefix, uri) in self.options.namespaces.into_iter().flatten() {
+            let name = if prefix.is_empty() {
+                "xmlns".to_string()
+            } else {
+                check_name(prefix)?;
+                format!("xmlns:{prefix}")
+            };
+
+            self.write_attribute(&name, uri);
+        }
+
+        Ok(())
+    }
+
+    fn write_attribute(&mut self, name: &str, value: &str) {
+        self.out.push(' ');
+        self.out.push_str(name);
+        self.out.push_str("=\"");
+        for c in value.chars() {
+            match c {
+                '"' => self.out.push_str("&quot;"),
+                '\n' => self.out.push_str("&#10;"),
+                c => self.push_escaped(c),
+            }
+        }
+        self.out.push('"');
+    }
+
+    fn write_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.push_escaped(c);
+        }
+    }
+
+    fn write_cdata(&mut self, text: &str) {
+        self.out.push_str("<![CDATA[");
+        self.out.push_str(&text.replace("]]>", "]]]]><![CDATA[>"));
+        self.out.push_str("]]>");
+    }
+
+    fn push_escaped(&mut self, c: char) {
+        match c {
+            '&' => self.out.push_str("&amp;"),
+            '<' => self.out.push_str("&lt;"),
+            '>' => self.out.push_str("&gt;"),
+            c => self.out.push(c),
+        }
+    }
+}
+
+/// Check whether a name is a valid XML element or attribute name.
+fn check_name(name: &str) -> anyhow::Result<()> {
+    let is_start = |c: char| {
+        c == '_' || c == ':' || c.is_ascii_alphabetic() || (!c.is_ascii() && c.is_alphabetic())
+    };
+    let is_char = |c: char| {
+        is_start(c)
+            || c == '-'
+            || c == '.'
+            || c.is_ascii_digit()
+            || (!c.is_ascii() && c.is_alphanumeric())
+    };
+
+    let mut chars = name.chars();
+    if !chars.next().is_some_and(is_start) || !chars.all(is_char) {
+        bail!("'{name}' is not a valid XML name");
+    }
+
+    Ok(())
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some(String::new()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
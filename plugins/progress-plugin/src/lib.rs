@@ -8,6 +8,7 @@ use anyhow::anyhow;
 #[cfg(feature = "plugin")]
 use datagen_rs::declare_plugin;
 use datagen_rs::generate::datagen_context::DatagenContextRef;
+use datagen_rs::generate::datagen_rng::DatagenRng;
 use datagen_rs::generate::generated_schema::GeneratedSchema;
 use datagen_rs::generate::generated_schema::IntoRandom;
 use datagen_rs::generate::schema_mapper::MapSchema;
@@ -25,6 +26,7 @@ use datagen_rs::util::traits::generate::TransformTrait;
 use rand::prelude::SliceRandom;
 use rand::Rng;
 use serde_json::Value;
+use std::cell::Cell;
 use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -214,18 +216,22 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
     ) -> anyhow::Result<Arc<GeneratedSchema>> {
         match array {
             Array::RandomArray(array) => {
-                let len = self.get_array_length(&array.length)?;
-                schema.map_array(
-                    len as _,
-                    array.items,
-                    array.transform,
-                    true,
-                    |cur, value| {
-                        let res = self.convert_any_value(cur.clone(), value)?;
-                        self.increase_count();
-                        Ok(res)
-                    },
-                )
+                let len = self.get_array_length(&array.length)? as usize;
+                let generated = Cell::new(0);
+                schema.map_array(len, array.items, array.transform, true, |cur, value| {
+                    generated.set(generated.get() + 1);
+                    let res = if generated.get() <= len {
+                        self.convert_any_value(cur.clone(), value)?
+                    } else {
+                        // Items refilled by the unique transform weren't counted
+                        // in advance, so they are generated without the queues
+                        self.total_elements.fetch_add(1, Ordering::SeqCst);
+                        value.into_random(cur.clone())?
+                    };
+
+                    self.increase_count();
+                    Ok(res)
+                })
             }
             Array::ArrayWithValues(array) => Ok(GeneratedSchema::Array(
                 array
@@ -286,15 +292,15 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
         schema.finalize(res)
     }
 
-    fn map_any(&self, val: &mut AnyValue) -> anyhow::Result<usize> {
+    fn map_any(&self, val: &mut AnyValue, rng: &mut DatagenRng) -> anyhow::Result<usize> {
         if let AnyValue::Any(any) = val {
             match any {
                 MaybeValidAny::Valid(inner) => match inner {
-                    Any::Array(array) => self.map_array(array.as_mut()),
+                    Any::Array(array) => self.map_array(array.as_mut(), rng),
                     Any::Object(object) => {
                         let mut len = 1;
                         for (_, value) in &mut object.properties {
-                            len += self.map_any(value)?;
+                            len += self.map_any(value, rng)?;
                         }
 
                         Ok(len)
@@ -303,7 +309,7 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
                         let any_of_str = serde_json::to_string(any_of).unwrap();
 
                         let mut values = any_of.values.clone();
-                        values.shuffle(&mut rand::thread_rng());
+                        values.shuffle(rng);
                         let min = if any_of.allow_null.unwrap_or(false) {
                             0
                         } else {
@@ -314,7 +320,7 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
                         match num.cmp(&0) {
                             core::cmp::Ordering::Equal => num = -1,
                             core::cmp::Ordering::Less => {
-                                num = rand::thread_rng().gen_range(min..=any_of.values.len() as i64)
+                                num = rng.gen_range(min..=any_of.values.len() as i64)
                             }
                             _ => {}
                         }
@@ -325,7 +331,7 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
 
                         let mut len = 0;
                         for val in &mut values {
-                            len += self.map_any(val)?;
+                            len += self.map_any(val, rng)?;
                         }
 
                         let mut lock = self.any_of_values.lock().unwrap();
@@ -341,7 +347,7 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
                     }
                     Any::Include(include) => {
                         *any = include.as_schema()?;
-                        self.map_any(val)
+                        self.map_any(val, rng)
                     }
                     _ => Ok(1),
                 },
@@ -352,7 +358,7 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
         }
     }
 
-    fn add_array_len(&self, len: &ArrayLength) -> u32 {
+    fn add_array_len(&self, len: &ArrayLength, rng: &mut DatagenRng) -> u32 {
         match len {
             ArrayLength::Random { min, max } => {
                 let mut arrays = self.arrays.lock().unwrap();
@@ -360,7 +366,6 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
                 let entry = arrays
                     .entry(RandomArrayLength::new(*min, *max))
                     .or_default();
-                let mut rng = rand::thread_rng();
                 let res = rng.gen_range(*min..=*max);
                 entry.push_back(res);
 
@@ -371,14 +376,14 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
         }
     }
 
-    fn map_array(&self, val: &mut Array) -> anyhow::Result<usize> {
+    fn map_array(&self, val: &mut Array, rng: &mut DatagenRng) -> anyhow::Result<usize> {
         match val {
             Array::RandomArray(array) => {
-                let len = self.add_array_len(&array.length);
+                let len = self.add_array_len(&array.length, rng);
 
                 let mut res = 1;
                 for _ in 0..len {
-                    res += self.map_any(&mut array.items)?;
+                    res += self.map_any(&mut array.items, rng)?;
                 }
 
                 Ok(res)
@@ -386,7 +391,7 @@ impl<F: Fn(usize, usize) + Send + Sync> ProgressPlugin<F> {
             Array::ArrayWithValues(array) => {
                 let mut res = 1;
                 for value in &mut array.values {
-                    res += self.map_any(value)?;
+                    res += self.map_any(value, rng)?;
                 }
 
                 Ok(res)
@@ -408,7 +413,7 @@ impl<F: Fn(usize, usize) + Send + Sync> Plugin for ProgressPlugin<F> {
         let mut val: AnyValue = serde_json::from_value(args)?;
 
         self.total_elements
-            .store(self.map_any(&mut val)?, Ordering::SeqCst);
+            .store(self.map_any(&mut val, &mut schema.rng())?, Ordering::SeqCst);
 
         let res = self.convert_any_value(schema, val)?;
         // Increase the progress by one to account for the root element
@@ -0,0 +1,78 @@
+use anyhow::anyhow;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The arguments used to initialize the plugin.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RhaiPluginArgs {
+    /// A script containing functions which can be called from all scripts.
+    pub script: Option<String>,
+    /// Script files containing functions which can be called from all scripts.
+    pub paths: Option<Vec<String>>,
+    /// The maximum number of operations a single script run may perform.
+    /// Defaults to no limit.
+    pub max_operations: Option<u64>,
+}
+
+impl RhaiPluginArgs {
+    pub fn from_value(args: Value) -> anyhow::Result<Self> {
+        if args.is_null() {
+            return Ok(Self::default());
+        }
+
+        serde_json::from_value(args).map_err(|e| anyhow!("Invalid plugin arguments: {e}"))
+    }
+}
+
+/// The arguments of a single generate, transform or serialize call.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ScriptArgs {
+    /// An inline script.
+    pub script: Option<String>,
+    /// The path of a script file.
+    pub path: Option<String>,
+    /// The name of the function to call.
+    /// If not set, the script is evaluated and the value
+    /// of the last expression is used.
+    pub function: Option<String>,
+    /// The arguments passed to the script as `args`.
+    pub args: Option<Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScriptArgsOrString {
+    Script(String),
+    Args(ScriptArgs),
+}
+
+impl ScriptArgs {
+    /// Parse the arguments, which are either an object
+    /// or a string containing an inline script.
+    pub fn from_value(args: Value) -> anyhow::Result<Self> {
+        let args = match serde_json::from_value(args).map_err(|_| {
+            anyhow!(
+                "The arguments must be a script or an object containing a script, path or function"
+            )
+        })? {
+            ScriptArgsOrString::Script(script) => Self {
+                script: Some(script),
+                ..Default::default()
+            },
+            ScriptArgsOrString::Args(args) => args,
+        };
+
+        anyhow::ensure!(
+            args.script.is_none() || args.path.is_none(),
+            "Only one of script and path can be set"
+        );
+        anyhow::ensure!(
+            args.script.is_some() || args.path.is_some() || args.function.is_some(),
+            "One of script, path or function must be set"
+        );
+
+        Ok(args)
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::anyhow;
+use datagen_rs::generate::generated_schema::GeneratedSchema;
+use indexmap::IndexMap;
+use rhai::{Array, Dynamic, Map};
+use std::sync::Arc;
+
+/// Convert a generated value into a native script value.
+/// Objects are converted to object maps and arrays to arrays.
+pub fn to_dynamic(value: &GeneratedSchema) -> anyhow::Result<Dynamic> {
+    Ok(match value {
+        GeneratedSchema::None => Dynamic::UNIT,
+        GeneratedSchema::Number(number) => Dynamic::from_float(number.0),
+        GeneratedSchema::Integer(integer) => Dynamic::from_int(*integer),
+        GeneratedSchema::Bool(bool) => Dynamic::from_bool(*bool),
+        GeneratedSchema::String(string) => string.clone().into(),
+        GeneratedSchema::Array(array) => Dynamic::from_array(
+            array
+                .iter()
+                .map(|value| to_dynamic(value))
+                .collect::<anyhow::Result<Array>>()?,
+        ),
+        GeneratedSchema::Object(object) => Dynamic::from_map(
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.into(), to_dynamic(value)?)))
+                .collect::<anyhow::Result<Map>>()?,
+        ),
+        GeneratedSchema::Value(value) => rhai::serde::to_dynamic(value)
+            .map_err(|e| anyhow!("Failed to convert the value: {e}"))?,
+    })
+}
+
+/// Convert a script value into a generated value.
+/// Object maps are sorted by key, so the keys which are part of the
+/// `template` keep the position they have in the template.
+pub fn from_dynamic(
+    value: Dynamic,
+    template: Option<&GeneratedSchema>,
+) -> anyhow::Result<GeneratedSchema> {
+    Ok(if value.is_unit() {
+        GeneratedSchema::None
+    } else if let Ok(bool) = value.as_bool() {
+        GeneratedSchema::Bool(bool)
+    } else if let Ok(integer) = value.as_int() {
+        GeneratedSchema::Integer(integer)
+    } else if let Ok(float) = value.as_float() {
+        GeneratedSchema::Number(float.into())
+    } else if value.is_string() || value.is_char() {
+        GeneratedSchema::String(value.to_string())
+    } else if value.is_array() {
+        let template = match template {
+            Some(GeneratedSchema::Array(template)) => Some(template),
+            _ => None,
+        };
+
+        GeneratedSchema::Array(
+            value
+                .into_array()
+                .map_err(|e| anyhow!("Failed to convert the array: {e}"))?
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let template = template.and_then(|t| t.get(i)).map(AsRef::as_ref);
+                    from_dynamic(value, template).map(Arc::new)
+                })
+                .collect::<anyhow::Result<_>>()?,
+        )
+    } else if value.is_map() {
+        let mut map = value.cast::<Map>();
+        let mut res = IndexMap::new();
+        if let Some(GeneratedSchema::Object(template)) = template {
+            for (key, template) in template {
+                if let Some(value) = map.remove(key.as_str()) {
+                    res.insert(key.clone(), from_dynamic(value, Some(template))?.into());
+                }
+            }
+        }
+
+        for (key, value) in map {
+            res.insert(key.to_string(), from_dynamic(value, None)?.into());
+        }
+
+        GeneratedSchema::Object(res)
+    } else {
+        return Err(anyhow!(
+            "Scripts can't return values of type '{}'",
+            value.type_name()
+        ));
+    })
+}
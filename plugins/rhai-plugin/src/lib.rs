@@ -0,0 +1,304 @@
+#[cfg(test)]
+mod tests;
+
+mod args;
+mod convert;
+
+use crate::args::{RhaiPluginArgs, ScriptArgs};
+use crate::convert::{from_dynamic, to_dynamic};
+use anyhow::anyhow;
+#[cfg(feature = "plugin-lib")]
+use datagen_rs::declare_plugin;
+use datagen_rs::generate::datagen_context::DatagenContextRef;
+use datagen_rs::generate::datagen_rng::DatagenRng;
+use datagen_rs::generate::generated_schema::GeneratedSchema;
+#[cfg(feature = "plugin-lib")]
+use datagen_rs::init_plugin_logger;
+use datagen_rs::plugins::plugin::{Plugin, PluginConstructor, PluginOptions};
+use log::{debug, info};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Scope, AST, INT};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+thread_local! {
+    /// The context of the generate or transform call which
+    /// is currently running on this thread.
+    static CONTEXT: RefCell<Option<DatagenContextRef>> = const { RefCell::new(None) };
+}
+
+/// Set the context for the duration of `func`.
+/// The previous context is restored afterwards,
+/// as scripts may be called recursively.
+fn with_context<T>(
+    context: Option<DatagenContextRef>,
+    func: impl FnOnce() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let previous = CONTEXT.with(|c| c.replace(context));
+    let res = func();
+    CONTEXT.with(|c| c.replace(previous));
+
+    res
+}
+
+/// The random number generator of the current context.
+/// Scripts running outside of a context use the thread-local generator.
+fn rng() -> DatagenRng {
+    CONTEXT.with(|c| c.borrow().as_ref().map(|c| c.rng()).unwrap_or_default())
+}
+
+fn script_error<E: std::fmt::Display>(error: E) -> Box<EvalAltResult> {
+    error.to_string().into()
+}
+
+fn resolve_in_context<T>(
+    reference: &str,
+    func: impl FnOnce(&DatagenContextRef, String) -> anyhow::Result<T>,
+) -> Result<T, Box<EvalAltResult>> {
+    let reference = if reference.starts_with("ref:") {
+        reference.to_string()
+    } else {
+        format!("ref:{reference}")
+    };
+
+    CONTEXT.with(|c| match c.borrow().as_ref() {
+        Some(context) => func(context, reference).map_err(script_error),
+        None => Err("References can only be resolved in generate and transform scripts".into()),
+    })
+}
+
+fn register_functions(engine: &mut Engine) {
+    engine
+        .register_fn("resolve", |reference: &str| {
+            resolve_in_context(reference, |context, reference| {
+                let value = context
+                    .resolve_ref(&reference)?
+                    .into_random(&mut context.rng())?;
+                to_dynamic(&value)
+            })
+        })
+        .register_fn("resolve_all", |reference: &str| {
+            resolve_in_context(reference, |context, reference| {
+                context
+                    .resolve_ref(&reference)?
+                    .into_vec()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|value| to_dynamic(value))
+                    .collect::<anyhow::Result<Array>>()
+            })
+        })
+        .register_fn("path", || {
+            CONTEXT.with(|c| match c.borrow().as_ref() {
+                Some(context) => context
+                    .path()
+                    .map(|path| path.to_string())
+                    .map_err(script_error),
+                None => Ok(String::new()),
+            })
+        })
+        .register_fn("random_int", |min: INT, max: INT| {
+            if min > max {
+                return Err(script_error(format!(
+                    "The minimum ({min}) must not be greater than the maximum ({max})"
+                )));
+            }
+
+            Ok(rng().gen_range(min..=max))
+        })
+        .register_fn("random_float", |min: f64, max: f64| {
+            if min > max {
+                return Err(script_error(format!(
+                    "The minimum ({min}) must not be greater than the maximum ({max})"
+                )));
+            } else if min == max {
+                return Ok(min);
+            }
+
+            Ok(rng().gen_range(min..max))
+        })
+        .register_fn("random_bool", |probability: f64| {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(script_error(format!(
+                    "The probability must be between 0 and 1, but was {probability}"
+                )));
+            }
+
+            Ok(rng().gen_bool(probability))
+        })
+        .register_fn("random_element", |array: Array| {
+            array.choose(&mut rng()).cloned().unwrap_or(Dynamic::UNIT)
+        });
+}
+
+/// A plugin which runs [Rhai](https://rhai.rs) scripts
+/// to generate, transform or serialize values.
+pub struct RhaiPlugin {
+    engine: Engine,
+    /// The functions defined in the scripts passed on initialization.
+    library: AST,
+    /// Compiled scripts, keyed by their source or path.
+    cache: Mutex<HashMap<String, Arc<AST>>>,
+}
+
+impl RhaiPlugin {
+    fn compile(&self, args: &ScriptArgs) -> anyhow::Result<Arc<AST>> {
+        let key = match (&args.script, &args.path) {
+            (Some(script), _) => format!("script:{script}"),
+            (_, Some(path)) => format!("path:{path}"),
+            _ => return Ok(Arc::new(self.library.clone())),
+        };
+
+        let mut cache = self
+            .cache
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock the script cache: {e}"))?;
+        if let Some(ast) = cache.get(&key) {
+            return Ok(ast.clone());
+        }
+
+        let ast = match (&args.script, &args.path) {
+            (Some(script), _) => self
+                .engine
+                .compile(script)
+                .map_err(|e| anyhow!("Failed to compile the script: {e}"))?,
+            (_, Some(path)) => self
+                .engine
+                .compile_file(PathBuf::from(path))
+                .map_err(|e| anyhow!("Failed to compile the script '{path}': {e}"))?,
+            _ => unreachable!(),
+        };
+
+        debug!("Compiled script '{key}'");
+        let ast = Arc::new(self.library.merge(&ast));
+        cache.insert(key, ast.clone());
+
+        Ok(ast)
+    }
+
+    fn run(
+        &self,
+        context: Option<DatagenContextRef>,
+        args: Value,
+        value: Option<Dynamic>,
+    ) -> anyhow::Result<Dynamic> {
+        let args = ScriptArgs::from_value(args)?;
+        let ast = self.compile(&args)?;
+        let script_args = match args.args {
+            Some(args) => rhai::serde::to_dynamic(args)
+                .map_err(|e| anyhow!("Failed to convert the script arguments: {e}"))?,
+            None => Dynamic::UNIT,
+        };
+
+        with_context(context, || {
+            let mut scope = Scope::new();
+            let res = match args.function {
+                Some(function) => match value {
+                    Some(value) => {
+                        self.engine
+                            .call_fn(&mut scope, &ast, &function, (value, script_args))
+                    }
+                    None => self
+                        .engine
+                        .call_fn(&mut scope, &ast, &function, (script_args,)),
+                },
+                None => {
+                    scope.push_dynamic("args", script_args);
+                    if let Some(value) = value {
+                        scope.push_dynamic("value", value);
+                    }
+
+                    self.engine.eval_ast_with_scope(&mut scope, &ast)
+                }
+            };
+
+            res.map_err(|e| anyhow!("Failed to run the script: {e}"))
+        })
+    }
+}
+
+impl Plugin for RhaiPlugin {
+    fn name(&self) -> String {
+        "rhai".into()
+    }
+
+    fn generate(
+        &self,
+        schema: DatagenContextRef,
+        args: Value,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        let res = self.run(Some(schema), args, None)?;
+        from_dynamic(res, None).map(Arc::new)
+    }
+
+    fn transform(
+        &self,
+        schema: DatagenContextRef,
+        value: Arc<GeneratedSchema>,
+        args: Value,
+    ) -> anyhow::Result<Arc<GeneratedSchema>> {
+        let res = self.run(Some(schema), args, Some(to_dynamic(&value)?))?;
+        from_dynamic(res, Some(&value)).map(Arc::new)
+    }
+
+    fn serialize(&self, value: &Arc<GeneratedSchema>, args: Value) -> anyhow::Result<String> {
+        let res = self.run(None, args, Some(to_dynamic(value)?))?;
+        if res.is_string() {
+            return Ok(res.to_string());
+        }
+
+        serde_json::to_string_pretty(&from_dynamic(res, Some(value))?).map_err(Into::into)
+    }
+}
+
+impl PluginConstructor for RhaiPlugin {
+    fn new(
+        args: Value,
+        #[cfg(feature = "plugin-lib")] options: PluginOptions,
+        #[cfg(not(feature = "plugin-lib"))] _options: PluginOptions,
+    ) -> anyhow::Result<Self> {
+        #[cfg(feature = "plugin-lib")]
+        init_plugin_logger!(options);
+
+        let args = RhaiPluginArgs::from_value(args)?;
+        let mut engine = Engine::new();
+        engine
+            .on_print(|text| info!("{text}"))
+            .on_debug(|text, source, pos| match source {
+                Some(source) => debug!("{source} @ {pos:?} > {text}"),
+                None => debug!("{pos:?} > {text}"),
+            });
+        if let Some(max_operations) = args.max_operations {
+            engine.set_max_operations(max_operations);
+        }
+        register_functions(&mut engine);
+
+        let mut library = AST::empty();
+        if let Some(script) = &args.script {
+            let ast = engine
+                .compile(script)
+                .map_err(|e| anyhow!("Failed to compile the library script: {e}"))?;
+            library.combine(ast.clone_functions_only());
+        }
+
+        for path in args.paths.unwrap_or_default() {
+            let ast = engine
+                .compile_file(PathBuf::from(&path))
+                .map_err(|e| anyhow!("Failed to compile the library script '{path}': {e}"))?;
+            library.combine(ast.clone_functions_only());
+        }
+
+        Ok(Self {
+            engine,
+            library,
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+}
+
+#[cfg(feature = "plugin-lib")]
+declare_plugin!(RhaiPlugin);
@@ -0,0 +1,280 @@
+use crate::RhaiPlugin;
+use datagen_rs::plugins::plugin::{Plugin, PluginConstructor, PluginOptions};
+use datagen_rs::schema::schema_definition::Schema;
+use datagen_rs::util::helpers::generate_random_data;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const SCRIPT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/script.rhai");
+
+fn generate(schema: Value, args: Value) -> anyhow::Result<Value> {
+    let schema: Schema = serde_json::from_value(schema)?;
+    let plugin: Arc<dyn Plugin> = Arc::new(RhaiPlugin::new(args, PluginOptions::default())?);
+    let plugins = HashMap::from([("rhai".to_string(), plugin)]);
+
+    Ok(serde_json::from_str(&generate_random_data(
+        schema,
+        Some(plugins),
+    )?)?)
+}
+
+#[test]
+fn test_generate_inline() {
+    let generated = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": "let x = 40; x + 2"
+        }),
+        Value::Null,
+    )
+    .unwrap();
+
+    assert_eq!(generated, json!(42));
+}
+
+#[test]
+fn test_generate_with_args() {
+    let generated = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": {
+                "script": "let res = []; for i in 0..args.count { res.push(random_int(args.min, args.max)); } res",
+                "args": {
+                    "count": 10,
+                    "min": 1,
+                    "max": 3
+                }
+            }
+        }),
+        Value::Null,
+    )
+    .unwrap();
+
+    let values = generated.as_array().unwrap();
+    assert_eq!(values.len(), 10);
+    assert!(values
+        .iter()
+        .all(|v| (1..=3).contains(&v.as_i64().unwrap())));
+}
+
+#[test]
+fn test_transform() {
+    let generated = generate(
+        json!({
+            "type": "object",
+            "properties": {
+                "name": "Alice",
+                "user": {
+                    "type": "object",
+                    "properties": {
+                        "id": 1,
+                        "active": false,
+                        "score": 1.5
+                    },
+                    "transform": [
+                        {
+                            "type": "plugin",
+                            "name": "rhai",
+                            "args": "value.active = true; value.score *= 2.0; value.name = resolve(\"../name\"); value"
+                        }
+                    ]
+                }
+            }
+        }),
+        Value::Null,
+    )
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&generated).unwrap(),
+        r#"{"name":"Alice","user":{"id":1.0,"active":true,"score":3.0,"name":"Alice"}}"#
+    );
+}
+
+#[test]
+fn test_library_functions() {
+    let generated = generate(
+        json!({
+            "type": "object",
+            "properties": {
+                "greeting": {
+                    "type": "plugin",
+                    "pluginName": "rhai",
+                    "args": "greet(\"World\")"
+                },
+                "name": {
+                    "type": "plugin",
+                    "pluginName": "rhai",
+                    "args": {
+                        "function": "full_name",
+                        "args": {
+                            "first": "Jane",
+                            "last": "Doe"
+                        }
+                    }
+                },
+                "shout": {
+                    "type": "string",
+                    "value": "quiet",
+                    "transform": [
+                        {
+                            "type": "plugin",
+                            "name": "rhai",
+                            "args": {
+                                "function": "to_upper"
+                            }
+                        }
+                    ]
+                },
+                "double": {
+                    "type": "plugin",
+                    "pluginName": "rhai",
+                    "args": {
+                        "function": "double",
+                        "args": 21
+                    }
+                }
+            }
+        }),
+        json!({
+            "script": "fn double(x) { x * 2 }",
+            "paths": [SCRIPT_PATH]
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        generated,
+        json!({
+            "greeting": "Hello, World!",
+            "name": "Jane Doe",
+            "shout": "QUIET",
+            "double": 42
+        })
+    );
+}
+
+#[test]
+fn test_script_from_path() {
+    let generated = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": {
+                "path": SCRIPT_PATH,
+                "function": "greet",
+                "args": "Rhai"
+            }
+        }),
+        Value::Null,
+    )
+    .unwrap();
+
+    assert_eq!(generated, json!("Hello, Rhai!"));
+}
+
+#[test]
+fn test_serialize() {
+    let schema: Schema = serde_json::from_value(json!({
+        "options": {
+            "serializer": {
+                "type": "plugin",
+                "pluginName": "rhai",
+                "args": "value.map(|v| `${v.name}=${v.age}`).reduce(|sum, v| if sum == () { v } else { sum + \"\\n\" + v })"
+            }
+        },
+        "type": "array",
+        "length": 2,
+        "items": {
+            "type": "object",
+            "properties": {
+                "name": "Bob",
+                "age": {
+                    "type": "integer",
+                    "value": 30
+                }
+            }
+        }
+    }))
+    .unwrap();
+    let plugin: Arc<dyn Plugin> =
+        Arc::new(RhaiPlugin::new(Value::Null, PluginOptions::default()).unwrap());
+    let generated =
+        generate_random_data(schema, Some(HashMap::from([("rhai".to_string(), plugin)]))).unwrap();
+
+    assert_eq!(generated, "Bob=30\nBob=30");
+}
+
+#[test]
+fn test_serialize_non_string() {
+    let plugin = RhaiPlugin::new(Value::Null, PluginOptions::default()).unwrap();
+    let value = Arc::new(datagen_rs::generate::generated_schema::GeneratedSchema::Integer(2));
+
+    assert_eq!(
+        plugin
+            .serialize(&value, json!("#{ value: value, double: value * 2 }"))
+            .unwrap(),
+        "{\n  \"double\": 4,\n  \"value\": 2\n}"
+    );
+}
+
+#[test]
+fn test_invalid_args() {
+    let err = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": {
+                "script": "1",
+                "path": SCRIPT_PATH
+            }
+        }),
+        Value::Null,
+    )
+    .unwrap_err();
+    assert!(format!("{err:?}").contains("Only one of script and path can be set"));
+
+    let err = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": {}
+        }),
+        Value::Null,
+    )
+    .unwrap_err();
+    assert!(format!("{err:?}").contains("One of script, path or function must be set"));
+}
+
+#[test]
+fn test_script_error() {
+    let err = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": "throw \"Something went wrong\""
+        }),
+        Value::Null,
+    )
+    .unwrap_err();
+    assert!(format!("{err:?}").contains("Something went wrong"));
+}
+
+#[test]
+fn test_max_operations() {
+    let err = generate(
+        json!({
+            "type": "plugin",
+            "pluginName": "rhai",
+            "args": "loop {}"
+        }),
+        json!({
+            "maxOperations": 1000
+        }),
+    )
+    .unwrap_err();
+    assert!(format!("{err:?}").contains("Too many operations"));
+}
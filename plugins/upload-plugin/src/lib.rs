@@ -34,6 +34,12 @@ impl Plugin for UploadPlugin {
         callback: PluginSerializeCallback,
     ) -> anyhow::Result<String> {
         let args: UploadArgs = from_value(args).map_err(anyhow::Error::new)?;
+        if args.serializer.as_ref().unwrap_or_default().is_binary()
+            && !args.return_null.unwrap_or_default()
+        {
+            anyhow::bail!("Binary serializers can only be used if 'returnNull' is set to true");
+        }
+
         args.upload_data(value, callback)?;
 
         if args.return_null.unwrap_or_default() {
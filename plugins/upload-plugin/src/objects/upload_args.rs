@@ -16,6 +16,7 @@ use tokio::runtime::Builder;
 use datagen_rs::generate::generated_schema::GeneratedSchema;
 use datagen_rs::plugins::plugin::PluginSerializeCallback;
 use datagen_rs::schema::serializer::Serializer;
+use datagen_rs::util::compression::Compression;
 
 use crate::auth::authentication::{AnyAuth, Authentication, NoAuth};
 use crate::objects::auth_args::AuthArgs;
@@ -69,6 +70,10 @@ pub(crate) struct UploadArgs {
     /// The root CA to use for certificate verification.
     #[serde(rename = "rootCA")]
     pub root_ca: Option<VecOrString>,
+    /// The compression to apply to the request body.
+    /// If set, the `Content-Encoding` header will be set accordingly.
+    /// If not specified, the body will not be compressed.
+    pub compression: Option<Compression>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -184,10 +189,28 @@ impl UploadArgs {
                     Serializer::Json { .. } => "application/json",
                     Serializer::Yaml { .. } => "application/yaml",
                     Serializer::Xml { .. } => "application/xml",
+                    Serializer::Avro { .. } => "avro/binary",
                     _ => return Err(anyhow!("Unsupported serializer")),
                 }
                 .parse()?,
             );
+
+            match self.compression {
+                // There is no registered content coding for bzip2
+                Some(Compression::Bzip2) => {
+                    return Err(anyhow!(
+                        "bzip2 can't be used to compress uploads, use gzip or zstd instead"
+                    ))
+                }
+                Some(compression) => {
+                    map.insert("Content-Encoding", compression.content_encoding().parse()?);
+                }
+                None => {}
+            }
+        } else if self.compression.is_some() {
+            return Err(anyhow!(
+                "Compression can only be used when uploading the data in the body"
+            ));
         }
 
         if let Some(additional) = self.headers.as_ref() {
@@ -264,6 +287,7 @@ impl UploadArgs {
         let creator = RequestCreator::try_from(self)?;
         let serializer = self.serializer.clone().unwrap_or_default();
         let upload_in = self.upload_in.unwrap_or_default();
+        let compression = self.compression;
 
         let num_splits = split.len();
         let callback_ref = &progress_callback;
@@ -281,6 +305,7 @@ impl UploadArgs {
                         let creator = &creator;
                         let serializer = &serializer;
                         let upload_in = &upload_in;
+                        let compression = compression.as_ref();
 
                         debug!("Uploading next chunk");
 
@@ -289,7 +314,7 @@ impl UploadArgs {
                                 .get_builder(url)
                                 .await?
                                 .headers(headers)
-                                .add_data(upload_in, serializer, d)?
+                                .add_data(upload_in, serializer, compression, d)?
                                 .send()
                                 .await
                                 .map_err(|e| anyhow!(e.to_string()))
@@ -1,5 +1,6 @@
 use datagen_rs::generate::generated_schema::GeneratedSchema;
 use datagen_rs::schema::serializer::Serializer;
+use datagen_rs::util::compression::Compression;
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -18,6 +19,7 @@ pub(crate) trait AddData {
         self,
         upload_in: &UploadIn,
         serializer: &Serializer,
+        compression: Option<&Compression>,
         data: Arc<GeneratedSchema>,
     ) -> anyhow::Result<RequestBuilder>;
 }
@@ -27,10 +29,19 @@ impl AddData for RequestBuilder {
         self,
         upload_in: &UploadIn,
         serializer: &Serializer,
+        compression: Option<&Compression>,
         data: Arc<GeneratedSchema>,
     ) -> anyhow::Result<RequestBuilder> {
         Ok(match upload_in {
-            UploadIn::Body => self.body(serializer.serialize_generated(data, None)?),
+            UploadIn::Body => match compression {
+                Some(compression) => {
+                    let mut writer = compression.writer(Vec::new())?;
+                    serializer.serialize_generated_to(data, None, &mut writer)?;
+
+                    self.body(writer.finish()?)
+                }
+                None => self.body(serializer.serialize_generated_bytes(data, None)?),
+            },
             UploadIn::Query => self.query(&data),
             UploadIn::Form => self.form(&data),
         })
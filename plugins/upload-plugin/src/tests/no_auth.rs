@@ -2,6 +2,7 @@ use crate::tests::{check_array, create_schema};
 use crate::UploadPlugin;
 use datagen_rs::plugins::plugin::Plugin;
 use datagen_rs::schema::schema_definition::Schema;
+use datagen_rs::util::compression::Compression;
 use datagen_rs::util::helpers::generate_random_data;
 use mockito::{Matcher, Mock, ServerGuard};
 use serde_json::{from_value, json, Value};
@@ -141,6 +142,102 @@ fn test_upload_yaml() {
     assert_eq!(res, "- test\n- test\n- test\n- test\n- test\n".to_string());
 }
 
+#[test]
+fn test_upload_avro() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/")
+        .with_status(201)
+        .match_header("Content-Type", "avro/binary")
+        .match_body(Matcher::Regex("^Obj\x01".into()))
+        .create()
+        .expect(1);
+
+    let res = create_schema(json!({
+        "url": server.url(),
+        "returnNull": true,
+        "serializer": {
+            "type": "avro",
+        }
+    }))
+    .unwrap();
+
+    mock.assert();
+    assert_eq!(res, "".to_string());
+}
+
+#[test]
+fn test_upload_avro_without_return_null() {
+    let res = create_schema(json!({
+        "url": "http://localhost",
+        "serializer": {
+            "type": "avro",
+        }
+    }));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_upload_compressed() {
+    for compression in [Compression::Gzip, Compression::Zstd] {
+        let mut server = mockito::Server::new();
+        let expected = compression
+            .compress(br#"["test","test","test","test","test"]"#)
+            .unwrap();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(201)
+            .match_header("Content-Type", "application/json")
+            .match_header("Content-Encoding", compression.content_encoding())
+            .match_body(expected)
+            .create()
+            .expect(1);
+
+        let res = create_schema(json!({
+            "url": server.url(),
+            "compression": compression,
+        }))
+        .unwrap();
+
+        mock.assert();
+        check_array(res);
+    }
+}
+
+#[test]
+fn test_upload_bzip2() {
+    let res = create_object_schema(json!({
+        "url": "http://localhost",
+        "compression": "bzip2"
+    }))
+    .unwrap_err();
+
+    assert!(
+        format!("{:?}", res).contains("bzip2 can't be used to compress uploads"),
+        "{:?}",
+        res
+    );
+}
+
+#[test]
+fn test_upload_compressed_in_query() {
+    let res = create_object_schema(json!({
+        "url": "http://localhost",
+        "uploadIn": "query",
+        "compression": "gzip"
+    }))
+    .unwrap_err();
+
+    assert!(
+        format!("{:?}", res).contains("Compression can only be used"),
+        "{:?}",
+        res
+    );
+}
+
 #[test]
 fn test_upload_return_null() {
     let mut server = mockito::Server::new();